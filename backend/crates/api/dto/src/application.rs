@@ -0,0 +1,51 @@
+use entity::applied_to_join::AppliedToJoin;
+use macros::dto;
+use ulid::Ulid;
+
+dto! {
+    ///
+    Application {
+        fields {
+            ///
+            #[schema(format = Ulid, examples(Ulid::default))]
+            user_id: Ulid,
+
+            ///
+            #[schema(format = Ulid, examples(Ulid::default))]
+            team_id: Ulid,
+
+            ///
+            #[schema(format = Ulid, examples(Ulid::default))]
+            specialization: Ulid,
+
+            ///
+            #[schema(max_length = 4096)]
+            application: String,
+        },
+        create
+        ///
+        {
+            ///
+            #[schema(format = Ulid, examples(Ulid::default))]
+            #[garde(skip)]
+            specialization: Ulid,
+
+            ///
+            #[schema(max_length = 4096)]
+            #[garde(length(max = 4096))]
+            application: String,
+        },
+    }
+}
+
+impl From<AppliedToJoin> for Application {
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn from(entity: AppliedToJoin) -> Self {
+        Self {
+            user_id: entity.r#in.into(),
+            team_id: entity.out.into(),
+            specialization: entity.specialization.into(),
+            application: entity.application,
+        }
+    }
+}
@@ -36,4 +36,79 @@ request! {
         #[schema(format = Password, min_length = 8, max_length = 100)]
         new_password_repeat: String,
     }
+
+    ///
+    EmailVerification {
+        ///
+        #[garde(length(min = 1, max = 1000))]
+        #[schema(min_length = 1, max_length = 1000)]
+        token: String,
+    }
+
+    ///
+    PasswordReset {
+        ///
+        #[garde(length(min = 6, max = 50), email)]
+        #[schema(format = IdnEmail, min_length = 6, max_length = 50)]
+        email: String,
+    }
+
+    ///
+    PasswordResetConfirm {
+        ///
+        #[garde(length(min = 1, max = 1000))]
+        #[schema(min_length = 1, max_length = 1000)]
+        token: String,
+
+        ///
+        #[garde(length(min = 8, max = 100), custom(validate_password))]
+        #[schema(format = Password, min_length = 8, max_length = 100)]
+        new_password: String,
+
+        ///
+        #[garde(
+            length(min = 8, max = 100),
+            matches(new_password),
+            custom(validate_password)
+        )]
+        #[schema(format = Password, min_length = 8, max_length = 100)]
+        new_password_repeat: String,
+    }
+
+    ///
+    Refresh {
+        ///
+        #[garde(length(min = 1, max = 1000))]
+        #[schema(min_length = 1, max_length = 1000)]
+        refresh_token: String,
+    }
+
+    ///
+    TwoFactorLogin {
+        ///
+        #[garde(length(min = 1, max = 1000))]
+        #[schema(min_length = 1, max_length = 1000)]
+        challenge_token: String,
+
+        ///
+        #[garde(length(min = 6, max = 20))]
+        #[schema(min_length = 6, max_length = 20)]
+        code: String,
+    }
+
+    ///
+    TwoFactorSetup {
+        ///
+        #[garde(length(min = 8, max = 100), custom(validate_password))]
+        #[schema(format = Password, min_length = 8, max_length = 100)]
+        password: String,
+    }
+
+    ///
+    TwoFactorCode {
+        ///
+        #[garde(length(min = 6, max = 20))]
+        #[schema(min_length = 6, max_length = 20)]
+        code: String,
+    }
 }
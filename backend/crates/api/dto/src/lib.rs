@@ -1,15 +1,27 @@
 #![allow(clippy::empty_docs)] // TODO: remove this
 
 use garde::Validate;
-use serde::Deserialize;
+use serde::{Deserialize, Deserializer};
 use utoipa::IntoParams;
 
+pub mod application;
 pub mod auth;
 pub mod image;
+pub mod member;
+pub mod mentor;
+pub mod notification;
 pub mod profile;
+pub mod recommendation;
 pub mod review;
+pub mod session;
+pub mod skill;
+pub mod specialization;
 pub mod team;
+pub mod technology;
+pub mod telegram;
 pub mod tour;
+pub mod tour_result;
+pub mod two_factor;
 pub mod user;
 
 ///
@@ -43,3 +55,15 @@ impl From<Pagination> for (u16, u64) {
         )
     }
 }
+
+/// Keeps an explicit `null` apart from a missing field, so updates can clear
+/// optional values.
+pub(crate) fn nullable<'de, D, T>(
+    deserializer: D,
+) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::deserialize(deserializer).map(Some)
+}
@@ -0,0 +1,33 @@
+use entity::member_of::MemberOf;
+use macros::dto;
+use ulid::Ulid;
+
+dto! {
+    ///
+    Member {
+        fields {
+            ///
+            #[schema(format = Ulid, examples(Ulid::default))]
+            user_id: Ulid,
+
+            ///
+            #[schema(format = Ulid, examples(Ulid::default))]
+            team_id: Ulid,
+
+            ///
+            #[schema(format = Ulid, examples(Ulid::default))]
+            specialization: Ulid,
+        },
+    }
+}
+
+impl From<MemberOf> for Member {
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn from(entity: MemberOf) -> Self {
+        Self {
+            user_id: entity.r#in.into(),
+            team_id: entity.out.into(),
+            specialization: entity.specialization.into(),
+        }
+    }
+}
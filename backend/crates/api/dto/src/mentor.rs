@@ -0,0 +1,28 @@
+use entity::mentors::Mentors;
+use macros::dto;
+use ulid::Ulid;
+
+dto! {
+    ///
+    Mentor {
+        fields {
+            ///
+            #[schema(format = Ulid, examples(Ulid::default))]
+            user_id: Ulid,
+
+            ///
+            #[schema(format = Ulid, examples(Ulid::default))]
+            team_id: Ulid,
+        },
+    }
+}
+
+impl From<Mentors> for Mentor {
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn from(entity: Mentors) -> Self {
+        Self {
+            user_id: entity.r#in.into(),
+            team_id: entity.out.into(),
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use entity::notification_settings::{
+    NotificationSettings as NotificationSettingsEntity,
+    UpsertNotificationSettings as UpsertNotificationSettingsEntity,
+};
+use macros::dto;
+
+dto! {
+    ///
+    NotificationSettings {
+        fields {
+            ///
+            application_accepted: bool,
+
+            ///
+            new_applicant: bool,
+
+            ///
+            review_received: bool,
+
+            ///
+            tour_starting: bool,
+        },
+        upsert
+        ///
+        {
+            ///
+            #[garde(skip)]
+            application_accepted: bool,
+
+            ///
+            #[garde(skip)]
+            new_applicant: bool,
+
+            ///
+            #[garde(skip)]
+            review_received: bool,
+
+            ///
+            #[garde(skip)]
+            tour_starting: bool,
+        },
+    }
+}
+
+impl From<NotificationSettingsEntity> for NotificationSettings {
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn from(entity: NotificationSettingsEntity) -> Self {
+        Self {
+            application_accepted: entity.application_accepted,
+            new_applicant: entity.new_applicant,
+            review_received: entity.review_received,
+            tour_starting: entity.tour_starting,
+        }
+    }
+}
+impl From<UpsertNotificationSettings> for UpsertNotificationSettingsEntity {
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn from(dto: UpsertNotificationSettings) -> Self {
+        Self {
+            application_accepted: dto.application_accepted,
+            new_applicant: dto.new_applicant,
+            review_received: dto.review_received,
+            tour_starting: dto.tour_starting,
+        }
+    }
+}
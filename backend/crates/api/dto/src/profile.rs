@@ -53,6 +53,9 @@ dto! {
 
             ///
             has_avatar: bool,
+
+            ///
+            telegram_verified: bool,
         },
         upsert
         ///
@@ -130,6 +133,7 @@ impl From<ProfileEntity> for Profile {
             bio: entity.bio,
             portfolio_urls: entity.portfolio_urls,
             has_avatar: entity.has_avatar,
+            telegram_verified: entity.telegram_verified,
         }
     }
 }
@@ -144,6 +148,7 @@ impl From<Profile> for UpsertProfileEntity {
             bio: dto.bio,
             portfolio_urls: dto.portfolio_urls,
             has_avatar: dto.has_avatar,
+            telegram_verified: dto.telegram_verified,
         }
     }
 }
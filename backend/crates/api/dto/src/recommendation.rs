@@ -0,0 +1,49 @@
+use entity::recommendation::Recommendation as RecommendationEntity;
+use macros::dto;
+use ulid::Ulid;
+
+dto! {
+    ///
+    Recommendation {
+        fields {
+            ///
+            #[schema(format = Ulid, examples(Ulid::default))]
+            user_id: Ulid,
+
+            ///
+            #[schema(min_length = 3, max_length = 20)]
+            username: String,
+
+            ///
+            #[schema(minimum = 0, maximum = 100, examples(72.5))]
+            score: f64,
+
+            ///
+            specializations: Vec<Ulid>,
+
+            ///
+            technologies: Vec<Ulid>,
+        },
+    }
+}
+
+impl From<RecommendationEntity> for Recommendation {
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn from(entity: RecommendationEntity) -> Self {
+        Self {
+            user_id: entity.user.into(),
+            username: entity.username,
+            score: entity.score,
+            specializations: entity
+                .specializations
+                .into_iter()
+                .map(Ulid::from)
+                .collect(),
+            technologies: entity
+                .technologies
+                .into_iter()
+                .map(Ulid::from)
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use chrono::{DateTime, Utc};
+use entity::session::Session as SessionEntity;
+use macros::dto;
+use ulid::Ulid;
+
+dto! {
+    ///
+    Session {
+        fields {
+            ///
+            #[schema(format = Ulid, examples(Ulid::default))]
+            id: Ulid,
+
+            ///
+            started_at: DateTime<Utc>,
+
+            ///
+            refreshed_at: DateTime<Utc>,
+
+            ///
+            expires_at: DateTime<Utc>,
+
+            ///
+            current: bool,
+        },
+    }
+
+    ///
+    SessionTokens {
+        fields {
+            ///
+            token: String,
+
+            ///
+            refresh_token: String,
+        },
+    }
+}
+
+impl From<(SessionEntity, Ulid)> for Session {
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn from((entity, current): (SessionEntity, Ulid)) -> Self {
+        Self {
+            id: entity.family,
+            started_at: entity.started_at,
+            refreshed_at: entity.created_at,
+            expires_at: entity.expires_at,
+            current: entity.family == current,
+        }
+    }
+}
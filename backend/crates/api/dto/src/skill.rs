@@ -0,0 +1,77 @@
+use entity::{has_experience_as::HasExperienceAs, knows::Knows};
+use macros::dto;
+use ulid::Ulid;
+
+dto! {
+    ///
+    TechnologySkill {
+        fields {
+            ///
+            #[schema(format = Ulid, examples(Ulid::default))]
+            user_id: Ulid,
+
+            ///
+            #[schema(format = Ulid, examples(Ulid::default))]
+            technology_id: Ulid,
+
+            ///
+            #[schema(minimum = 1, maximum = 10, examples(7))]
+            level: u16,
+        },
+        upsert
+        ///
+        {
+            ///
+            #[schema(minimum = 1, maximum = 10, examples(7))]
+            #[garde(range(min = 1, max = 10))]
+            level: u16,
+        },
+    }
+
+    ///
+    SpecializationSkill {
+        fields {
+            ///
+            #[schema(format = Ulid, examples(Ulid::default))]
+            user_id: Ulid,
+
+            ///
+            #[schema(format = Ulid, examples(Ulid::default))]
+            specialization_id: Ulid,
+
+            ///
+            #[schema(minimum = 1, maximum = 10, examples(7))]
+            level: u16,
+        },
+        upsert
+        ///
+        {
+            ///
+            #[schema(minimum = 1, maximum = 10, examples(7))]
+            #[garde(range(min = 1, max = 10))]
+            level: u16,
+        },
+    }
+}
+
+impl From<Knows> for TechnologySkill {
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn from(entity: Knows) -> Self {
+        Self {
+            user_id: entity.r#in.into(),
+            technology_id: entity.out.into(),
+            level: entity.level,
+        }
+    }
+}
+
+impl From<HasExperienceAs> for SpecializationSkill {
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn from(entity: HasExperienceAs) -> Self {
+        Self {
+            user_id: entity.r#in.into(),
+            specialization_id: entity.out.into(),
+            level: entity.level,
+        }
+    }
+}
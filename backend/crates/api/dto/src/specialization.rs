@@ -0,0 +1,44 @@
+use entity::specialization::Specialization as SpecializationEntity;
+use macros::dto;
+use ulid::Ulid;
+
+dto! {
+    ///
+    Specialization {
+        fields {
+            ///
+            #[schema(format = Ulid, examples(Ulid::default))]
+            id: Ulid,
+
+            ///
+            #[schema(min_length = 1, max_length = 30)]
+            name: String,
+        },
+        create
+        ///
+        {
+            ///
+            #[schema(min_length = 1, max_length = 30)]
+            #[garde(length(min = 1, max = 30))]
+            name: String,
+        },
+        update
+        ///
+        {
+            ///
+            #[schema(min_length = 1, max_length = 30)]
+            #[garde(length(min = 1, max = 30))]
+            name: String,
+        },
+    }
+}
+
+impl From<SpecializationEntity> for Specialization {
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn from(entity: SpecializationEntity) -> Self {
+        Self {
+            id: entity.id.into(),
+            name: entity.name,
+        }
+    }
+}
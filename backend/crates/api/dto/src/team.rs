@@ -1,7 +1,10 @@
 use entity::team::Team as TeamEntity;
+use garde::Validate;
 use macros::dto;
+use serde::Deserialize;
 use ulid::Ulid;
 use utils::validation::RE_SENTENCE;
+use utoipa::IntoParams;
 
 dto! {
     ///
@@ -35,6 +38,11 @@ dto! {
             #[schema(format = Ulid, examples(Ulid::default))]
             #[garde(skip)]
             tour: Ulid,
+
+            ///
+            #[schema(format = Ulid, examples(Ulid::default))]
+            #[garde(skip)]
+            specialization: Ulid,
         },
         update
         ///
@@ -47,6 +55,60 @@ dto! {
     }
 }
 
+dto! {
+    ///
+    SpecializationCount {
+        fields {
+            ///
+            #[schema(format = Ulid, examples(Ulid::default))]
+            specialization: Ulid,
+
+            ///
+            #[schema(minimum = 1, examples(1))]
+            count: u16,
+        },
+    }
+
+    ///
+    TeamComposition {
+        fields {
+            ///
+            #[schema(format = Ulid, examples(Ulid::default))]
+            team_id: Ulid,
+
+            ///
+            #[schema(minimum = 0, maximum = 10, examples(3))]
+            members: u16,
+
+            ///
+            #[schema(minimum = 1, maximum = 10, examples(5))]
+            max_members: u16,
+
+            ///
+            missing: Vec<SpecializationCount>,
+
+            ///
+            duplicated: Vec<SpecializationCount>,
+
+            ///
+            over_capacity: bool,
+
+            ///
+            ready: bool,
+        },
+    }
+}
+
+///
+#[derive(Deserialize, Validate, IntoParams, Debug)]
+#[into_params(style = Form, parameter_in = Query)]
+pub struct TeamFilter {
+    ///
+    #[param(format = Ulid)]
+    #[garde(skip)]
+    pub technology_id: Ulid,
+}
+
 impl From<TeamEntity> for Team {
     #[tracing::instrument(skip_all, level = "trace")]
     fn from(entity: TeamEntity) -> Self {
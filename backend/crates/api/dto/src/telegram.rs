@@ -0,0 +1,19 @@
+use chrono::{DateTime, Utc};
+use macros::dto;
+
+dto! {
+    ///
+    TelegramLink {
+        fields {
+            ///
+            code: String,
+
+            ///
+            #[schema(format = Uri)]
+            url: String,
+
+            ///
+            expires_at: DateTime<Utc>,
+        },
+    }
+}
@@ -28,6 +28,10 @@ dto! {
             #[schema(minimum = 1, maximum = 10, examples(5))]
             max_members: u16,
 
+            ///
+            #[schema(minimum = 1, maximum = 10, examples(2))]
+            max_mentors: Option<u16>,
+
             ///
             required_specializations: Vec<Ulid>,
         },
@@ -54,6 +58,11 @@ dto! {
             #[garde(range(min = 1, max = 10))]
             max_members: u16,
 
+            ///
+            #[schema(minimum = 1, maximum = 10, examples(2))]
+            #[garde(range(min = 1, max = 10))]
+            max_mentors: Option<u16>,
+
             ///
             #[garde(skip)]
             required_specializations: Vec<Ulid>,
@@ -81,6 +90,12 @@ dto! {
             #[garde(range(min = 1, max = 10))]
             max_members: u16,
 
+            ///
+            #[schema(minimum = 1, maximum = 10, examples(2))]
+            #[serde(default, deserialize_with = "crate::nullable")]
+            #[garde(range(min = 1, max = 10))]
+            max_mentors: Option<u16>,
+
             ///
             #[garde(skip)]
             required_specializations: Vec<Ulid>,
@@ -97,6 +112,7 @@ impl From<TourEntity> for Tour {
             starts_at: entity.starts_at,
             ends_at: entity.ends_at,
             max_members: entity.max_members,
+            max_mentors: entity.max_mentors,
             required_specializations: entity
                 .required_specializations
                 .into_iter()
@@ -0,0 +1,90 @@
+use entity::{participated_in::ParticipatedIn, technology::TechnologyId};
+use garde::Validate;
+use macros::{dto, request};
+use serde::Deserialize;
+use ulid::Ulid;
+use utoipa::IntoParams;
+
+dto! {
+    ///
+    TourResult {
+        fields {
+            ///
+            #[schema(format = Ulid, examples(Ulid::default))]
+            user_id: Ulid,
+
+            ///
+            #[schema(format = Ulid, examples(Ulid::default))]
+            tour_id: Ulid,
+
+            ///
+            #[schema(minimum = 0, maximum = 100, examples(87.5))]
+            score: f64,
+
+            ///
+            #[schema(format = Ulid, examples(Ulid::default))]
+            specialization: Ulid,
+
+            ///
+            technologies: Vec<Ulid>,
+        },
+        upsert
+        ///
+        {
+            ///
+            #[schema(minimum = 0, maximum = 100, examples(87.5))]
+            #[garde(range(min = 0.0, max = 100.0))]
+            score: f64,
+
+            ///
+            #[schema(format = Ulid, examples(Ulid::default))]
+            #[garde(skip)]
+            specialization: Ulid,
+
+            ///
+            #[garde(length(max = 57))]
+            technologies: Vec<Ulid>,
+        },
+    }
+}
+
+request! {
+    ///
+    TeamResult {
+        ///
+        #[schema(minimum = 0, maximum = 100, examples(87.5))]
+        #[garde(range(min = 0.0, max = 100.0))]
+        score: f64,
+
+        ///
+        #[garde(length(max = 57))]
+        technologies: Vec<Ulid>,
+    }
+}
+
+///
+#[derive(Deserialize, Validate, IntoParams, Debug)]
+#[into_params(style = Form, parameter_in = Query)]
+pub struct LeaderboardFilter {
+    ///
+    #[param(format = Ulid)]
+    #[garde(skip)]
+    pub specialization: Option<Ulid>,
+}
+
+impl From<ParticipatedIn> for TourResult {
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn from(entity: ParticipatedIn) -> Self {
+        Self {
+            user_id: entity.r#in.into(),
+            tour_id: entity.out.into(),
+            score: entity.score,
+            specialization: entity.specialization.into(),
+            technologies: entity
+                .technologies
+                .into_iter()
+                .map(TechnologyId::into)
+                .collect(),
+        }
+    }
+}
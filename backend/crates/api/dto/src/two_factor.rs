@@ -0,0 +1,40 @@
+use macros::dto;
+
+use crate::{session::SessionTokens, user::User};
+
+dto! {
+    ///
+    TwoFactorEnrollment {
+        fields {
+            ///
+            secret: String,
+
+            ///
+            #[schema(format = Uri)]
+            provisioning_uri: String,
+        },
+    }
+
+    ///
+    TwoFactorRecoveryCodes {
+        fields {
+            ///
+            recovery_codes: Vec<String>,
+        },
+    }
+
+    ///
+    TwoFactorChallenge {
+        fields {
+            ///
+            challenge_token: String,
+        },
+    }
+}
+
+/// Result of checking the password, which only completes the login for
+/// users without two-factor authentication.
+pub enum LoginOutcome {
+    Authenticated(User, SessionTokens),
+    TwoFactorRequired(TwoFactorChallenge),
+}
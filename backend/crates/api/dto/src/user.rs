@@ -22,6 +22,9 @@ use utoipa::ToSchema;
 #[serde(rename_all = "snake_case")]
 #[schema(default = "participant")]
 pub enum UserRole {
+    ///
+    #[strum(serialize = "superadmin")]
+    Superadmin,
     ///
     #[strum(serialize = "organizer")]
     Organizer,
@@ -36,6 +39,7 @@ impl From<UserRole> for UserEntityRole {
     fn from(role: UserRole) -> Self {
         use UserRole as Role;
         match role {
+            Role::Superadmin => Self::Superadmin,
             Role::Organizer => Self::Organizer,
             Role::Mentor => Self::Mentor,
             Role::Participant => Self::Participant,
@@ -46,6 +50,7 @@ impl From<UserEntityRole> for UserRole {
     fn from(role: UserEntityRole) -> Self {
         use UserEntityRole as Role;
         match role {
+            Role::Superadmin => Self::Superadmin,
             Role::Organizer => Self::Organizer,
             Role::Mentor => Self::Mentor,
             Role::Participant => Self::Participant,
@@ -74,6 +79,15 @@ dto! {
 
             ///
             has_profile: bool,
+
+            ///
+            email_verified: bool,
+
+            ///
+            telegram_linked: bool,
+
+            ///
+            two_factor_enabled: bool,
         },
         create
         ///
@@ -122,6 +136,9 @@ impl From<UserEntity> for User {
             username: entity.username,
             role: entity.role.into(),
             has_profile: entity.profile.is_some(),
+            email_verified: entity.email_verified,
+            telegram_linked: entity.telegram_chat_id.is_some(),
+            two_factor_enabled: entity.totp_enabled,
         }
     }
 }
@@ -1,17 +1,20 @@
 use macros::entity;
 
-use crate::{team::TeamId, user::UserId};
+use crate::{specialization::SpecializationId, team::TeamId, user::UserId};
 
 entity! {
     UserId -> AppliedToJoin -> TeamId {
         fields {
             application: String,
+            specialization: SpecializationId,
         },
         create {
             application: String,
+            specialization: SpecializationId,
         },
         update {
             application: String,
+            specialization: SpecializationId,
         }
     }
 }
@@ -24,6 +27,7 @@ impl From<CreateAppliedToJoin> for AppliedToJoin {
             r#in: create_relation.r#in,
             out: create_relation.out,
             application: create_relation.application,
+            specialization: create_relation.specialization,
         }
     }
 }
@@ -0,0 +1,29 @@
+use macros::entity;
+use ulid::Ulid;
+
+use crate::user::UserId;
+
+entity! {
+    EmailVerification {
+        id: Ulid,
+        fields {
+            user: UserId,
+            email: String,
+        },
+        create {
+            user: UserId,
+            email: String,
+        }
+    }
+}
+
+impl From<CreateEmailVerification> for EmailVerification {
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn from(create_entity: CreateEmailVerification) -> Self {
+        Self {
+            id: EmailVerificationId::from(Ulid::new()),
+            user: create_entity.user,
+            email: create_entity.email,
+        }
+    }
+}
@@ -1,15 +1,26 @@
 pub mod applied_to_join;
+pub mod email_verification;
 pub mod has_experience_as;
 pub mod image;
 pub mod knows;
+pub mod mail;
 pub mod member_of;
 pub mod mentors;
+pub mod mentors_in;
+pub mod notification;
+pub mod notification_settings;
+pub mod organizes;
+pub mod outbox_event;
 pub mod participated_in;
+pub mod password_reset;
 pub mod profile;
+pub mod recommendation;
 pub mod reviewed;
+pub mod session;
 pub mod specialization;
 pub mod team;
 pub mod technology;
+pub mod telegram_link;
 pub mod tour;
 pub mod user;
 pub mod uses;
@@ -0,0 +1,9 @@
+use std::collections::HashMap;
+
+#[derive(Debug)]
+pub struct Mail {
+    pub recipient: String,
+    pub template: String,
+    pub locale: Option<String>,
+    pub variables: HashMap<String, String>,
+}
@@ -0,0 +1,18 @@
+use macros::entity;
+
+use crate::{tour::TourId, user::UserId};
+
+entity! {
+    UserId -> MentorsIn -> TourId { }
+}
+
+impl From<CreateMentorsIn> for MentorsIn {
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn from(create_relation: CreateMentorsIn) -> Self {
+        Self {
+            id: create_relation.get_id(),
+            r#in: create_relation.r#in,
+            out: create_relation.out,
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use strum_macros::Display;
+
+#[derive(Deserialize, Serialize, Display, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationCategory {
+    #[strum(serialize = "application_accepted")]
+    ApplicationAccepted,
+    #[strum(serialize = "new_applicant")]
+    NewApplicant,
+    #[strum(serialize = "review_received")]
+    ReviewReceived,
+    #[strum(serialize = "tour_starting")]
+    TourStarting,
+}
+
+#[derive(Debug)]
+pub struct Notification {
+    pub chat_id: i64,
+    pub category: NotificationCategory,
+    pub variables: HashMap<String, String>,
+}
@@ -0,0 +1,67 @@
+use macros::entity;
+use ulid::Ulid;
+
+use crate::notification::NotificationCategory;
+
+entity! {
+    NotificationSettings {
+        id: Ulid,
+        fields {
+            application_accepted: bool,
+            new_applicant: bool,
+            review_received: bool,
+            tour_starting: bool,
+        },
+        upsert {
+            application_accepted: bool,
+            new_applicant: bool,
+            review_received: bool,
+            tour_starting: bool,
+        },
+    }
+}
+
+impl From<(UpsertNotificationSettings, NotificationSettingsId)>
+    for NotificationSettings
+{
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn from(
+        (upsert_entity, id): (
+            UpsertNotificationSettings,
+            NotificationSettingsId,
+        ),
+    ) -> Self {
+        Self {
+            id,
+            application_accepted: upsert_entity.application_accepted,
+            new_applicant: upsert_entity.new_applicant,
+            review_received: upsert_entity.review_received,
+            tour_starting: upsert_entity.tour_starting,
+        }
+    }
+}
+
+impl NotificationSettings {
+    /// Every category is enabled until the user opts out of it.
+    #[tracing::instrument(skip_all, level = "trace")]
+    pub fn enabled_by_default(id: NotificationSettingsId) -> Self {
+        Self {
+            id,
+            application_accepted: true,
+            new_applicant: true,
+            review_received: true,
+            tour_starting: true,
+        }
+    }
+
+    #[tracing::instrument(skip_all, level = "trace")]
+    pub fn is_enabled(&self, category: NotificationCategory) -> bool {
+        use NotificationCategory as NC;
+        match category {
+            NC::ApplicationAccepted => self.application_accepted,
+            NC::NewApplicant => self.new_applicant,
+            NC::ReviewReceived => self.review_received,
+            NC::TourStarting => self.tour_starting,
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use macros::entity;
+
+use crate::{tour::TourId, user::UserId};
+
+entity! {
+    UserId -> Organizes -> TourId { }
+}
+
+impl From<CreateOrganizes> for Organizes {
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn from(create_relation: CreateOrganizes) -> Self {
+        Self {
+            id: create_relation.get_id(),
+            r#in: create_relation.r#in,
+            out: create_relation.out,
+        }
+    }
+}
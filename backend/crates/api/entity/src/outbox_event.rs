@@ -0,0 +1,72 @@
+use chrono::{DateTime, Utc};
+use macros::entity;
+use serde::{Deserialize, Serialize};
+use strum_macros::Display;
+use ulid::Ulid;
+
+/// A change other services have to learn about. Only identifiers are kept,
+/// as the details are looked up when the event is delivered.
+#[derive(Deserialize, Serialize, Display, Clone, PartialEq, Debug)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DomainEvent {
+    #[strum(serialize = "user_registered")]
+    UserRegistered { user: Ulid },
+    #[strum(serialize = "application_submitted")]
+    ApplicationSubmitted { user: Ulid, team: Ulid },
+    #[strum(serialize = "member_accepted")]
+    MemberAccepted { user: Ulid, team: Ulid },
+    #[strum(serialize = "review_upserted")]
+    ReviewUpserted {
+        reviewer: Ulid,
+        reviewee: Ulid,
+        score: u16,
+    },
+}
+
+#[derive(Deserialize, Serialize, Display, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum OutboxStatus {
+    #[strum(serialize = "pending")]
+    Pending,
+    #[strum(serialize = "dead_lettered")]
+    DeadLettered,
+}
+
+entity! {
+    OutboxEvent {
+        id: Ulid,
+        fields {
+            event: DomainEvent,
+            status: OutboxStatus,
+            attempts: u32,
+            next_attempt_at: DateTime<Utc>,
+            last_error: Option<String>,
+            created_at: DateTime<Utc>,
+        },
+        create {
+            event: DomainEvent,
+        },
+        update {
+            status: OutboxStatus,
+            attempts: u32,
+            next_attempt_at: DateTime<Utc>,
+            last_error: String,
+        }
+    }
+}
+
+impl From<CreateOutboxEvent> for OutboxEvent {
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn from(create_entity: CreateOutboxEvent) -> Self {
+        let now = Utc::now();
+        Self {
+            id: OutboxEventId::from(Ulid::new()),
+            event: create_entity.event,
+            status: OutboxStatus::Pending,
+            attempts: 0,
+            next_attempt_at: now,
+            last_error: None,
+            created_at: now,
+        }
+    }
+}
@@ -8,17 +8,17 @@ use crate::{
 entity! {
     UserId -> ParticipatedIn -> TourId {
         fields {
-            score: u16,
+            score: f64,
             specialization: SpecializationId,
             technologies: Vec<TechnologyId>,
         },
         create {
-            score: u16,
+            score: f64,
             specialization: SpecializationId,
             technologies: Vec<TechnologyId>,
         },
         update {
-            score: u16,
+            score: f64,
             specialization: SpecializationId,
             technologies: Vec<TechnologyId>,
         }
@@ -0,0 +1,33 @@
+use chrono::{DateTime, Utc};
+use macros::entity;
+use ulid::Ulid;
+
+use crate::user::UserId;
+
+entity! {
+    PasswordReset {
+        id: Ulid,
+        fields {
+            user: UserId,
+            token_hash: String,
+            expires_at: DateTime<Utc>,
+        },
+        create {
+            user: UserId,
+            token_hash: String,
+            expires_at: DateTime<Utc>,
+        }
+    }
+}
+
+impl From<CreatePasswordReset> for PasswordReset {
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn from(create_entity: CreatePasswordReset) -> Self {
+        Self {
+            id: PasswordResetId::from(Ulid::new()),
+            user: create_entity.user,
+            token_hash: create_entity.token_hash,
+            expires_at: create_entity.expires_at,
+        }
+    }
+}
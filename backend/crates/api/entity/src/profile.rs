@@ -12,6 +12,8 @@ entity! {
             bio: String,
             portfolio_urls: Vec<String>,
             has_avatar: bool,
+            #[serde(default)]
+            telegram_verified: bool,
         },
         upsert {
             name: String,
@@ -21,6 +23,7 @@ entity! {
             bio: String,
             portfolio_urls: Vec<String>,
             has_avatar: bool,
+            telegram_verified: bool,
         },
     }
 }
@@ -37,6 +40,7 @@ impl From<(UpsertProfile, ProfileId)> for Profile {
             bio: upsert_entity.bio,
             portfolio_urls: upsert_entity.portfolio_urls,
             has_avatar: upsert_entity.has_avatar,
+            telegram_verified: upsert_entity.telegram_verified,
         }
     }
 }
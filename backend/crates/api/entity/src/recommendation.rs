@@ -0,0 +1,15 @@
+use serde::Deserialize;
+
+use crate::{
+    specialization::SpecializationId, technology::TechnologyId, user::UserId,
+};
+
+/// A candidate scored against a team, as ranked by the database.
+#[derive(Deserialize, Debug)]
+pub struct Recommendation {
+    pub user: UserId,
+    pub username: String,
+    pub score: f64,
+    pub specializations: Vec<SpecializationId>,
+    pub technologies: Vec<TechnologyId>,
+}
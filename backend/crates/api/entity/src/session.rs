@@ -0,0 +1,49 @@
+use chrono::{DateTime, Utc};
+use macros::entity;
+use ulid::Ulid;
+
+use crate::user::UserId;
+
+entity! {
+    Session {
+        id: Ulid,
+        fields {
+            user: UserId,
+            /// Shared by every refresh token issued since the login, so the
+            /// whole chain can be revoked at once.
+            family: Ulid,
+            token_hash: String,
+            /// Set once the token has been exchanged for a new one.
+            rotated: bool,
+            started_at: DateTime<Utc>,
+            created_at: DateTime<Utc>,
+            expires_at: DateTime<Utc>,
+        },
+        create {
+            user: UserId,
+            family: Ulid,
+            token_hash: String,
+            started_at: DateTime<Utc>,
+            expires_at: DateTime<Utc>,
+        },
+        update {
+            rotated: bool,
+        }
+    }
+}
+
+impl From<CreateSession> for Session {
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn from(create_entity: CreateSession) -> Self {
+        Self {
+            id: SessionId::from(Ulid::new()),
+            user: create_entity.user,
+            family: create_entity.family,
+            token_hash: create_entity.token_hash,
+            rotated: false,
+            started_at: create_entity.started_at,
+            created_at: Utc::now(),
+            expires_at: create_entity.expires_at,
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use chrono::{DateTime, Utc};
+use macros::entity;
+use ulid::Ulid;
+
+use crate::user::UserId;
+
+entity! {
+    TelegramLink {
+        id: Ulid,
+        fields {
+            user: UserId,
+            code: String,
+            expires_at: DateTime<Utc>,
+        },
+        create {
+            user: UserId,
+            code: String,
+            expires_at: DateTime<Utc>,
+        }
+    }
+}
+
+impl From<CreateTelegramLink> for TelegramLink {
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn from(create_entity: CreateTelegramLink) -> Self {
+        Self {
+            id: TelegramLinkId::from(Ulid::new()),
+            user: create_entity.user,
+            code: create_entity.code,
+            expires_at: create_entity.expires_at,
+        }
+    }
+}
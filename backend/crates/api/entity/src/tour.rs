@@ -12,20 +12,27 @@ entity! {
             starts_at: DateTime<Utc>,
             ends_at: DateTime<Utc>,
             max_members: u16,
+            max_mentors: Option<u16>,
             required_specializations: Vec<SpecializationId>,
+            #[serde(default)]
+            reminder_sent: bool,
         },
         create {
             name: String,
             starts_at: DateTime<Utc>,
             ends_at: DateTime<Utc>,
             max_members: u16,
+            max_mentors: Option<u16>,
             required_specializations: Vec<SpecializationId>,
         },
         update {
             name: String,
             starts_at: DateTime<Utc>,
             ends_at: DateTime<Utc>,
+            max_members: u16,
+            max_mentors: Option<u16>,
             required_specializations: Vec<SpecializationId>,
+            reminder_sent: bool,
         }
     }
 }
@@ -39,7 +46,9 @@ impl From<CreateTour> for Tour {
             starts_at: create_entity.starts_at,
             ends_at: create_entity.ends_at,
             max_members: create_entity.max_members,
+            max_mentors: create_entity.max_mentors,
             required_specializations: create_entity.required_specializations,
+            reminder_sent: false,
         }
     }
 }
@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use macros::entity;
 use serde::{Deserialize, Serialize};
 use strum_macros::Display;
@@ -8,6 +9,8 @@ use crate::profile::ProfileId;
 #[derive(Deserialize, Serialize, Display, Clone, Copy, PartialEq, Debug)]
 #[serde(rename_all = "snake_case")]
 pub enum UserRole {
+    #[strum(serialize = "superadmin")]
+    Superadmin,
     #[strum(serialize = "organizer")]
     Organizer,
     #[strum(serialize = "mentor")]
@@ -25,6 +28,22 @@ entity! {
             username: String,
             role: UserRole,
             profile: Option<ProfileId>,
+            #[serde(default)]
+            email_verified: bool,
+            #[serde(default)]
+            password_changed_at: Option<DateTime<Utc>>,
+            #[serde(default)]
+            telegram_chat_id: Option<i64>,
+            #[serde(default)]
+            telegram_username: Option<String>,
+            #[serde(default)]
+            totp_secret: Option<String>,
+            #[serde(default)]
+            totp_enabled: bool,
+            #[serde(default)]
+            totp_recovery_codes: Vec<String>,
+            #[serde(default)]
+            totp_last_step: Option<u64>,
         },
         create {
             email: String,
@@ -38,6 +57,14 @@ entity! {
             username: String,
             role: UserRole,
             profile: Option<ProfileId>,
+            email_verified: bool,
+            password_changed_at: Option<DateTime<Utc>>,
+            telegram_chat_id: Option<i64>,
+            telegram_username: Option<String>,
+            totp_secret: Option<String>,
+            totp_enabled: bool,
+            totp_recovery_codes: Vec<String>,
+            totp_last_step: Option<u64>,
         }
     }
 }
@@ -52,6 +79,14 @@ impl From<CreateUser> for User {
             username: create_entity.username,
             role: create_entity.role,
             profile: None,
+            email_verified: false,
+            password_changed_at: None,
+            telegram_chat_id: None,
+            telegram_username: None,
+            totp_secret: None,
+            totp_enabled: false,
+            totp_recovery_codes: vec![],
+            totp_last_step: None,
         }
     }
 }
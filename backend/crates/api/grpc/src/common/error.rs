@@ -0,0 +1,56 @@
+use service::common::ServiceError;
+use tonic::Status;
+
+#[derive(thiserror::Error, Debug)]
+pub enum GrpcError {
+    #[error("Telegram chat is not linked to any account")]
+    NotLinked,
+
+    #[error("Missing `{0}` field")]
+    MissingField(&'static str),
+
+    #[error("Invalid id: {0}")]
+    InvalidId(#[from] ulid::DecodeError),
+
+    #[error("{0}")]
+    Validation(#[from] garde::Report),
+
+    #[error(transparent)]
+    Service(#[from] ServiceError),
+}
+
+impl From<GrpcError> for Status {
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn from(err: GrpcError) -> Self {
+        use ServiceError as SE;
+        match err {
+            GrpcError::NotLinked => Self::unauthenticated(err.to_string()),
+            GrpcError::MissingField(..)
+            | GrpcError::InvalidId(..)
+            | GrpcError::Validation(..) => {
+                Self::invalid_argument(err.to_string())
+            },
+            GrpcError::Service(err) => match err {
+                SE::BadRequest(..)
+                | SE::UnsupportedMediaType {
+                    ..
+                } => Self::invalid_argument(err.to_string()),
+                SE::InvalidPassword | SE::Unauthorized(..) => {
+                    Self::unauthenticated(err.to_string())
+                },
+                SE::Forbidden(..) | SE::AccessDenied => {
+                    Self::permission_denied(err.to_string())
+                },
+                SE::NotFound(..) => Self::not_found(err.to_string()),
+                SE::AlreadyExists(..) => Self::already_exists(err.to_string()),
+                SE::Conflict(..) => Self::failed_precondition(err.to_string()),
+                SE::PayloadTooLarge(..) => {
+                    Self::resource_exhausted(err.to_string())
+                },
+                SE::Hasher(..) | SE::Database(..) | SE::Internal(..) => {
+                    Self::internal(err.to_string())
+                },
+            },
+        }
+    }
+}
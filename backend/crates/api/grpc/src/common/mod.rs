@@ -0,0 +1,3 @@
+pub use error::GrpcError;
+
+pub mod error;
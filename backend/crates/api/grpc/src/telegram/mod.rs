@@ -0,0 +1,332 @@
+use std::str::FromStr;
+
+use dto::{
+    application::{Application, CreateApplication},
+    team::{Team, TeamComposition},
+    tour::Tour,
+    user::User,
+};
+use garde::Validate as _;
+use proto::{
+    api::{
+        Application as ProtoApplication, ApplicationDecisionRequest,
+        ApplicationDecisionResponse, ApplyRequest, ApplyResponse,
+        GetTeamNeedsRequest, GetTeamNeedsResponse, LinkAccountRequest,
+        LinkAccountResponse, ListApplicationsRequest, ListApplicationsResponse,
+        ListOpenTeamsRequest, ListOpenTeamsResponse, SpecializationNeed,
+        Team as ProtoTeam, Tour as ProtoTour,
+        telegram_service_server::{self, TelegramServiceServer},
+    },
+    auth::TokenInterceptor,
+};
+use service::{
+    application::ApplicationServiceDependency, permission::Actor,
+    specialization::SpecializationServiceDependency,
+    team::TeamServiceDependency, telegram::TelegramServiceDependency,
+    tour::TourServiceDependency, user::UserServiceDependency,
+};
+use tonic::{Request, Response, Status, codegen::InterceptedService};
+use ulid::Ulid;
+
+use crate::common::GrpcError;
+
+const MAX_PAGE_SIZE: u32 = 57;
+
+pub struct TelegramGrpc {
+    telegram_service: TelegramServiceDependency,
+    user_service: UserServiceDependency,
+    tour_service: TourServiceDependency,
+    team_service: TeamServiceDependency,
+    specialization_service: SpecializationServiceDependency,
+    application_service: ApplicationServiceDependency,
+}
+impl TelegramGrpc {
+    #[tracing::instrument(skip_all, level = "trace")]
+    pub fn server(
+        telegram_service: TelegramServiceDependency,
+        user_service: UserServiceDependency,
+        tour_service: TourServiceDependency,
+        team_service: TeamServiceDependency,
+        specialization_service: SpecializationServiceDependency,
+        application_service: ApplicationServiceDependency,
+        interceptor: TokenInterceptor,
+    ) -> InterceptedService<TelegramServiceServer<Self>, TokenInterceptor> {
+        TelegramServiceServer::with_interceptor(
+            Self {
+                telegram_service,
+                user_service,
+                tour_service,
+                team_service,
+                specialization_service,
+                application_service,
+            },
+            interceptor,
+        )
+    }
+
+    /// Resolves the user the chat is linked to, as the bot acts on behalf
+    /// of whoever owns the chat.
+    #[tracing::instrument(skip_all, level = "trace")]
+    async fn linked_user(&self, chat_id: i64) -> Result<User, GrpcError> {
+        self.telegram_service
+            .find_user_by_chat(chat_id)
+            .await?
+            .ok_or(GrpcError::NotLinked)
+    }
+
+    #[tracing::instrument(skip_all, level = "trace")]
+    async fn team_summary(&self, team: Team) -> Result<ProtoTeam, GrpcError> {
+        let composition = self.team_service.get_composition(team.id).await?;
+        Ok(summary(team, &composition))
+    }
+
+    #[tracing::instrument(skip_all, level = "trace")]
+    async fn applicant(
+        &self,
+        application: Application,
+    ) -> Result<ProtoApplication, GrpcError> {
+        let user = self.user_service.get_by_id(application.user_id).await?;
+        let specialization = self
+            .specialization_service
+            .get_by_id(application.specialization)
+            .await?;
+
+        Ok(ProtoApplication {
+            user_id: user.id.to_string(),
+            username: user.username,
+            specialization: specialization.name,
+            application: application.application,
+        })
+    }
+}
+
+#[tonic::async_trait]
+impl telegram_service_server::TelegramService for TelegramGrpc {
+    #[tracing::instrument(skip_all, name = "TelegramGrpc::link_account")]
+    async fn link_account(
+        &self,
+        request: Request<LinkAccountRequest>,
+    ) -> Result<Response<LinkAccountResponse>, Status> {
+        let request = request.into_inner();
+        let user = self
+            .telegram_service
+            .link(request.code, request.chat_id, request.username)
+            .await
+            .map_err(GrpcError::from)?;
+
+        Ok(Response::new(user.into()))
+    }
+
+    #[tracing::instrument(skip_all, name = "TelegramGrpc::list_open_teams")]
+    async fn list_open_teams(
+        &self,
+        request: Request<ListOpenTeamsRequest>,
+    ) -> Result<Response<ListOpenTeamsResponse>, Status> {
+        let request = request.into_inner();
+        let tour = self
+            .tour_service
+            .get_current()
+            .await
+            .map_err(GrpcError::from)?;
+
+        let teams = self
+            .team_service
+            .find_all_by_tour(
+                tour.id,
+                pagination(request.limit, request.offset),
+            )
+            .await
+            .map_err(GrpcError::from)?;
+        let has_more = u32::try_from(teams.len())
+            .is_ok_and(|fetched| fetched >= request.limit.min(MAX_PAGE_SIZE));
+        let mut open_teams = Vec::with_capacity(teams.len());
+        for team in teams {
+            let team = self.team_summary(team).await?;
+            if team.members < team.max_members {
+                open_teams.push(team);
+            }
+        }
+
+        Ok(Response::new(ListOpenTeamsResponse {
+            tour: Some(tour.into()),
+            teams: open_teams,
+            has_more,
+        }))
+    }
+
+    #[tracing::instrument(skip_all, name = "TelegramGrpc::get_team_needs")]
+    async fn get_team_needs(
+        &self,
+        request: Request<GetTeamNeedsRequest>,
+    ) -> Result<Response<GetTeamNeedsResponse>, Status> {
+        let team_id = parse_id(&request.into_inner().team_id)?;
+        let team = self
+            .team_service
+            .get_by_id(team_id)
+            .await
+            .map_err(GrpcError::from)?;
+        let composition = self
+            .team_service
+            .get_composition(team_id)
+            .await
+            .map_err(GrpcError::from)?;
+
+        let mut missing = Vec::with_capacity(composition.missing.len());
+        for need in &composition.missing {
+            let specialization = self
+                .specialization_service
+                .get_by_id(need.specialization)
+                .await
+                .map_err(GrpcError::from)?;
+            missing.push(SpecializationNeed {
+                specialization_id: specialization.id.to_string(),
+                name: specialization.name,
+                count: need.count.into(),
+            });
+        }
+
+        Ok(Response::new(GetTeamNeedsResponse {
+            team: Some(summary(team, &composition)),
+            missing,
+        }))
+    }
+
+    #[tracing::instrument(skip_all, name = "TelegramGrpc::apply")]
+    async fn apply(
+        &self,
+        request: Request<ApplyRequest>,
+    ) -> Result<Response<ApplyResponse>, Status> {
+        let request = request.into_inner();
+        let team_id = parse_id(&request.team_id)?;
+        let application = CreateApplication {
+            specialization: parse_id(&request.specialization_id)?,
+            application: request.application,
+        };
+        application.validate().map_err(GrpcError::from)?;
+        let user = self.linked_user(request.chat_id).await?;
+
+        self.application_service
+            .apply(user.id, team_id, application)
+            .await
+            .map_err(GrpcError::from)?;
+
+        Ok(Response::new(ApplyResponse {}))
+    }
+
+    #[tracing::instrument(skip_all, name = "TelegramGrpc::list_applications")]
+    async fn list_applications(
+        &self,
+        request: Request<ListApplicationsRequest>,
+    ) -> Result<Response<ListApplicationsResponse>, Status> {
+        let request = request.into_inner();
+        let user = self.linked_user(request.chat_id).await?;
+        let tour = self
+            .tour_service
+            .get_current()
+            .await
+            .map_err(GrpcError::from)?;
+        let team = self
+            .team_service
+            .get_by_tour_and_lead(tour.id, user.id)
+            .await
+            .map_err(GrpcError::from)?;
+
+        let applications = self
+            .application_service
+            .find_all_by_team(
+                team.id,
+                Actor::from(&user),
+                pagination(request.limit, request.offset),
+            )
+            .await
+            .map_err(GrpcError::from)?;
+        let mut applicants = Vec::with_capacity(applications.len());
+        for application in applications {
+            applicants.push(self.applicant(application).await?);
+        }
+
+        Ok(Response::new(ListApplicationsResponse {
+            team: Some(self.team_summary(team).await?),
+            applications: applicants,
+        }))
+    }
+
+    #[tracing::instrument(skip_all, name = "TelegramGrpc::accept_application")]
+    async fn accept_application(
+        &self,
+        request: Request<ApplicationDecisionRequest>,
+    ) -> Result<Response<ApplicationDecisionResponse>, Status> {
+        let request = request.into_inner();
+        let (team_id, user_id) =
+            (parse_id(&request.team_id)?, parse_id(&request.user_id)?);
+        let lead = self.linked_user(request.chat_id).await?;
+
+        self.application_service
+            .accept(team_id, user_id, Actor::from(&lead))
+            .await
+            .map_err(GrpcError::from)?;
+
+        Ok(Response::new(ApplicationDecisionResponse {}))
+    }
+
+    #[tracing::instrument(skip_all, name = "TelegramGrpc::reject_application")]
+    async fn reject_application(
+        &self,
+        request: Request<ApplicationDecisionRequest>,
+    ) -> Result<Response<ApplicationDecisionResponse>, Status> {
+        let request = request.into_inner();
+        let (team_id, user_id) =
+            (parse_id(&request.team_id)?, parse_id(&request.user_id)?);
+        let lead = self.linked_user(request.chat_id).await?;
+
+        self.application_service
+            .reject(team_id, user_id, Actor::from(&lead))
+            .await
+            .map_err(GrpcError::from)?;
+
+        Ok(Response::new(ApplicationDecisionResponse {}))
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+fn parse_id(id: &str) -> Result<Ulid, GrpcError> {
+    Ok(Ulid::from_str(id)?)
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+fn pagination(limit: u32, offset: u64) -> (u16, u64) {
+    (
+        u16::try_from(limit.min(MAX_PAGE_SIZE)).unwrap_or_default(),
+        offset,
+    )
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+fn summary(team: Team, composition: &TeamComposition) -> ProtoTeam {
+    ProtoTeam {
+        id: team.id.to_string(),
+        name: team.name,
+        members: composition.members.into(),
+        max_members: composition.max_members.into(),
+    }
+}
+
+impl From<User> for LinkAccountResponse {
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn from(user: User) -> Self {
+        Self {
+            user_id: user.id.to_string(),
+            username: user.username,
+        }
+    }
+}
+
+impl From<Tour> for ProtoTour {
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn from(tour: Tour) -> Self {
+        Self {
+            id: tour.id.to_string(),
+            name: tour.name,
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use std::str::FromStr;
+
+use dto::user::User;
+use proto::{
+    api::{
+        GetUserRequest, GetUserResponse,
+        get_user_request::Lookup,
+        user_service_server::{self, UserServiceServer},
+    },
+    auth::TokenInterceptor,
+};
+use service::{
+    common::ServiceError, telegram::TelegramServiceDependency,
+    user::UserServiceDependency,
+};
+use tonic::{Request, Response, Status, codegen::InterceptedService};
+use ulid::Ulid;
+
+use crate::common::GrpcError;
+
+pub struct UserGrpc {
+    user_service: UserServiceDependency,
+    telegram_service: TelegramServiceDependency,
+}
+impl UserGrpc {
+    #[tracing::instrument(skip_all, level = "trace")]
+    pub fn server(
+        user_service: UserServiceDependency,
+        telegram_service: TelegramServiceDependency,
+        interceptor: TokenInterceptor,
+    ) -> InterceptedService<UserServiceServer<Self>, TokenInterceptor> {
+        UserServiceServer::with_interceptor(
+            Self {
+                user_service,
+                telegram_service,
+            },
+            interceptor,
+        )
+    }
+}
+
+#[tonic::async_trait]
+impl user_service_server::UserService for UserGrpc {
+    #[tracing::instrument(skip_all, name = "UserGrpc::get_user")]
+    async fn get_user(
+        &self,
+        request: Request<GetUserRequest>,
+    ) -> Result<Response<GetUserResponse>, Status> {
+        let user = match request.into_inner().lookup {
+            Some(Lookup::UserId(id)) => self
+                .user_service
+                .get_by_id(Ulid::from_str(&id).map_err(GrpcError::from)?)
+                .await
+                .map_err(GrpcError::from)?,
+            Some(Lookup::TelegramChatId(chat_id)) => self
+                .telegram_service
+                .find_user_by_chat(chat_id)
+                .await
+                .map_err(GrpcError::from)?
+                .ok_or_else(|| {
+                    GrpcError::from(ServiceError::NotFound(
+                        "User with provided Telegram chat".into(),
+                    ))
+                })?,
+            None => Err(GrpcError::MissingField("lookup"))?,
+        };
+
+        Ok(Response::new(user.into()))
+    }
+}
+
+impl From<User> for GetUserResponse {
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn from(user: User) -> Self {
+        Self {
+            user_id: user.id.to_string(),
+            username: user.username,
+            email: user.email,
+            email_verified: user.email_verified,
+            telegram_linked: user.telegram_linked,
+        }
+    }
+}
@@ -0,0 +1,221 @@
+use actix_web::{
+    HttpResponse, delete, get, post,
+    web::{Data, Json},
+};
+use actix_web_lab::extract::{Path, Query};
+use actix_web_validation::Validated;
+use dto::{
+    Pagination,
+    application::{Application, CreateApplication},
+    member::Member,
+};
+use macros::handler_implementation;
+use service::{application::ApplicationServiceDependency, permission::Actor};
+use tracing::instrument;
+use ulid::Ulid;
+
+use super::{
+    ApplicationHandler, ApplicationHandlerHelper, ApplicationHandlerResult,
+};
+use crate::common::{
+    ApiError, ValidationError,
+    extractor::{Authorized, Participant},
+    openapi,
+};
+
+handler_implementation! {
+    ApplicationHandler as ApplicationHandlerImpl {
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                ("team_id" = Ulid, description = ""),
+            ),
+            security(
+                ("participant" = []),
+            ),
+            request_body(
+                description = "",
+                content = CreateApplication
+            ),
+            responses(
+                (status = 201, description = "", body = Application),
+                (status = 409, description = "", body = ApiError),
+                (status = 404, description = "", body = ApiError),
+                (status = 400, description = "", body = ValidationError),
+                (status = 403, description = "", body = ApiError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[post("/{team_id}")]
+        #[instrument(skip_all, name = "ApplicationHandler::apply_to_team_by_id")]
+        async fn apply_to_team_by_id(
+            application_service: Data<ApplicationServiceDependency>,
+            user: Authorized<Participant>,
+            Path(team_id): Path<Ulid>,
+            Validated(Json(body)): Validated<Json<CreateApplication>>,
+        ) -> HttpResponse {
+            let resp = application_service
+                .apply(user.id, team_id, body)
+                .await?;
+            HttpResponse::Created().json(resp)
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                ("team_id" = Ulid, description = ""),
+            ),
+            security(
+                ("participant" = []),
+            ),
+            responses(
+                (status = 204, description = ""),
+                (status = 404, description = "", body = ApiError),
+                (status = 403, description = "", body = ApiError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[delete("/{team_id}")]
+        #[instrument(skip_all, name = "ApplicationHandler::withdraw_application_by_team_id")]
+        async fn withdraw_application_by_team_id(
+            application_service: Data<ApplicationServiceDependency>,
+            user: Authorized<Participant>,
+            Path(team_id): Path<Ulid>,
+        ) -> HttpResponse {
+            application_service
+                .withdraw(user.id, team_id)
+                .await?;
+            HttpResponse::NoContent().finish()
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                Pagination,
+            ),
+            security(
+                ("participant" = []),
+            ),
+            responses(
+                (status = 200, description = "", body = Vec<Application>),
+                (status = 400, description = "", body = ValidationError),
+                (status = 403, description = "", body = ApiError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[get("/my")]
+        #[instrument(skip_all, name = "ApplicationHandler::get_current_applications_paginated")]
+        async fn get_current_applications_paginated(
+            application_service: Data<ApplicationServiceDependency>,
+            user: Authorized<Participant>,
+            Validated(Query(pagination)): Validated<Query<Pagination>>,
+        ) -> Json<Vec<Application>> {
+            let resp = application_service
+                .find_all_by_user(user.id, pagination.into())
+                .await?;
+            Json(resp)
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                ("team_id" = Ulid, description = ""),
+                Pagination,
+            ),
+            security(
+                ("participant" = []),
+            ),
+            responses(
+                (status = 200, description = "", body = Vec<Application>),
+                (status = 404, description = "", body = ApiError),
+                (status = 400, description = "", body = ValidationError),
+                (status = 403, description = "", body = ApiError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[get("/{team_id}")]
+        #[instrument(skip_all, name = "ApplicationHandler::get_applications_by_team_id_paginated")]
+        async fn get_applications_by_team_id_paginated(
+            application_service: Data<ApplicationServiceDependency>,
+            user: Authorized<Participant>,
+            Path(team_id): Path<Ulid>,
+            Validated(Query(pagination)): Validated<Query<Pagination>>,
+        ) -> Json<Vec<Application>> {
+            let resp = application_service
+                .find_all_by_team(team_id, Actor::from(&*user), pagination.into())
+                .await?;
+            Json(resp)
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                ("team_id" = Ulid, description = ""),
+                ("user_id" = Ulid, description = ""),
+            ),
+            security(
+                ("participant" = []),
+            ),
+            responses(
+                (status = 200, description = "", body = Member),
+                (status = 409, description = "", body = ApiError),
+                (status = 404, description = "", body = ApiError),
+                (status = 403, description = "", body = ApiError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[post("/{team_id}/{user_id}/accept")]
+        #[instrument(skip_all, name = "ApplicationHandler::accept_application_by_team_id_and_user_id")]
+        async fn accept_application_by_team_id_and_user_id(
+            application_service: Data<ApplicationServiceDependency>,
+            user: Authorized<Participant>,
+            Path((team_id, user_id)): Path<(Ulid, Ulid)>,
+        ) -> Json<Member> {
+            let resp = application_service
+                .accept(team_id, user_id, Actor::from(&*user))
+                .await?;
+            Json(resp)
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                ("team_id" = Ulid, description = ""),
+                ("user_id" = Ulid, description = ""),
+            ),
+            security(
+                ("participant" = []),
+            ),
+            responses(
+                (status = 204, description = ""),
+                (status = 404, description = "", body = ApiError),
+                (status = 403, description = "", body = ApiError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[post("/{team_id}/{user_id}/reject")]
+        #[instrument(skip_all, name = "ApplicationHandler::reject_application_by_team_id_and_user_id")]
+        async fn reject_application_by_team_id_and_user_id(
+            application_service: Data<ApplicationServiceDependency>,
+            user: Authorized<Participant>,
+            Path((team_id, user_id)): Path<(Ulid, Ulid)>,
+        ) -> HttpResponse {
+            application_service
+                .reject(team_id, user_id, Actor::from(&*user))
+                .await?;
+            HttpResponse::NoContent().finish()
+        }
+    }
+}
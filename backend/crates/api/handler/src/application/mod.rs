@@ -0,0 +1,84 @@
+use actix_web::{
+    HttpResponse,
+    middleware::from_fn,
+    web::{Data, Json},
+};
+use actix_web_lab::extract::{Path, Query};
+use actix_web_validation::Validated;
+use dto::{
+    Pagination,
+    application::{Application, CreateApplication},
+    member::Member,
+};
+use macros::handler;
+use service::application::ApplicationServiceDependency;
+use ulid::Ulid;
+use utoipa_actix_web::{scope, service_config::ServiceConfig};
+
+use crate::common::{
+    HandlerError,
+    extractor::{Authorized, Participant},
+    middleware::user_extractor_middleware,
+};
+
+pub mod implementation;
+
+handler! {
+    Application
+        Err: HandlerError,
+        Impl: ImplementedApplicationHandler
+    {
+        fn routes(application_service: ApplicationServiceDependency) {
+            move |cfg: &mut ServiceConfig| {
+                cfg.app_data(Data::new(application_service))
+                    .service(scope("/applications")
+                        .wrap(from_fn(user_extractor_middleware))
+                        .service(Self::get_current_applications_paginated())
+                        .service(Self::get_applications_by_team_id_paginated())
+                        .service(Self::apply_to_team_by_id())
+                        .service(Self::withdraw_application_by_team_id())
+                        .service(Self::accept_application_by_team_id_and_user_id())
+                        .service(Self::reject_application_by_team_id_and_user_id())
+                    );
+            }
+        }
+
+        async fn apply_to_team_by_id(
+            application_service: Data<ApplicationServiceDependency>,
+            user: Authorized<Participant>,
+            path: Path<Ulid>,
+            body: Validated<Json<CreateApplication>>,
+        ) -> HttpResponse;
+
+        async fn withdraw_application_by_team_id(
+            application_service: Data<ApplicationServiceDependency>,
+            user: Authorized<Participant>,
+            path: Path<Ulid>,
+        ) -> HttpResponse;
+
+        async fn get_current_applications_paginated(
+            application_service: Data<ApplicationServiceDependency>,
+            user: Authorized<Participant>,
+            query: Validated<Query<Pagination>>,
+        ) -> Json<Vec<Application>>;
+
+        async fn get_applications_by_team_id_paginated(
+            application_service: Data<ApplicationServiceDependency>,
+            user: Authorized<Participant>,
+            path: Path<Ulid>,
+            query: Validated<Query<Pagination>>,
+        ) -> Json<Vec<Application>>;
+
+        async fn accept_application_by_team_id_and_user_id(
+            application_service: Data<ApplicationServiceDependency>,
+            user: Authorized<Participant>,
+            path: Path<(Ulid, Ulid)>,
+        ) -> Json<Member>;
+
+        async fn reject_application_by_team_id_and_user_id(
+            application_service: Data<ApplicationServiceDependency>,
+            user: Authorized<Participant>,
+            path: Path<(Ulid, Ulid)>,
+        ) -> HttpResponse;
+    }
+}
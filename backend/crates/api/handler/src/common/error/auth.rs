@@ -20,6 +20,14 @@ pub enum AuthenticationError {
     #[response(status = 403)]
     #[error("Missing permissions")]
     MissingPermissions,
+
+    #[response(status = 403)]
+    #[error("Email is not verified")]
+    EmailNotVerified,
+
+    #[response(status = 403)]
+    #[error("Two-factor authentication has to be enabled")]
+    TwoFactorRequired,
 }
 
 impl ResponseError for AuthenticationError {
@@ -31,6 +39,8 @@ impl ResponseError for AuthenticationError {
             Self::InvalidCredentials => SC::UNAUTHORIZED,
             Self::InvalidAuthMethod => SC::UNAUTHORIZED,
             Self::MissingPermissions => SC::FORBIDDEN,
+            Self::EmailNotVerified => SC::FORBIDDEN,
+            Self::TwoFactorRequired => SC::FORBIDDEN,
         }
     }
 
@@ -51,6 +61,8 @@ impl AuthenticationError {
             Self::InvalidCredentials => "invalid_credentials",
             Self::InvalidAuthMethod => "invalid_auth_method",
             Self::MissingPermissions => "missing_permissions",
+            Self::EmailNotVerified => "email_not_verified",
+            Self::TwoFactorRequired => "two_factor_required",
         }
     }
 }
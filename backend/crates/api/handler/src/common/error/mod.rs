@@ -1,5 +1,10 @@
 #![allow(clippy::empty_docs)] // TODO: remove this
-use actix_web::{HttpResponse, ResponseError, http::StatusCode};
+use std::time::Duration;
+
+use actix_web::{
+    HttpResponse, ResponseError,
+    http::{StatusCode, header::RETRY_AFTER},
+};
 use serde::{Deserialize, Serialize};
 use service::common::ServiceError;
 use utoipa::ToSchema;
@@ -31,12 +36,18 @@ pub enum HandlerError {
     #[error("{0} already exists")]
     AlreadyExists(String),
 
+    #[error("{0}")]
+    Conflict(String),
+
     #[error("Payload size exceeds the limit of {0}")]
     PayloadTooLarge(String),
 
     #[error("Unsupported media type: {0}")]
     UnsupportedMediaType(String),
 
+    #[error("Too many requests, retry in {}s", retry_after_secs(.0))]
+    TooManyRequests(Duration),
+
     #[error("{0}")]
     Internal(String),
 }
@@ -48,9 +59,12 @@ impl From<ServiceError> for HandlerError {
         match err {
             SE::BadRequest(msg) => Self::BadRequest(msg),
             SE::InvalidPassword => Self::Unauthorized(err.to_string()),
+            SE::Unauthorized(msg) => Self::Unauthorized(msg),
             SE::Forbidden(msg) => Self::ForbiddenWithMsg(msg),
+            SE::AccessDenied => Self::Forbidden,
             SE::NotFound(msg) => Self::NotFound(msg),
             SE::AlreadyExists(msg) => Self::AlreadyExists(msg),
+            SE::Conflict(msg) => Self::Conflict(msg),
             SE::PayloadTooLarge(msg) => Self::PayloadTooLarge(msg),
             SE::UnsupportedMediaType {
                 ..
@@ -71,10 +85,12 @@ impl HandlerError {
                 Self::Forbidden | Self::ForbiddenWithMsg(..) => "access_denied",
                 Self::NotFound(..) => "not_found",
                 Self::AlreadyExists(..) => "already_exists",
+                Self::Conflict(..) => "conflict",
                 Self::PayloadTooLarge(..) => "payload_too_large",
                 Self::UnsupportedMediaType {
                     ..
                 } => "unsupported_media_type",
+                Self::TooManyRequests(..) => "too_many_requests",
                 Self::Internal(..) => "internal_error",
             }
             .to_string(),
@@ -92,18 +108,37 @@ impl ResponseError for HandlerError {
             Self::Unauthorized(..) => SC::UNAUTHORIZED,
             Self::Forbidden | Self::ForbiddenWithMsg(..) => SC::FORBIDDEN,
             Self::NotFound(..) => SC::NOT_FOUND,
-            Self::AlreadyExists(..) => SC::CONFLICT,
+            Self::AlreadyExists(..) | Self::Conflict(..) => SC::CONFLICT,
             Self::PayloadTooLarge(..) => SC::PAYLOAD_TOO_LARGE,
             Self::UnsupportedMediaType {
                 ..
             } => SC::UNSUPPORTED_MEDIA_TYPE,
+            Self::TooManyRequests(..) => SC::TOO_MANY_REQUESTS,
             Self::Internal(..) => SC::INTERNAL_SERVER_ERROR,
         }
     }
 
     #[tracing::instrument(skip_all, level = "trace")]
     fn error_response(&self) -> HttpResponse {
-        HttpResponse::build(self.status_code()).json(self.as_api_error())
+        let mut response = HttpResponse::build(self.status_code());
+        if let Self::TooManyRequests(retry_after) = self {
+            response.insert_header((
+                RETRY_AFTER,
+                retry_after_secs(retry_after).to_string(),
+            ));
+        }
+        response.json(self.as_api_error())
+    }
+}
+
+/// Whole seconds to wait, rounded up so clients never retry too early.
+#[tracing::instrument(skip_all, level = "trace")]
+fn retry_after_secs(retry_after: &Duration) -> u64 {
+    let secs = retry_after.as_secs();
+    if retry_after.subsec_nanos() > 0 {
+        secs.saturating_add(1)
+    } else {
+        secs
     }
 }
 
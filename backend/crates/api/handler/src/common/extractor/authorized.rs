@@ -0,0 +1,76 @@
+use std::{
+    future::{Ready, ready},
+    marker::PhantomData,
+    ops::Deref,
+};
+
+use actix_web::{FromRequest, HttpMessage, HttpRequest, dev::Payload};
+use dto::user::{User, UserRole};
+use service::permission::{
+    Actor, Permission,
+    policy::{self, Relations},
+};
+
+use crate::common::{AuthenticationError, HandlerError};
+
+/// Roles an [`Authorized`] user is required to have one of.
+pub trait Roles {
+    const ROLES: &'static [UserRole];
+}
+
+pub struct Superadmin;
+impl Roles for Superadmin {
+    const ROLES: &'static [UserRole] = &[UserRole::Superadmin];
+}
+
+/// Tour organizers and superadmins. Organizers are scoped to their tours,
+/// so handlers taking this still have to check the resource they act on.
+pub struct Organizer;
+impl Roles for Organizer {
+    const ROLES: &'static [UserRole] =
+        &[UserRole::Superadmin, UserRole::Organizer];
+}
+
+pub struct Participant;
+impl Roles for Participant {
+    const ROLES: &'static [UserRole] = &[UserRole::Participant];
+}
+
+/// Current user, extracted only if they are granted [`Permission::Role`]
+/// for `R`, so handlers declare the roles they require in their signature.
+/// Otherwise the request fails with [`HandlerError::Forbidden`].
+pub struct Authorized<R: Roles>(User, PhantomData<R>);
+impl<R: Roles> Authorized<R> {
+    pub fn into_inner(self) -> User {
+        self.0
+    }
+}
+impl<R: Roles> Deref for Authorized<R> {
+    type Target = User;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl<R: Roles> FromRequest for Authorized<R> {
+    type Error = HandlerError;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    #[tracing::instrument(name = "authorized", skip_all, level = "info")]
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        let Some(user) = req.extensions().get::<User>().cloned() else {
+            return ready(Err(AuthenticationError::InvalidCredentials.into()));
+        };
+
+        let granted = policy::is_granted(
+            &Actor::from(&user),
+            Permission::Role(R::ROLES),
+            Relations::default(),
+        );
+        ready(if granted {
+            Ok(Self(user, PhantomData))
+        } else {
+            Err(HandlerError::Forbidden)
+        })
+    }
+}
@@ -1,3 +0,0 @@
-mod role;
-
-pub use role::UserRoleGuard;
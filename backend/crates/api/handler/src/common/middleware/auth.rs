@@ -12,22 +12,35 @@ use dto::user::UserRole;
 use service::user::UserServiceDependency;
 use ulid::Ulid;
 use utils::{
-    auth::{jsonwebtoken, jwt},
+    auth::{JwtKeys, jwt},
     chrono::Utc,
 };
 
-use crate::common::{AuthenticationError, HandlerError, wrapper::JwtSecret};
+use crate::common::{
+    AuthenticationError, HandlerError,
+    wrapper::{
+        CurrentSession, RequireOrganizerTwoFactor, RequireVerifiedEmail,
+    },
+};
+
+/// Routes that stay available to users who don't meet
+/// [`RequireVerifiedEmail`] or [`RequireOrganizerTwoFactor`], so they can
+/// still manage their own account, request a new verification email and
+/// enable two-factor authentication.
+const ACCOUNT_SCOPE: &str = "/users/me";
 
 #[tracing::instrument(skip_all, level = "info")]
 pub async fn user_extractor_middleware(
-    jwt_secret: Data<JwtSecret>,
+    jwt_keys: Data<JwtKeys>,
+    require_verified_email: Data<RequireVerifiedEmail>,
+    require_organizer_two_factor: Data<RequireOrganizerTwoFactor>,
     user_service: Data<UserServiceDependency>,
     req: ServiceRequest,
     next: Next<impl MessageBody>,
 ) -> Result<ServiceResponse<impl MessageBody>, actix_web::Error> {
     let token = extract_token_from_authorization_header(&req)?;
 
-    let claims = jwt::parse(&token, &jwt_secret.0)
+    let claims = jwt::parse(&token, &jwt_keys)
         .ok_or(AuthenticationError::InvalidCredentials)?;
 
     if claims.iat
@@ -38,26 +51,42 @@ pub async fn user_extractor_middleware(
 
     let id = Ulid::from_string(&claims.sub)
         .map_err(|_| AuthenticationError::InvalidCredentials)?;
+    let session = claims
+        .sid
+        .as_deref()
+        .and_then(|sid| Ulid::from_string(sid).ok())
+        .ok_or(AuthenticationError::InvalidCredentials)?;
 
     let user = user_service
-        .find_by_id(id)
+        .find_by_session(id, session, claims.iat)
         .await
         .map_err(HandlerError::from)?
         .ok_or(AuthenticationError::InvalidCredentials)?;
 
-    let token_type = jsonwebtoken::decode_header(&token)
-        .map_err(|_| AuthenticationError::InvalidCredentials)?
-        .kid
-        .ok_or(AuthenticationError::InvalidCredentials)?;
-
-    let user_role = UserRole::from_str(&token_type)
+    let user_role = UserRole::from_str(&claims.kind)
         .map_err(|_| AuthenticationError::InvalidCredentials)?;
 
     if user_role != user.role {
         Err(AuthenticationError::InvalidCredentials)?
     }
 
+    if require_verified_email.0
+        && !user.email_verified
+        && !req.path().starts_with(ACCOUNT_SCOPE)
+    {
+        Err(AuthenticationError::EmailNotVerified)?
+    }
+
+    if require_organizer_two_factor.0
+        && matches!(user.role, UserRole::Superadmin | UserRole::Organizer)
+        && !user.two_factor_enabled
+        && !req.path().starts_with(ACCOUNT_SCOPE)
+    {
+        Err(AuthenticationError::TwoFactorRequired)?
+    }
+
     req.extensions_mut().insert(user);
+    req.extensions_mut().insert(CurrentSession(session));
     next.call(req).await
 }
 
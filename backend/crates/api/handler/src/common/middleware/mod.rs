@@ -1,3 +1,5 @@
 mod auth;
+mod rate_limit;
 
 pub use auth::user_extractor_middleware;
+pub use rate_limit::{RateLimiter, rate_limit_middleware};
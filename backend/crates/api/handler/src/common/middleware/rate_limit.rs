@@ -0,0 +1,150 @@
+use std::time::Duration;
+
+use actix_web::{
+    HttpRequest,
+    body::MessageBody,
+    dev::{ServiceRequest, ServiceResponse},
+    middleware::Next,
+    web::Data,
+};
+use utils::rate_limit::{LockoutPolicy, Quota, RateLimitStoreDependency};
+
+use crate::common::HandlerError;
+
+/// Routes that let anonymous clients guess credentials or tokens, so they
+/// get a stricter quota than the rest of the API.
+const AUTH_ROUTES: [&str; 5] = [
+    "/users/login",
+    "/users/refresh",
+    "/users/verify-email",
+    "/users/password-reset",
+    "/users/register",
+];
+
+/// Request quotas and login lockouts, shared by every worker.
+#[derive(Clone)]
+pub struct RateLimiter {
+    store: RateLimitStoreDependency,
+    auth_quota: Quota,
+    api_quota: Quota,
+    account_lockout: LockoutPolicy,
+    ip_lockout: LockoutPolicy,
+    trust_proxy: bool,
+}
+impl RateLimiter {
+    /// `trust_proxy` makes clients be told apart by the `Forwarded` and
+    /// `X-Forwarded-For` headers, so it must only be enabled behind a proxy
+    /// that overwrites them.
+    #[tracing::instrument(skip_all, level = "trace")]
+    pub fn new(
+        store: RateLimitStoreDependency,
+        auth_quota: Quota,
+        api_quota: Quota,
+        account_lockout: LockoutPolicy,
+        ip_lockout: LockoutPolicy,
+        trust_proxy: bool,
+    ) -> Self {
+        Self {
+            store,
+            auth_quota,
+            api_quota,
+            account_lockout,
+            ip_lockout,
+            trust_proxy,
+        }
+    }
+
+    #[tracing::instrument(skip_all, level = "trace")]
+    pub fn client_ip(&self, req: &HttpRequest) -> String {
+        let conn = req.connection_info();
+        let ip = if self.trust_proxy {
+            conn.realip_remote_addr()
+        } else {
+            conn.peer_addr()
+        };
+        ip.unwrap_or("unknown").to_string()
+    }
+
+    /// Fails with the time left if either the account or the IP is locked
+    /// out of logging in.
+    #[tracing::instrument(skip_all, level = "debug")]
+    pub async fn check_login(
+        &self,
+        account: &str,
+        ip: &str,
+    ) -> Result<(), HandlerError> {
+        let locked_for = self
+            .store
+            .locked_for(&account_key(account))
+            .await
+            .max(self.store.locked_for(&ip_key(ip)).await);
+        match locked_for {
+            Some(locked_for) => Err(HandlerError::TooManyRequests(locked_for)),
+            None => Ok(()),
+        }
+    }
+
+    #[tracing::instrument(skip_all, level = "debug")]
+    pub async fn login_failed(&self, account: &str, ip: &str) {
+        let account_lockout = self
+            .store
+            .record_failure(&account_key(account), self.account_lockout)
+            .await;
+        let ip_lockout = self
+            .store
+            .record_failure(&ip_key(ip), self.ip_lockout)
+            .await;
+        if let Some(lockout) = account_lockout.max(ip_lockout) {
+            tracing::warn!(
+                "Locked out login attempts from {ip} for {}s",
+                lockout.as_secs()
+            );
+        }
+    }
+
+    /// Only the account is forgiven, so an attacker can't reset the failures
+    /// of their IP by logging into an account of their own.
+    #[tracing::instrument(skip_all, level = "debug")]
+    pub async fn login_succeeded(&self, account: &str) {
+        self.store.reset_failures(&account_key(account)).await;
+    }
+
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn route_group(&self, path: &str) -> (&'static str, Quota) {
+        if AUTH_ROUTES.iter().any(|route| path.starts_with(route)) {
+            ("auth", self.auth_quota)
+        } else {
+            ("api", self.api_quota)
+        }
+    }
+
+    #[tracing::instrument(skip_all, level = "trace")]
+    async fn acquire(&self, req: &HttpRequest) -> Result<(), Duration> {
+        let (group, quota) = self.route_group(req.path());
+        let key = format!("{group}:{}", self.client_ip(req));
+        self.store.acquire(&key, quota).await
+    }
+}
+
+#[inline]
+fn account_key(account: &str) -> String {
+    format!("login:account:{}", account.to_lowercase())
+}
+
+#[inline]
+fn ip_key(ip: &str) -> String {
+    format!("login:ip:{ip}")
+}
+
+#[tracing::instrument(skip_all, level = "info")]
+pub async fn rate_limit_middleware(
+    rate_limiter: Data<RateLimiter>,
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, actix_web::Error> {
+    rate_limiter
+        .acquire(req.request())
+        .await
+        .map_err(HandlerError::TooManyRequests)?;
+    next.call(req).await
+}
@@ -1,5 +1,5 @@
 mod error;
-pub mod guard;
+pub mod extractor;
 pub mod middleware;
 pub mod wrapper;
 
@@ -1,3 +1,13 @@
-pub struct JwtSecret(pub String);
+use ulid::Ulid;
 
 pub struct BaseApiUrl(pub String);
+
+pub struct RequireVerifiedEmail(pub bool);
+
+/// Whether organizers have to enable two-factor authentication before
+/// using anything but their own account.
+pub struct RequireOrganizerTwoFactor(pub bool);
+
+/// Session of the access token the request was authenticated with.
+#[derive(Clone, Copy)]
+pub struct CurrentSession(pub Ulid);
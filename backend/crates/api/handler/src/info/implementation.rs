@@ -8,7 +8,10 @@ use tracing::instrument;
 use super::{
     ApiInfoResponse, InfoHandler, InfoHandlerHelper, InfoHandlerResult,
 };
-use crate::{common::openapi, info::BaseApiUrl};
+use crate::{
+    common::openapi,
+    info::{BaseApiUrl, JwkSet, JwtKeys},
+};
 
 handler_implementation! {
     InfoHandler as InfoHandlerImpl {
@@ -27,5 +30,23 @@ handler_implementation! {
         ) ->Json<ApiInfoResponse> {
             Json(ApiInfoResponse::new(&base_api_url.0))
         }
+
+        /// Fetch token verification keys
+        ///
+        /// Returns the JSON Web Key Set of public keys that access tokens
+        /// can be verified with, including retired keys whose tokens haven't
+        /// expired yet. Keys are matched by the `kid` token header.
+        #[openapi(
+            responses(
+                (status = 200, description = "Returned JSON Web Key Set", content_type = "application/json"),
+            ),
+        )]
+        #[get(".well-known/jwks.json")]
+        #[instrument(skip_all, name = "InfoHandler::jwks")]
+        async fn jwks(
+            jwt_keys: Data<JwtKeys>,
+        ) -> Json<JwkSet> {
+            Json(jwt_keys.jwks().clone())
+        }
     }
 }
@@ -1,5 +1,6 @@
 use actix_web::web::{Data, Json};
 use macros::{handler, response};
+use utils::auth::{JwtKeys, jsonwebtoken::jwk::JwkSet};
 use utoipa_actix_web::{scope, service_config::ServiceConfig};
 
 use crate::common::{HandlerError, wrapper::BaseApiUrl};
@@ -14,6 +15,7 @@ handler! {
             move |cfg: &mut ServiceConfig| {
                 cfg.service(scope("/")
                     .service(Self::info())
+                    .service(Self::jwks())
                 );
             }
         }
@@ -21,6 +23,10 @@ handler! {
         async fn info(
             base_api_url: Data<BaseApiUrl>,
         ) -> Json<ApiInfoResponse>;
+
+        async fn jwks(
+            jwt_keys: Data<JwtKeys>,
+        ) -> Json<JwkSet>;
     }
 }
 
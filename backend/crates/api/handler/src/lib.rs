@@ -3,10 +3,20 @@ use std::fmt::Display;
 use actix_web::{HttpRequest, HttpResponse};
 use common::{ApiError, ValidationError};
 
+pub mod application;
 pub mod common;
 pub mod info;
+pub mod mentor;
 pub mod profile;
+pub mod recommendation;
 pub mod review;
+pub mod skill;
+pub mod specialization;
+pub mod team;
+pub mod technology;
+pub mod telegram;
+pub mod tour;
+pub mod tour_result;
 pub mod user;
 
 #[tracing::instrument(skip_all, level = "trace")]
@@ -0,0 +1,148 @@
+use actix_web::{
+    HttpResponse, delete, get, put,
+    web::{Data, Json, ReqData},
+};
+use actix_web_lab::extract::{Path, Query};
+use actix_web_validation::Validated;
+use dto::{Pagination, mentor::Mentor, team::Team, user::User};
+use macros::handler_implementation;
+use service::{mentor::MentorServiceDependency, permission::Actor};
+use tracing::instrument;
+use ulid::Ulid;
+
+use super::{MentorHandler, MentorHandlerHelper, MentorHandlerResult};
+use crate::common::{
+    ApiError, ValidationError,
+    extractor::{Authorized, Organizer},
+    openapi,
+};
+
+handler_implementation! {
+    MentorHandler as MentorHandlerImpl {
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                Pagination,
+            ),
+            security(
+                ("mentor" = []),
+            ),
+            responses(
+                (status = 200, description = "", body = Vec<Team>),
+                (status = 400, description = "", body = ValidationError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[get("/my")]
+        #[instrument(skip_all, name = "MentorHandler::get_current_mentored_teams_paginated")]
+        async fn get_current_mentored_teams_paginated(
+            mentor_service: Data<MentorServiceDependency>,
+            user: ReqData<User>,
+            Validated(Query(pagination)): Validated<Query<Pagination>>,
+        ) -> Json<Vec<Team>> {
+            let resp = mentor_service
+                .find_all_teams_by_mentor(user.id, pagination.into())
+                .await?;
+            Json(resp)
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                ("team_id" = Ulid, description = ""),
+                Pagination,
+            ),
+            security(
+                ("participant" = []),
+                ("mentor" = []),
+                ("organizer" = []),
+            ),
+            responses(
+                (status = 200, description = "", body = Vec<Mentor>),
+                (status = 404, description = "", body = ApiError),
+                (status = 400, description = "", body = ValidationError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[get("/{team_id}")]
+        #[instrument(skip_all, name = "MentorHandler::get_mentors_by_team_id_paginated")]
+        async fn get_mentors_by_team_id_paginated(
+            mentor_service: Data<MentorServiceDependency>,
+            Path(team_id): Path<Ulid>,
+            Validated(Query(pagination)): Validated<Query<Pagination>>,
+        ) -> Json<Vec<Mentor>> {
+            let resp = mentor_service
+                .find_all_by_team(team_id, pagination.into())
+                .await?;
+            Json(resp)
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                ("team_id" = Ulid, description = ""),
+                ("user_id" = Ulid, description = ""),
+            ),
+            security(
+                ("organizer" = []),
+            ),
+            responses(
+                (status = 200, description = "", body = Mentor),
+                (status = 409, description = "", body = ApiError),
+                (status = 404, description = "", body = ApiError),
+                (status = 400, description = "", body = ApiError),
+                (status = 403, description = "", body = ApiError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[put("/{team_id}/{user_id}")]
+        #[instrument(skip_all, name = "MentorHandler::assign_mentor_by_team_id_and_user_id")]
+        async fn assign_mentor_by_team_id_and_user_id(
+            mentor_service: Data<MentorServiceDependency>,
+            user: Authorized<Organizer>,
+            Path((team_id, user_id)): Path<(Ulid, Ulid)>,
+        ) -> Json<Mentor> {
+            let resp = mentor_service
+                .assign(team_id, user_id, Actor::from(&*user))
+                .await?;
+            Json(resp)
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                ("team_id" = Ulid, description = ""),
+                ("user_id" = Ulid, description = ""),
+            ),
+            security(
+                ("organizer" = []),
+            ),
+            responses(
+                (status = 204, description = ""),
+                (status = 404, description = "", body = ApiError),
+                (status = 403, description = "", body = ApiError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[delete("/{team_id}/{user_id}")]
+        #[instrument(skip_all, name = "MentorHandler::unassign_mentor_by_team_id_and_user_id")]
+        async fn unassign_mentor_by_team_id_and_user_id(
+            mentor_service: Data<MentorServiceDependency>,
+            user: Authorized<Organizer>,
+            Path((team_id, user_id)): Path<(Ulid, Ulid)>,
+        ) -> HttpResponse {
+            mentor_service
+                .unassign(team_id, user_id, Actor::from(&*user))
+                .await?;
+            HttpResponse::NoContent().finish()
+        }
+    }
+}
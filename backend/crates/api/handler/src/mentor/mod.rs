@@ -0,0 +1,64 @@
+use actix_web::{
+    HttpResponse,
+    middleware::from_fn,
+    web::{Data, Json, ReqData},
+};
+use actix_web_lab::extract::{Path, Query};
+use actix_web_validation::Validated;
+use dto::{Pagination, mentor::Mentor, team::Team, user::User};
+use macros::handler;
+use service::mentor::MentorServiceDependency;
+use ulid::Ulid;
+use utoipa_actix_web::{scope, service_config::ServiceConfig};
+
+use crate::common::{
+    HandlerError,
+    extractor::{Authorized, Organizer},
+    middleware::user_extractor_middleware,
+};
+
+pub mod implementation;
+
+handler! {
+    Mentor
+        Err: HandlerError,
+        Impl: ImplementedMentorHandler
+    {
+        fn routes(mentor_service: MentorServiceDependency) {
+            move |cfg: &mut ServiceConfig| {
+                cfg.app_data(Data::new(mentor_service))
+                    .service(scope("/mentors")
+                        .wrap(from_fn(user_extractor_middleware))
+                        .service(Self::get_current_mentored_teams_paginated())
+                        .service(Self::get_mentors_by_team_id_paginated())
+                        .service(Self::assign_mentor_by_team_id_and_user_id())
+                        .service(Self::unassign_mentor_by_team_id_and_user_id())
+                    );
+            }
+        }
+
+        async fn get_current_mentored_teams_paginated(
+            mentor_service: Data<MentorServiceDependency>,
+            user: ReqData<User>,
+            query: Validated<Query<Pagination>>,
+        ) -> Json<Vec<Team>>;
+
+        async fn get_mentors_by_team_id_paginated(
+            mentor_service: Data<MentorServiceDependency>,
+            path: Path<Ulid>,
+            query: Validated<Query<Pagination>>,
+        ) -> Json<Vec<Mentor>>;
+
+        async fn assign_mentor_by_team_id_and_user_id(
+            mentor_service: Data<MentorServiceDependency>,
+            user: Authorized<Organizer>,
+            path: Path<(Ulid, Ulid)>,
+        ) -> Json<Mentor>;
+
+        async fn unassign_mentor_by_team_id_and_user_id(
+            mentor_service: Data<MentorServiceDependency>,
+            user: Authorized<Organizer>,
+            path: Path<(Ulid, Ulid)>,
+        ) -> HttpResponse;
+    }
+}
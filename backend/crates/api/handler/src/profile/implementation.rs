@@ -11,7 +11,7 @@ use dto::{
 };
 use macros::handler_implementation;
 use service::{
-    profile::ProfileServiceDependency,
+    permission::Actor, profile::ProfileServiceDependency,
     profile_image::ProfileImageServiceDependency,
 };
 use tracing::instrument;
@@ -20,7 +20,11 @@ use ulid::Ulid;
 use super::{
     ProfileHandler, ProfileHandlerHelper, ProfileHandlerResult, UploadForm,
 };
-use crate::common::{ApiError, ValidationError, openapi};
+use crate::common::{
+    ApiError, ValidationError,
+    extractor::{Authorized, Organizer},
+    openapi,
+};
 
 handler_implementation! {
     ProfileHandler as ProfileHandlerImpl {
@@ -47,7 +51,7 @@ handler_implementation! {
             user: ReqData<User>,
         ) -> Json<Profile> {
             let res = profile_service
-                .get_by_id(user.id, false)
+                .get_by_id(user.id, Actor::from(&*user))
                 .await?;
             Json(res)
         }
@@ -79,7 +83,7 @@ handler_implementation! {
             Validated(Json(body)): Validated<Json<UpsertProfile>>
         ) -> Json<Profile> {
             let resp: Profile = profile_service
-                .upsert_by_id(user.id, body, None, false)
+                .upsert_by_id(user.id, body, None, Actor::from(&*user))
                 .await?;
             Json(resp)
         }
@@ -106,7 +110,7 @@ handler_implementation! {
             user: ReqData<User>,
         ) -> HttpResponse {
             profile_service
-                .delete_by_id(user.id, false)
+                .delete_by_id(user.id, Actor::from(&*user))
                 .await?;
             HttpResponse::NoContent().finish()
         }
@@ -134,7 +138,7 @@ handler_implementation! {
             user: ReqData<User>,
         ) -> HttpResponse {
             let res = profile_image_service
-                .get_by_id(user.id, false)
+                .get_by_id(user.id, Actor::from(&*user))
                 .await?;
             HttpResponse::Ok()
                .content_type(res.content_type)
@@ -170,7 +174,7 @@ handler_implementation! {
             MultipartForm(form): MultipartForm<UploadForm>,
         ) -> HttpResponse {
             profile_image_service
-               .upsert_by_id(user.id, form.file, false)
+               .upsert_by_id(user.id, form.file, Actor::from(&*user))
                .await?;
 
             HttpResponse::Ok().finish()
@@ -197,7 +201,7 @@ handler_implementation! {
             user: ReqData<User>,
         ) -> HttpResponse {
             profile_image_service
-                .delete_by_id(user.id, false)
+                .delete_by_id(user.id, Actor::from(&*user))
                 .await?;
             HttpResponse::NoContent().finish()
         }
@@ -230,7 +234,7 @@ handler_implementation! {
             user: ReqData<User>,
         ) -> Json<Profile> {
             let res = profile_service
-                .get_by_id(profile_id, profile_id != user.id)
+                .get_by_id(profile_id, Actor::from(&*user))
                 .await?;
             Json(res)
         }
@@ -256,10 +260,10 @@ handler_implementation! {
         async fn delete_profile_by_id(
             profile_service: Data<ProfileServiceDependency>,
             Path(profile_id): Path<Ulid>,
-            user: ReqData<User>,
+            user: Authorized<Organizer>,
         ) -> HttpResponse {
             profile_service
-                .delete_by_id(profile_id, profile_id != user.id)
+                .delete_by_id(profile_id, Actor::from(&*user))
                 .await?;
             HttpResponse::NoContent().finish()
         }
@@ -289,7 +293,7 @@ handler_implementation! {
             user: ReqData<User>,
         ) -> HttpResponse {
             let res = profile_image_service
-                .get_by_id(profile_id, profile_id != user.id)
+                .get_by_id(profile_id, Actor::from(&*user))
                 .await?;
             HttpResponse::Ok()
               .content_type(res.content_type)
@@ -317,10 +321,10 @@ handler_implementation! {
         async fn delete_profile_image_by_id(
             profile_image_service: Data<ProfileImageServiceDependency>,
             Path(profile_id): Path<Ulid>,
-            user: ReqData<User>,
+            user: Authorized<Organizer>,
         ) -> HttpResponse {
             profile_image_service
-                .delete_by_id(profile_id, profile_id != user.id)
+                .delete_by_id(profile_id, Actor::from(&*user))
                 .await?;
             HttpResponse::NoContent().finish()
         }
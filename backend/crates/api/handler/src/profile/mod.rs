@@ -8,7 +8,7 @@ use actix_web_lab::extract::Path;
 use actix_web_validation::Validated;
 use dto::{
     profile::{Profile, UpsertProfile},
-    user::{User, UserRole},
+    user::User,
 };
 use macros::handler;
 use service::{
@@ -20,7 +20,9 @@ use utoipa::ToSchema;
 use utoipa_actix_web::{scope, service_config::ServiceConfig};
 
 use crate::common::{
-    HandlerError, guard::UserRoleGuard, middleware::user_extractor_middleware,
+    HandlerError,
+    extractor::{Authorized, Organizer},
+    middleware::user_extractor_middleware,
 };
 
 pub mod implementation;
@@ -47,11 +49,8 @@ handler! {
                         .service(Self::delete_current_profile_image())
                         .service(Self::get_profile_by_id())
                         .service(Self::get_profile_image_by_id())
-                        .service(scope("")
-                            .guard(UserRoleGuard::new(&[UserRole::Organizer]))
-                            .service(Self::delete_profile_by_id())
-                            .service(Self::delete_profile_image_by_id())
-                        )
+                        .service(Self::delete_profile_by_id())
+                        .service(Self::delete_profile_image_by_id())
                     );
             }
         }
@@ -103,13 +102,13 @@ handler! {
         async fn delete_profile_by_id(
             profile_service: Data<ProfileServiceDependency>,
             profile_id: Path<Ulid>,
-            user: ReqData<User>,
+            user: Authorized<Organizer>,
         ) -> HttpResponse;
 
         async fn delete_profile_image_by_id(
             profile_image_service: Data<ProfileImageServiceDependency>,
             profile_id: Path<Ulid>,
-            user: ReqData<User>,
+            user: Authorized<Organizer>,
         ) -> HttpResponse;
     }
 }
@@ -0,0 +1,60 @@
+use actix_web::{
+    get,
+    web::{Data, Json},
+};
+use actix_web_lab::extract::{Path, Query};
+use actix_web_validation::Validated;
+use dto::{Pagination, recommendation::Recommendation};
+use macros::handler_implementation;
+use service::{
+    permission::Actor, recommendation::RecommendationServiceDependency,
+};
+use tracing::instrument;
+use ulid::Ulid;
+
+use super::{
+    RecommendationHandler, RecommendationHandlerHelper,
+    RecommendationHandlerResult,
+};
+use crate::common::{
+    ApiError, ValidationError,
+    extractor::{Authorized, Participant},
+    openapi,
+};
+
+handler_implementation! {
+    RecommendationHandler as RecommendationHandlerImpl {
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                ("team_id" = Ulid, description = ""),
+                Pagination,
+            ),
+            security(
+                ("participant" = []),
+            ),
+            responses(
+                (status = 200, description = "", body = Vec<Recommendation>),
+                (status = 404, description = "", body = ApiError),
+                (status = 400, description = "", body = ValidationError),
+                (status = 403, description = "", body = ApiError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[get("/{team_id}")]
+        #[instrument(skip_all, name = "RecommendationHandler::get_recommendations_by_team_id_paginated")]
+        async fn get_recommendations_by_team_id_paginated(
+            recommendation_service: Data<RecommendationServiceDependency>,
+            user: Authorized<Participant>,
+            Path(team_id): Path<Ulid>,
+            Validated(Query(pagination)): Validated<Query<Pagination>>,
+        ) -> Json<Vec<Recommendation>> {
+            let resp = recommendation_service
+                .find_for_team(team_id, Actor::from(&*user), pagination.into())
+                .await?;
+            Json(resp)
+        }
+    }
+}
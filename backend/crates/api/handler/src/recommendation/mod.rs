@@ -0,0 +1,43 @@
+use actix_web::{
+    middleware::from_fn,
+    web::{Data, Json},
+};
+use actix_web_lab::extract::{Path, Query};
+use actix_web_validation::Validated;
+use dto::{Pagination, recommendation::Recommendation};
+use macros::handler;
+use service::recommendation::RecommendationServiceDependency;
+use ulid::Ulid;
+use utoipa_actix_web::{scope, service_config::ServiceConfig};
+
+use crate::common::{
+    HandlerError,
+    extractor::{Authorized, Participant},
+    middleware::user_extractor_middleware,
+};
+
+pub mod implementation;
+
+handler! {
+    Recommendation
+        Err: HandlerError,
+        Impl: ImplementedRecommendationHandler
+    {
+        fn routes(recommendation_service: RecommendationServiceDependency) {
+            move |cfg: &mut ServiceConfig| {
+                cfg.app_data(Data::new(recommendation_service))
+                    .service(scope("/recommendations")
+                        .wrap(from_fn(user_extractor_middleware))
+                        .service(Self::get_recommendations_by_team_id_paginated())
+                    );
+            }
+        }
+
+        async fn get_recommendations_by_team_id_paginated(
+            recommendation_service: Data<RecommendationServiceDependency>,
+            user: Authorized<Participant>,
+            path: Path<Ulid>,
+            query: Validated<Query<Pagination>>,
+        ) -> Json<Vec<Recommendation>>;
+    }
+}
@@ -10,12 +10,16 @@ use dto::{
     user::User,
 };
 use macros::handler_implementation;
-use service::review::ReviewServiceDependency;
+use service::{permission::Actor, review::ReviewServiceDependency};
 use tracing::instrument;
 use ulid::Ulid;
 
 use super::{ReviewHandler, ReviewHandlerHelper, ReviewHandlerResult};
-use crate::common::{ApiError, ValidationError, openapi};
+use crate::common::{
+    ApiError, ValidationError,
+    extractor::{Authorized, Organizer},
+    openapi,
+};
 
 handler_implementation! {
     ReviewHandler as ReviewHandlerImpl {
@@ -124,12 +128,7 @@ handler_implementation! {
             user: ReqData<User>,
         ) -> HttpResponse {
             review_service
-                .delete_by_id(
-                    user.id,
-                    reviewee_id,
-                    false,
-                    true,
-                )
+                .delete_by_id(user.id, reviewee_id, Actor::from(&*user))
                 .await?;
             HttpResponse::NoContent().finish()
         }
@@ -305,15 +304,10 @@ handler_implementation! {
         async fn delete_review_by_reviewee_id_and_reviewer_id(
             review_service: Data<ReviewServiceDependency>,
             Path((reviewee_id, reviewer_id)): Path<(Ulid, Ulid)>,
-            user: ReqData<User>,
+            user: Authorized<Organizer>,
         ) -> HttpResponse {
             review_service
-                .delete_by_id(
-                    reviewer_id,
-                    reviewee_id,
-                    reviewer_id != user.id,
-                    reviewee_id != user.id,
-                )
+                .delete_by_id(reviewer_id, reviewee_id, Actor::from(&*user))
                 .await?;
             HttpResponse::NoContent().finish()
         }
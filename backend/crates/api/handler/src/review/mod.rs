@@ -8,7 +8,7 @@ use actix_web_validation::Validated;
 use dto::{
     Pagination,
     review::{Review, UpsertReview},
-    user::{User, UserRole},
+    user::User,
 };
 use macros::handler;
 use service::review::ReviewServiceDependency;
@@ -18,7 +18,9 @@ use utoipa_actix_web::{scope, service_config::ServiceConfig};
 pub mod implementation;
 
 use crate::common::{
-    HandlerError, guard::UserRoleGuard, middleware::user_extractor_middleware,
+    HandlerError,
+    extractor::{Authorized, Organizer},
+    middleware::user_extractor_middleware,
 };
 
 handler! {
@@ -38,10 +40,7 @@ handler! {
                         .service(Self::delete_review_by_id())
                         .service(Self::get_reviews_by_reviewer_id_paginated())
                         .service(Self::get_review_by_reviewee_id_and_reviewer_id())
-                        .service(scope("")
-                            .guard(UserRoleGuard::new(&[UserRole::Organizer]))
-                            .service(Self::delete_review_by_reviewee_id_and_reviewer_id())
-                        )
+                        .service(Self::delete_review_by_reviewee_id_and_reviewer_id())
                     );
             }
         }
@@ -94,7 +93,7 @@ handler! {
         async fn delete_review_by_reviewee_id_and_reviewer_id(
             review_service: Data<ReviewServiceDependency>,
             path: Path<(Ulid, Ulid)>,
-            user: ReqData<User>,
+            user: Authorized<Organizer>,
         ) -> HttpResponse;
     }
 }
@@ -0,0 +1,289 @@
+use actix_web::{
+    HttpResponse, delete, get, put,
+    web::{Data, Json, ReqData},
+};
+use actix_web_lab::extract::{Path, Query};
+use actix_web_validation::Validated;
+use dto::{
+    Pagination,
+    skill::{
+        SpecializationSkill, TechnologySkill, UpsertSpecializationSkill,
+        UpsertTechnologySkill,
+    },
+    user::User,
+};
+use macros::handler_implementation;
+use service::skill::SkillServiceDependency;
+use tracing::instrument;
+use ulid::Ulid;
+
+use super::{SkillHandler, SkillHandlerHelper, SkillHandlerResult};
+use crate::common::{ApiError, ValidationError, openapi};
+
+handler_implementation! {
+    SkillHandler as SkillHandlerImpl {
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                Pagination,
+            ),
+            security(
+                ("participant" = []),
+                ("mentor" = []),
+                ("organizer" = []),
+            ),
+            responses(
+                (status = 200, description = "", body = Vec<TechnologySkill>),
+                (status = 400, description = "", body = ValidationError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[get("/me/technologies")]
+        #[instrument(skip_all, name = "SkillHandler::get_current_technologies_paginated")]
+        async fn get_current_technologies_paginated(
+            skill_service: Data<SkillServiceDependency>,
+            user: ReqData<User>,
+            Validated(Query(pagination)): Validated<Query<Pagination>>,
+        ) -> Json<Vec<TechnologySkill>> {
+            let resp = skill_service
+                .find_all_technologies_by_user(user.id, pagination.into(), false)
+                .await?;
+            Json(resp)
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                ("technology_id" = Ulid, description = ""),
+            ),
+            security(
+                ("participant" = []),
+                ("mentor" = []),
+                ("organizer" = []),
+            ),
+            request_body(
+                description = "",
+                content = UpsertTechnologySkill
+            ),
+            responses(
+                (status = 200, description = "", body = TechnologySkill),
+                (status = 404, description = "", body = ApiError),
+                (status = 400, description = "", body = ValidationError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[put("/me/technologies/{technology_id}")]
+        #[instrument(skip_all, name = "SkillHandler::upsert_current_technology_by_id")]
+        async fn upsert_current_technology_by_id(
+            skill_service: Data<SkillServiceDependency>,
+            user: ReqData<User>,
+            Path(technology_id): Path<Ulid>,
+            Validated(Json(body)): Validated<Json<UpsertTechnologySkill>>,
+        ) -> Json<TechnologySkill> {
+            let resp = skill_service
+                .upsert_technology(user.id, technology_id, body)
+                .await?;
+            Json(resp)
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                ("technology_id" = Ulid, description = ""),
+            ),
+            security(
+                ("participant" = []),
+                ("mentor" = []),
+                ("organizer" = []),
+            ),
+            responses(
+                (status = 204, description = ""),
+                (status = 404, description = "", body = ApiError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[delete("/me/technologies/{technology_id}")]
+        #[instrument(skip_all, name = "SkillHandler::delete_current_technology_by_id")]
+        async fn delete_current_technology_by_id(
+            skill_service: Data<SkillServiceDependency>,
+            user: ReqData<User>,
+            Path(technology_id): Path<Ulid>,
+        ) -> HttpResponse {
+            skill_service
+                .delete_technology(user.id, technology_id)
+                .await?;
+            HttpResponse::NoContent().finish()
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                Pagination,
+            ),
+            security(
+                ("participant" = []),
+                ("mentor" = []),
+                ("organizer" = []),
+            ),
+            responses(
+                (status = 200, description = "", body = Vec<SpecializationSkill>),
+                (status = 400, description = "", body = ValidationError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[get("/me/specializations")]
+        #[instrument(skip_all, name = "SkillHandler::get_current_specializations_paginated")]
+        async fn get_current_specializations_paginated(
+            skill_service: Data<SkillServiceDependency>,
+            user: ReqData<User>,
+            Validated(Query(pagination)): Validated<Query<Pagination>>,
+        ) -> Json<Vec<SpecializationSkill>> {
+            let resp = skill_service
+                .find_all_specializations_by_user(user.id, pagination.into(), false)
+                .await?;
+            Json(resp)
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                ("specialization_id" = Ulid, description = ""),
+            ),
+            security(
+                ("participant" = []),
+                ("mentor" = []),
+                ("organizer" = []),
+            ),
+            request_body(
+                description = "",
+                content = UpsertSpecializationSkill
+            ),
+            responses(
+                (status = 200, description = "", body = SpecializationSkill),
+                (status = 404, description = "", body = ApiError),
+                (status = 400, description = "", body = ValidationError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[put("/me/specializations/{specialization_id}")]
+        #[instrument(skip_all, name = "SkillHandler::upsert_current_specialization_by_id")]
+        async fn upsert_current_specialization_by_id(
+            skill_service: Data<SkillServiceDependency>,
+            user: ReqData<User>,
+            Path(specialization_id): Path<Ulid>,
+            Validated(Json(body)): Validated<Json<UpsertSpecializationSkill>>,
+        ) -> Json<SpecializationSkill> {
+            let resp = skill_service
+                .upsert_specialization(user.id, specialization_id, body)
+                .await?;
+            Json(resp)
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                ("specialization_id" = Ulid, description = ""),
+            ),
+            security(
+                ("participant" = []),
+                ("mentor" = []),
+                ("organizer" = []),
+            ),
+            responses(
+                (status = 204, description = ""),
+                (status = 404, description = "", body = ApiError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[delete("/me/specializations/{specialization_id}")]
+        #[instrument(skip_all, name = "SkillHandler::delete_current_specialization_by_id")]
+        async fn delete_current_specialization_by_id(
+            skill_service: Data<SkillServiceDependency>,
+            user: ReqData<User>,
+            Path(specialization_id): Path<Ulid>,
+        ) -> HttpResponse {
+            skill_service
+                .delete_specialization(user.id, specialization_id)
+                .await?;
+            HttpResponse::NoContent().finish()
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                ("user_id" = Ulid, description = ""),
+                Pagination,
+            ),
+            security(
+                ("participant" = []),
+                ("mentor" = []),
+                ("organizer" = []),
+            ),
+            responses(
+                (status = 200, description = "", body = Vec<TechnologySkill>),
+                (status = 404, description = "", body = ApiError),
+                (status = 400, description = "", body = ValidationError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[get("/{user_id}/technologies")]
+        #[instrument(skip_all, name = "SkillHandler::get_technologies_by_user_id_paginated")]
+        async fn get_technologies_by_user_id_paginated(
+            skill_service: Data<SkillServiceDependency>,
+            Path(user_id): Path<Ulid>,
+            Validated(Query(pagination)): Validated<Query<Pagination>>,
+        ) -> Json<Vec<TechnologySkill>> {
+            let resp = skill_service
+                .find_all_technologies_by_user(user_id, pagination.into(), true)
+                .await?;
+            Json(resp)
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                ("user_id" = Ulid, description = ""),
+                Pagination,
+            ),
+            security(
+                ("participant" = []),
+                ("mentor" = []),
+                ("organizer" = []),
+            ),
+            responses(
+                (status = 200, description = "", body = Vec<SpecializationSkill>),
+                (status = 404, description = "", body = ApiError),
+                (status = 400, description = "", body = ValidationError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[get("/{user_id}/specializations")]
+        #[instrument(skip_all, name = "SkillHandler::get_specializations_by_user_id_paginated")]
+        async fn get_specializations_by_user_id_paginated(
+            skill_service: Data<SkillServiceDependency>,
+            Path(user_id): Path<Ulid>,
+            Validated(Query(pagination)): Validated<Query<Pagination>>,
+        ) -> Json<Vec<SpecializationSkill>> {
+            let resp = skill_service
+                .find_all_specializations_by_user(user_id, pagination.into(), true)
+                .await?;
+            Json(resp)
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use actix_web::{
+    HttpResponse,
+    middleware::from_fn,
+    web::{Data, Json, ReqData},
+};
+use actix_web_lab::extract::{Path, Query};
+use actix_web_validation::Validated;
+use dto::{
+    Pagination,
+    skill::{
+        SpecializationSkill, TechnologySkill, UpsertSpecializationSkill,
+        UpsertTechnologySkill,
+    },
+    user::User,
+};
+use macros::handler;
+use service::skill::SkillServiceDependency;
+use ulid::Ulid;
+use utoipa_actix_web::{scope, service_config::ServiceConfig};
+
+use crate::common::{HandlerError, middleware::user_extractor_middleware};
+
+pub mod implementation;
+
+handler! {
+    Skill
+        Err: HandlerError,
+        Impl: ImplementedSkillHandler
+    {
+        fn routes(skill_service: SkillServiceDependency) {
+            move |cfg: &mut ServiceConfig| {
+                cfg.app_data(Data::new(skill_service))
+                    .service(scope("/skills")
+                        .wrap(from_fn(user_extractor_middleware))
+                        .service(Self::get_current_technologies_paginated())
+                        .service(Self::upsert_current_technology_by_id())
+                        .service(Self::delete_current_technology_by_id())
+                        .service(Self::get_current_specializations_paginated())
+                        .service(Self::upsert_current_specialization_by_id())
+                        .service(Self::delete_current_specialization_by_id())
+                        .service(Self::get_technologies_by_user_id_paginated())
+                        .service(Self::get_specializations_by_user_id_paginated())
+                    );
+            }
+        }
+
+        async fn get_current_technologies_paginated(
+            skill_service: Data<SkillServiceDependency>,
+            user: ReqData<User>,
+            query: Validated<Query<Pagination>>,
+        ) -> Json<Vec<TechnologySkill>>;
+
+        async fn upsert_current_technology_by_id(
+            skill_service: Data<SkillServiceDependency>,
+            user: ReqData<User>,
+            path: Path<Ulid>,
+            body: Validated<Json<UpsertTechnologySkill>>,
+        ) -> Json<TechnologySkill>;
+
+        async fn delete_current_technology_by_id(
+            skill_service: Data<SkillServiceDependency>,
+            user: ReqData<User>,
+            path: Path<Ulid>,
+        ) -> HttpResponse;
+
+        async fn get_current_specializations_paginated(
+            skill_service: Data<SkillServiceDependency>,
+            user: ReqData<User>,
+            query: Validated<Query<Pagination>>,
+        ) -> Json<Vec<SpecializationSkill>>;
+
+        async fn upsert_current_specialization_by_id(
+            skill_service: Data<SkillServiceDependency>,
+            user: ReqData<User>,
+            path: Path<Ulid>,
+            body: Validated<Json<UpsertSpecializationSkill>>,
+        ) -> Json<SpecializationSkill>;
+
+        async fn delete_current_specialization_by_id(
+            skill_service: Data<SkillServiceDependency>,
+            user: ReqData<User>,
+            path: Path<Ulid>,
+        ) -> HttpResponse;
+
+        async fn get_technologies_by_user_id_paginated(
+            skill_service: Data<SkillServiceDependency>,
+            path: Path<Ulid>,
+            query: Validated<Query<Pagination>>,
+        ) -> Json<Vec<TechnologySkill>>;
+
+        async fn get_specializations_by_user_id_paginated(
+            skill_service: Data<SkillServiceDependency>,
+            path: Path<Ulid>,
+            query: Validated<Query<Pagination>>,
+        ) -> Json<Vec<SpecializationSkill>>;
+    }
+}
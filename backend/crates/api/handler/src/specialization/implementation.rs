@@ -0,0 +1,178 @@
+use actix_web::{
+    HttpResponse, delete, get, patch, post,
+    web::{Data, Json},
+};
+use actix_web_lab::extract::{Path, Query};
+use actix_web_validation::Validated;
+use dto::{
+    Pagination,
+    specialization::{
+        CreateSpecialization, Specialization, SpecializationUpdate,
+    },
+};
+use macros::handler_implementation;
+use service::specialization::SpecializationServiceDependency;
+use tracing::instrument;
+use ulid::Ulid;
+
+use super::{
+    SpecializationHandler, SpecializationHandlerHelper,
+    SpecializationHandlerResult,
+};
+use crate::common::{
+    ApiError, ValidationError,
+    extractor::{Authorized, Organizer},
+    openapi,
+};
+
+handler_implementation! {
+    SpecializationHandler as SpecializationHandlerImpl {
+        ///
+        ///
+        ///
+        #[openapi(
+            security(
+                ("organizer" = []),
+            ),
+            request_body(
+                description = "",
+                content = CreateSpecialization
+            ),
+            responses(
+                (status = 201, description = "", body = Specialization),
+                (status = 409, description = "", body = ApiError),
+                (status = 400, description = "", body = ValidationError),
+                (status = 403, description = "", body = ApiError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[post("")]
+        #[instrument(skip_all, name = "SpecializationHandler::create_specialization")]
+        async fn create_specialization(
+            specialization_service: Data<SpecializationServiceDependency>,
+            _user: Authorized<Organizer>,
+            Validated(Json(body)): Validated<Json<CreateSpecialization>>,
+        ) -> HttpResponse {
+            let resp = specialization_service
+                .create(body)
+                .await?;
+            HttpResponse::Created().json(resp)
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                Pagination,
+            ),
+            responses(
+                (status = 200, description = "", body = Vec<Specialization>),
+                (status = 400, description = "", body = ValidationError),
+            ),
+        )]
+        #[get("")]
+        #[instrument(skip_all, name = "SpecializationHandler::get_specializations_paginated")]
+        async fn get_specializations_paginated(
+            specialization_service: Data<SpecializationServiceDependency>,
+            Validated(Query(pagination)): Validated<Query<Pagination>>,
+        ) -> Json<Vec<Specialization>> {
+            let resp = specialization_service
+                .find_all(pagination.into())
+                .await?;
+            Json(resp)
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                ("specialization_id" = Ulid, description = ""),
+            ),
+            responses(
+                (status = 200, description = "", body = Specialization),
+                (status = 404, description = "", body = ApiError),
+            ),
+        )]
+        #[get("/{specialization_id}")]
+        #[instrument(skip_all, name = "SpecializationHandler::get_specialization_by_id")]
+        async fn get_specialization_by_id(
+            specialization_service: Data<SpecializationServiceDependency>,
+            Path(specialization_id): Path<Ulid>,
+        ) -> Json<Specialization> {
+            let resp = specialization_service
+                .get_by_id(specialization_id)
+                .await?;
+            Json(resp)
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                ("specialization_id" = Ulid, description = ""),
+            ),
+            security(
+                ("organizer" = []),
+            ),
+            request_body(
+                description = "",
+                content = SpecializationUpdate
+            ),
+            responses(
+                (status = 200, description = "", body = Specialization),
+                (status = 409, description = "", body = ApiError),
+                (status = 404, description = "", body = ApiError),
+                (status = 400, description = "", body = ValidationError),
+                (status = 403, description = "", body = ApiError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[patch("/{specialization_id}")]
+        #[instrument(skip_all, name = "SpecializationHandler::update_specialization_by_id")]
+        async fn update_specialization_by_id(
+            specialization_service: Data<SpecializationServiceDependency>,
+            _user: Authorized<Organizer>,
+            Path(specialization_id): Path<Ulid>,
+            Validated(Json(body)): Validated<Json<SpecializationUpdate>>,
+        ) -> Json<Specialization> {
+            let resp = specialization_service
+                .update_by_id(specialization_id, body)
+                .await?;
+            Json(resp)
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                ("specialization_id" = Ulid, description = ""),
+            ),
+            security(
+                ("organizer" = []),
+            ),
+            responses(
+                (status = 204, description = ""),
+                (status = 409, description = "", body = ApiError),
+                (status = 404, description = "", body = ApiError),
+                (status = 403, description = "", body = ApiError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[delete("/{specialization_id}")]
+        #[instrument(skip_all, name = "SpecializationHandler::delete_specialization_by_id")]
+        async fn delete_specialization_by_id(
+            specialization_service: Data<SpecializationServiceDependency>,
+            _user: Authorized<Organizer>,
+            Path(specialization_id): Path<Ulid>,
+        ) -> HttpResponse {
+            specialization_service
+                .delete_by_id(specialization_id)
+                .await?;
+            HttpResponse::NoContent().finish()
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use actix_web::{
+    HttpResponse,
+    middleware::from_fn,
+    web::{Data, Json},
+};
+use actix_web_lab::extract::{Path, Query};
+use actix_web_validation::Validated;
+use dto::{
+    Pagination,
+    specialization::{
+        CreateSpecialization, Specialization, SpecializationUpdate,
+    },
+};
+use macros::handler;
+use service::specialization::SpecializationServiceDependency;
+use ulid::Ulid;
+use utoipa_actix_web::{scope, service_config::ServiceConfig};
+
+use crate::common::{
+    HandlerError,
+    extractor::{Authorized, Organizer},
+    middleware::user_extractor_middleware,
+};
+
+pub mod implementation;
+
+handler! {
+    Specialization
+        Err: HandlerError,
+        Impl: ImplementedSpecializationHandler
+    {
+        fn routes(specialization_service: SpecializationServiceDependency) {
+            move |cfg: &mut ServiceConfig| {
+                cfg.app_data(Data::new(specialization_service))
+                    .service(scope("/specializations")
+                        .service(Self::get_specializations_paginated())
+                        .service(Self::get_specialization_by_id())
+                        .service(scope("")
+                            .wrap(from_fn(user_extractor_middleware))
+                            .service(Self::create_specialization())
+                            .service(Self::update_specialization_by_id())
+                            .service(Self::delete_specialization_by_id())
+                        )
+                    );
+            }
+        }
+
+        async fn create_specialization(
+            specialization_service: Data<SpecializationServiceDependency>,
+            _user: Authorized<Organizer>,
+            body: Validated<Json<CreateSpecialization>>,
+        ) -> HttpResponse;
+
+        async fn get_specializations_paginated(
+            specialization_service: Data<SpecializationServiceDependency>,
+            query: Validated<Query<Pagination>>,
+        ) -> Json<Vec<Specialization>>;
+
+        async fn get_specialization_by_id(
+            specialization_service: Data<SpecializationServiceDependency>,
+            path: Path<Ulid>,
+        ) -> Json<Specialization>;
+
+        async fn update_specialization_by_id(
+            specialization_service: Data<SpecializationServiceDependency>,
+            _user: Authorized<Organizer>,
+            path: Path<Ulid>,
+            body: Validated<Json<SpecializationUpdate>>,
+        ) -> Json<Specialization>;
+
+        async fn delete_specialization_by_id(
+            specialization_service: Data<SpecializationServiceDependency>,
+            _user: Authorized<Organizer>,
+            path: Path<Ulid>,
+        ) -> HttpResponse;
+    }
+}
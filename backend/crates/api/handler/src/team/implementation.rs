@@ -0,0 +1,381 @@
+use actix_web::{
+    HttpResponse, delete, get, patch, post, put,
+    web::{Data, Json},
+};
+use actix_web_lab::extract::{Path, Query};
+use actix_web_validation::Validated;
+use dto::{
+    Pagination,
+    member::Member,
+    team::{CreateTeam, Team, TeamComposition, TeamUpdate},
+    technology::Technology,
+};
+use macros::handler_implementation;
+use service::{permission::Actor, team::TeamServiceDependency};
+use tracing::instrument;
+use ulid::Ulid;
+
+use super::{TeamHandler, TeamHandlerHelper, TeamHandlerResult};
+use crate::common::{
+    ApiError, ValidationError,
+    extractor::{Authorized, Organizer, Participant},
+    openapi,
+};
+
+handler_implementation! {
+    TeamHandler as TeamHandlerImpl {
+        ///
+        ///
+        ///
+        #[openapi(
+            security(
+                ("participant" = []),
+            ),
+            request_body(
+                description = "",
+                content = CreateTeam
+            ),
+            responses(
+                (status = 201, description = "", body = Team),
+                (status = 409, description = "", body = ApiError),
+                (status = 404, description = "", body = ApiError),
+                (status = 400, description = "", body = ValidationError),
+                (status = 403, description = "", body = ApiError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[post("")]
+        #[instrument(skip_all, name = "TeamHandler::create_team")]
+        async fn create_team(
+            team_service: Data<TeamServiceDependency>,
+            user: Authorized<Participant>,
+            Validated(Json(body)): Validated<Json<CreateTeam>>,
+        ) -> HttpResponse {
+            let resp = team_service
+                .create(user.id, body)
+                .await?;
+            HttpResponse::Created().json(resp)
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                ("tour_id" = Ulid, description = ""),
+                Pagination,
+            ),
+            security(
+                ("participant" = []),
+                ("mentor" = []),
+                ("organizer" = []),
+            ),
+            responses(
+                (status = 200, description = "", body = Vec<Team>),
+                (status = 404, description = "", body = ApiError),
+                (status = 400, description = "", body = ValidationError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[get("/tour/{tour_id}")]
+        #[instrument(skip_all, name = "TeamHandler::get_teams_by_tour_id_paginated")]
+        async fn get_teams_by_tour_id_paginated(
+            team_service: Data<TeamServiceDependency>,
+            Path(tour_id): Path<Ulid>,
+            Validated(Query(pagination)): Validated<Query<Pagination>>,
+        ) -> Json<Vec<Team>> {
+            let resp = team_service
+                .find_all_by_tour(tour_id, pagination.into())
+                .await?;
+            Json(resp)
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                ("team_id" = Ulid, description = ""),
+            ),
+            security(
+                ("participant" = []),
+                ("mentor" = []),
+                ("organizer" = []),
+            ),
+            responses(
+                (status = 200, description = "", body = Team),
+                (status = 404, description = "", body = ApiError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[get("/{team_id}")]
+        #[instrument(skip_all, name = "TeamHandler::get_team_by_id")]
+        async fn get_team_by_id(
+            team_service: Data<TeamServiceDependency>,
+            Path(team_id): Path<Ulid>,
+        ) -> Json<Team> {
+            let resp = team_service
+                .get_by_id(team_id)
+                .await?;
+            Json(resp)
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                ("team_id" = Ulid, description = ""),
+                Pagination,
+            ),
+            security(
+                ("participant" = []),
+                ("mentor" = []),
+                ("organizer" = []),
+            ),
+            responses(
+                (status = 200, description = "", body = Vec<Member>),
+                (status = 404, description = "", body = ApiError),
+                (status = 400, description = "", body = ValidationError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[get("/{team_id}/members")]
+        #[instrument(skip_all, name = "TeamHandler::get_team_members_by_id_paginated")]
+        async fn get_team_members_by_id_paginated(
+            team_service: Data<TeamServiceDependency>,
+            Path(team_id): Path<Ulid>,
+            Validated(Query(pagination)): Validated<Query<Pagination>>,
+        ) -> Json<Vec<Member>> {
+            let resp = team_service
+                .find_members(team_id, pagination.into())
+                .await?;
+            Json(resp)
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                ("team_id" = Ulid, description = ""),
+            ),
+            security(
+                ("participant" = []),
+                ("mentor" = []),
+                ("organizer" = []),
+            ),
+            responses(
+                (status = 200, description = "", body = TeamComposition),
+                (status = 404, description = "", body = ApiError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[get("/{team_id}/composition")]
+        #[instrument(skip_all, name = "TeamHandler::get_team_composition_by_id")]
+        async fn get_team_composition_by_id(
+            team_service: Data<TeamServiceDependency>,
+            Path(team_id): Path<Ulid>,
+        ) -> Json<TeamComposition> {
+            let resp = team_service
+                .get_composition(team_id)
+                .await?;
+            Json(resp)
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                ("tour_id" = Ulid, description = ""),
+                Pagination,
+            ),
+            security(
+                ("organizer" = []),
+            ),
+            responses(
+                (status = 200, description = "", body = Vec<TeamComposition>),
+                (status = 404, description = "", body = ApiError),
+                (status = 400, description = "", body = ValidationError),
+                (status = 403, description = "", body = ApiError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[get("/tour/{tour_id}/composition")]
+        #[instrument(skip_all, name = "TeamHandler::get_team_compositions_by_tour_id_paginated")]
+        async fn get_team_compositions_by_tour_id_paginated(
+            team_service: Data<TeamServiceDependency>,
+            _user: Authorized<Organizer>,
+            Path(tour_id): Path<Ulid>,
+            Validated(Query(pagination)): Validated<Query<Pagination>>,
+        ) -> Json<Vec<TeamComposition>> {
+            let resp = team_service
+                .find_compositions_by_tour(tour_id, pagination.into())
+                .await?;
+            Json(resp)
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                ("team_id" = Ulid, description = ""),
+            ),
+            security(
+                ("participant" = []),
+            ),
+            request_body(
+                description = "",
+                content = TeamUpdate
+            ),
+            responses(
+                (status = 200, description = "", body = Team),
+                (status = 409, description = "", body = ApiError),
+                (status = 404, description = "", body = ApiError),
+                (status = 400, description = "", body = ValidationError),
+                (status = 403, description = "", body = ApiError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[patch("/{team_id}")]
+        #[instrument(skip_all, name = "TeamHandler::update_team_by_id")]
+        async fn update_team_by_id(
+            team_service: Data<TeamServiceDependency>,
+            user: Authorized<Participant>,
+            Path(team_id): Path<Ulid>,
+            Validated(Json(body)): Validated<Json<TeamUpdate>>,
+        ) -> Json<Team> {
+            let resp = team_service
+                .update_by_id(team_id, body, Actor::from(&*user))
+                .await?;
+            Json(resp)
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                ("team_id" = Ulid, description = ""),
+            ),
+            security(
+                ("participant" = []),
+            ),
+            responses(
+                (status = 204, description = ""),
+                (status = 404, description = "", body = ApiError),
+                (status = 403, description = "", body = ApiError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[delete("/{team_id}")]
+        #[instrument(skip_all, name = "TeamHandler::delete_team_by_id")]
+        async fn delete_team_by_id(
+            team_service: Data<TeamServiceDependency>,
+            user: Authorized<Participant>,
+            Path(team_id): Path<Ulid>,
+        ) -> HttpResponse {
+            team_service
+                .delete_by_id(team_id, Actor::from(&*user))
+                .await?;
+            HttpResponse::NoContent().finish()
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                ("team_id" = Ulid, description = ""),
+                Pagination,
+            ),
+            security(
+                ("participant" = []),
+                ("mentor" = []),
+                ("organizer" = []),
+            ),
+            responses(
+                (status = 200, description = "", body = Vec<Technology>),
+                (status = 404, description = "", body = ApiError),
+                (status = 400, description = "", body = ValidationError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[get("/{team_id}/technologies")]
+        #[instrument(skip_all, name = "TeamHandler::get_team_technologies_by_id_paginated")]
+        async fn get_team_technologies_by_id_paginated(
+            team_service: Data<TeamServiceDependency>,
+            Path(team_id): Path<Ulid>,
+            Validated(Query(pagination)): Validated<Query<Pagination>>,
+        ) -> Json<Vec<Technology>> {
+            let resp = team_service
+                .find_technologies(team_id, pagination.into())
+                .await?;
+            Json(resp)
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                ("team_id" = Ulid, description = ""),
+                ("technology_id" = Ulid, description = ""),
+            ),
+            security(
+                ("participant" = []),
+            ),
+            responses(
+                (status = 200, description = "", body = Technology),
+                (status = 404, description = "", body = ApiError),
+                (status = 403, description = "", body = ApiError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[put("/{team_id}/technologies/{technology_id}")]
+        #[instrument(skip_all, name = "TeamHandler::add_team_technology_by_id")]
+        async fn add_team_technology_by_id(
+            team_service: Data<TeamServiceDependency>,
+            user: Authorized<Participant>,
+            Path((team_id, technology_id)): Path<(Ulid, Ulid)>,
+        ) -> Json<Technology> {
+            let resp = team_service
+                .add_technology(team_id, technology_id, Actor::from(&*user))
+                .await?;
+            Json(resp)
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                ("team_id" = Ulid, description = ""),
+                ("technology_id" = Ulid, description = ""),
+            ),
+            security(
+                ("participant" = []),
+            ),
+            responses(
+                (status = 204, description = ""),
+                (status = 404, description = "", body = ApiError),
+                (status = 403, description = "", body = ApiError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[delete("/{team_id}/technologies/{technology_id}")]
+        #[instrument(skip_all, name = "TeamHandler::remove_team_technology_by_id")]
+        async fn remove_team_technology_by_id(
+            team_service: Data<TeamServiceDependency>,
+            user: Authorized<Participant>,
+            Path((team_id, technology_id)): Path<(Ulid, Ulid)>,
+        ) -> HttpResponse {
+            team_service
+                .remove_technology(team_id, technology_id, Actor::from(&*user))
+                .await?;
+            HttpResponse::NoContent().finish()
+        }
+    }
+}
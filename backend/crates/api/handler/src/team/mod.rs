@@ -0,0 +1,118 @@
+use actix_web::{
+    HttpResponse,
+    middleware::from_fn,
+    web::{Data, Json},
+};
+use actix_web_lab::extract::{Path, Query};
+use actix_web_validation::Validated;
+use dto::{
+    Pagination,
+    member::Member,
+    team::{CreateTeam, Team, TeamComposition, TeamUpdate},
+    technology::Technology,
+};
+use macros::handler;
+use service::team::TeamServiceDependency;
+use ulid::Ulid;
+use utoipa_actix_web::{scope, service_config::ServiceConfig};
+
+use crate::common::{
+    HandlerError,
+    extractor::{Authorized, Organizer, Participant},
+    middleware::user_extractor_middleware,
+};
+
+pub mod implementation;
+
+handler! {
+    Team
+        Err: HandlerError,
+        Impl: ImplementedTeamHandler
+    {
+        fn routes(team_service: TeamServiceDependency) {
+            move |cfg: &mut ServiceConfig| {
+                cfg.app_data(Data::new(team_service))
+                    .service(scope("/teams")
+                        .wrap(from_fn(user_extractor_middleware))
+                        .service(Self::get_teams_by_tour_id_paginated())
+                        .service(Self::get_team_by_id())
+                        .service(Self::get_team_members_by_id_paginated())
+                        .service(Self::get_team_composition_by_id())
+                        .service(Self::get_team_technologies_by_id_paginated())
+                        .service(Self::create_team())
+                        .service(Self::update_team_by_id())
+                        .service(Self::delete_team_by_id())
+                        .service(Self::add_team_technology_by_id())
+                        .service(Self::remove_team_technology_by_id())
+                        .service(Self::get_team_compositions_by_tour_id_paginated())
+                    );
+            }
+        }
+
+        async fn create_team(
+            team_service: Data<TeamServiceDependency>,
+            user: Authorized<Participant>,
+            body: Validated<Json<CreateTeam>>,
+        ) -> HttpResponse;
+
+        async fn get_teams_by_tour_id_paginated(
+            team_service: Data<TeamServiceDependency>,
+            path: Path<Ulid>,
+            query: Validated<Query<Pagination>>,
+        ) -> Json<Vec<Team>>;
+
+        async fn get_team_by_id(
+            team_service: Data<TeamServiceDependency>,
+            path: Path<Ulid>,
+        ) -> Json<Team>;
+
+        async fn get_team_members_by_id_paginated(
+            team_service: Data<TeamServiceDependency>,
+            path: Path<Ulid>,
+            query: Validated<Query<Pagination>>,
+        ) -> Json<Vec<Member>>;
+
+        async fn get_team_composition_by_id(
+            team_service: Data<TeamServiceDependency>,
+            path: Path<Ulid>,
+        ) -> Json<TeamComposition>;
+
+        async fn get_team_compositions_by_tour_id_paginated(
+            team_service: Data<TeamServiceDependency>,
+            _user: Authorized<Organizer>,
+            path: Path<Ulid>,
+            query: Validated<Query<Pagination>>,
+        ) -> Json<Vec<TeamComposition>>;
+
+        async fn get_team_technologies_by_id_paginated(
+            team_service: Data<TeamServiceDependency>,
+            path: Path<Ulid>,
+            query: Validated<Query<Pagination>>,
+        ) -> Json<Vec<Technology>>;
+
+        async fn add_team_technology_by_id(
+            team_service: Data<TeamServiceDependency>,
+            user: Authorized<Participant>,
+            path: Path<(Ulid, Ulid)>,
+        ) -> Json<Technology>;
+
+        async fn remove_team_technology_by_id(
+            team_service: Data<TeamServiceDependency>,
+            user: Authorized<Participant>,
+            path: Path<(Ulid, Ulid)>,
+        ) -> HttpResponse;
+
+        async fn update_team_by_id(
+            team_service: Data<TeamServiceDependency>,
+            user: Authorized<Participant>,
+            path: Path<Ulid>,
+            body: Validated<Json<TeamUpdate>>,
+        ) -> Json<Team>;
+
+        async fn delete_team_by_id(
+            team_service: Data<TeamServiceDependency>,
+            user: Authorized<Participant>,
+            path: Path<Ulid>,
+        ) -> HttpResponse;
+    }
+}
@@ -0,0 +1,175 @@
+use actix_web::{
+    HttpResponse, delete, get, patch, post,
+    web::{Data, Json},
+};
+use actix_web_lab::extract::{Path, Query};
+use actix_web_validation::Validated;
+use dto::{
+    Pagination,
+    technology::{CreateTechnology, Technology, TechnologyUpdate},
+};
+use macros::handler_implementation;
+use service::technology::TechnologyServiceDependency;
+use tracing::instrument;
+use ulid::Ulid;
+
+use super::{
+    TechnologyHandler, TechnologyHandlerHelper, TechnologyHandlerResult,
+};
+use crate::common::{
+    ApiError, ValidationError,
+    extractor::{Authorized, Organizer},
+    openapi,
+};
+
+handler_implementation! {
+    TechnologyHandler as TechnologyHandlerImpl {
+        ///
+        ///
+        ///
+        #[openapi(
+            security(
+                ("organizer" = []),
+            ),
+            request_body(
+                description = "",
+                content = CreateTechnology
+            ),
+            responses(
+                (status = 201, description = "", body = Technology),
+                (status = 409, description = "", body = ApiError),
+                (status = 400, description = "", body = ValidationError),
+                (status = 403, description = "", body = ApiError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[post("")]
+        #[instrument(skip_all, name = "TechnologyHandler::create_technology")]
+        async fn create_technology(
+            technology_service: Data<TechnologyServiceDependency>,
+            _user: Authorized<Organizer>,
+            Validated(Json(body)): Validated<Json<CreateTechnology>>,
+        ) -> HttpResponse {
+            let resp = technology_service
+                .create(body)
+                .await?;
+            HttpResponse::Created().json(resp)
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                Pagination,
+            ),
+            responses(
+                (status = 200, description = "", body = Vec<Technology>),
+                (status = 400, description = "", body = ValidationError),
+            ),
+        )]
+        #[get("")]
+        #[instrument(skip_all, name = "TechnologyHandler::get_technologies_paginated")]
+        async fn get_technologies_paginated(
+            technology_service: Data<TechnologyServiceDependency>,
+            Validated(Query(pagination)): Validated<Query<Pagination>>,
+        ) -> Json<Vec<Technology>> {
+            let resp = technology_service
+                .find_all(pagination.into())
+                .await?;
+            Json(resp)
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                ("technology_id" = Ulid, description = ""),
+            ),
+            responses(
+                (status = 200, description = "", body = Technology),
+                (status = 404, description = "", body = ApiError),
+            ),
+        )]
+        #[get("/{technology_id}")]
+        #[instrument(skip_all, name = "TechnologyHandler::get_technology_by_id")]
+        async fn get_technology_by_id(
+            technology_service: Data<TechnologyServiceDependency>,
+            Path(technology_id): Path<Ulid>,
+        ) -> Json<Technology> {
+            let resp = technology_service
+                .get_by_id(technology_id)
+                .await?;
+            Json(resp)
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                ("technology_id" = Ulid, description = ""),
+            ),
+            security(
+                ("organizer" = []),
+            ),
+            request_body(
+                description = "",
+                content = TechnologyUpdate
+            ),
+            responses(
+                (status = 200, description = "", body = Technology),
+                (status = 409, description = "", body = ApiError),
+                (status = 404, description = "", body = ApiError),
+                (status = 400, description = "", body = ValidationError),
+                (status = 403, description = "", body = ApiError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[patch("/{technology_id}")]
+        #[instrument(skip_all, name = "TechnologyHandler::update_technology_by_id")]
+        async fn update_technology_by_id(
+            technology_service: Data<TechnologyServiceDependency>,
+            _user: Authorized<Organizer>,
+            Path(technology_id): Path<Ulid>,
+            Validated(Json(body)): Validated<Json<TechnologyUpdate>>,
+        ) -> Json<Technology> {
+            let resp = technology_service
+                .update_by_id(technology_id, body)
+                .await?;
+            Json(resp)
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                ("technology_id" = Ulid, description = ""),
+            ),
+            security(
+                ("organizer" = []),
+            ),
+            responses(
+                (status = 204, description = ""),
+                (status = 409, description = "", body = ApiError),
+                (status = 404, description = "", body = ApiError),
+                (status = 403, description = "", body = ApiError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[delete("/{technology_id}")]
+        #[instrument(skip_all, name = "TechnologyHandler::delete_technology_by_id")]
+        async fn delete_technology_by_id(
+            technology_service: Data<TechnologyServiceDependency>,
+            _user: Authorized<Organizer>,
+            Path(technology_id): Path<Ulid>,
+        ) -> HttpResponse {
+            technology_service
+                .delete_by_id(technology_id)
+                .await?;
+            HttpResponse::NoContent().finish()
+        }
+    }
+}
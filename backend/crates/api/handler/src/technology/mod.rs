@@ -0,0 +1,75 @@
+use actix_web::{
+    HttpResponse,
+    middleware::from_fn,
+    web::{Data, Json},
+};
+use actix_web_lab::extract::{Path, Query};
+use actix_web_validation::Validated;
+use dto::{
+    Pagination,
+    technology::{CreateTechnology, Technology, TechnologyUpdate},
+};
+use macros::handler;
+use service::technology::TechnologyServiceDependency;
+use ulid::Ulid;
+use utoipa_actix_web::{scope, service_config::ServiceConfig};
+
+use crate::common::{
+    HandlerError,
+    extractor::{Authorized, Organizer},
+    middleware::user_extractor_middleware,
+};
+
+pub mod implementation;
+
+handler! {
+    Technology
+        Err: HandlerError,
+        Impl: ImplementedTechnologyHandler
+    {
+        fn routes(technology_service: TechnologyServiceDependency) {
+            move |cfg: &mut ServiceConfig| {
+                cfg.app_data(Data::new(technology_service))
+                    .service(scope("/technologies")
+                        .service(Self::get_technologies_paginated())
+                        .service(Self::get_technology_by_id())
+                        .service(scope("")
+                            .wrap(from_fn(user_extractor_middleware))
+                            .service(Self::create_technology())
+                            .service(Self::update_technology_by_id())
+                            .service(Self::delete_technology_by_id())
+                        )
+                    );
+            }
+        }
+
+        async fn create_technology(
+            technology_service: Data<TechnologyServiceDependency>,
+            _user: Authorized<Organizer>,
+            body: Validated<Json<CreateTechnology>>,
+        ) -> HttpResponse;
+
+        async fn get_technologies_paginated(
+            technology_service: Data<TechnologyServiceDependency>,
+            query: Validated<Query<Pagination>>,
+        ) -> Json<Vec<Technology>>;
+
+        async fn get_technology_by_id(
+            technology_service: Data<TechnologyServiceDependency>,
+            path: Path<Ulid>,
+        ) -> Json<Technology>;
+
+        async fn update_technology_by_id(
+            technology_service: Data<TechnologyServiceDependency>,
+            _user: Authorized<Organizer>,
+            path: Path<Ulid>,
+            body: Validated<Json<TechnologyUpdate>>,
+        ) -> Json<Technology>;
+
+        async fn delete_technology_by_id(
+            technology_service: Data<TechnologyServiceDependency>,
+            _user: Authorized<Organizer>,
+            path: Path<Ulid>,
+        ) -> HttpResponse;
+    }
+}
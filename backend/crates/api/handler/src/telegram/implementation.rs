@@ -0,0 +1,142 @@
+use actix_web::{
+    HttpResponse, delete, get, post, put,
+    web::{Data, Json, ReqData},
+};
+use actix_web_validation::Validated;
+use dto::{
+    notification::{NotificationSettings, UpsertNotificationSettings},
+    telegram::TelegramLink,
+    user::User,
+};
+use macros::handler_implementation;
+use service::{
+    notification::NotificationServiceDependency,
+    telegram::TelegramServiceDependency,
+};
+use tracing::instrument;
+
+use super::{TelegramHandler, TelegramHandlerHelper, TelegramHandlerResult};
+use crate::common::{ApiError, ValidationError, openapi};
+
+handler_implementation! {
+    TelegramHandler as TelegramHandlerImpl {
+        ///
+        ///
+        ///
+        #[openapi(
+            security(
+                ("participant" = []),
+                ("mentor" = []),
+                ("organizer" = []),
+            ),
+            responses(
+                (status = 201, description = "", body = TelegramLink),
+                (status = 403, description = "", body = ApiError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[post("/link")]
+        #[instrument(skip_all, name = "TelegramHandler::create_telegram_link")]
+        async fn create_telegram_link(
+            telegram_service: Data<TelegramServiceDependency>,
+            user: ReqData<User>,
+        ) -> HttpResponse {
+            let user: User = user.into_inner();
+            let resp: TelegramLink = telegram_service
+                .create_link(user.id)
+                .await?;
+            HttpResponse::Created().json(resp)
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            security(
+                ("participant" = []),
+                ("mentor" = []),
+                ("organizer" = []),
+            ),
+            responses(
+                (status = 204, description = ""),
+                (status = 404, description = "", body = ApiError),
+                (status = 403, description = "", body = ApiError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[delete("/link")]
+        #[instrument(skip_all, name = "TelegramHandler::delete_telegram_link")]
+        async fn delete_telegram_link(
+            telegram_service: Data<TelegramServiceDependency>,
+            user: ReqData<User>,
+        ) -> HttpResponse {
+            let user: User = user.into_inner();
+            telegram_service
+                .unlink(user.id)
+                .await?;
+            HttpResponse::NoContent().finish()
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            security(
+                ("participant" = []),
+                ("mentor" = []),
+                ("organizer" = []),
+            ),
+            responses(
+                (status = 200, description = "", body = NotificationSettings),
+                (status = 403, description = "", body = ApiError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[get("/notifications")]
+        #[instrument(skip_all, name = "TelegramHandler::get_notification_settings")]
+        async fn get_notification_settings(
+            notification_service: Data<NotificationServiceDependency>,
+            user: ReqData<User>,
+        ) -> Json<NotificationSettings> {
+            let user: User = user.into_inner();
+            let resp = notification_service
+                .get_settings(user.id)
+                .await?;
+            Json(resp)
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            request_body(
+                description = "",
+                content = UpsertNotificationSettings
+            ),
+            security(
+                ("participant" = []),
+                ("mentor" = []),
+                ("organizer" = []),
+            ),
+            responses(
+                (status = 200, description = "", body = NotificationSettings),
+                (status = 400, description = "", body = ValidationError),
+                (status = 403, description = "", body = ApiError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[put("/notifications")]
+        #[instrument(skip_all, name = "TelegramHandler::update_notification_settings")]
+        async fn update_notification_settings(
+            notification_service: Data<NotificationServiceDependency>,
+            user: ReqData<User>,
+            Validated(Json(body)): Validated<Json<UpsertNotificationSettings>>,
+        ) -> Json<NotificationSettings> {
+            let user: User = user.into_inner();
+            let resp = notification_service
+                .update_settings(user.id, body)
+                .await?;
+            Json(resp)
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use actix_web::{
+    HttpResponse,
+    middleware::from_fn,
+    web::{Data, Json, ReqData},
+};
+use actix_web_validation::Validated;
+use dto::{
+    notification::{NotificationSettings, UpsertNotificationSettings},
+    user::User,
+};
+use macros::handler;
+use service::{
+    notification::NotificationServiceDependency,
+    telegram::TelegramServiceDependency,
+};
+use utoipa_actix_web::{scope, service_config::ServiceConfig};
+
+use crate::common::{HandlerError, middleware::user_extractor_middleware};
+
+pub mod implementation;
+
+handler! {
+    Telegram
+        Err: HandlerError,
+        Impl: ImplementedTelegramHandler
+    {
+        fn routes(
+            telegram_service: TelegramServiceDependency,
+            notification_service: NotificationServiceDependency,
+        ) {
+            move |cfg: &mut ServiceConfig| {
+                cfg.app_data(Data::new(telegram_service))
+                    .app_data(Data::new(notification_service))
+                    .service(scope("/telegram")
+                        .wrap(from_fn(user_extractor_middleware))
+                        .service(Self::create_telegram_link())
+                        .service(Self::delete_telegram_link())
+                        .service(Self::get_notification_settings())
+                        .service(Self::update_notification_settings())
+                    );
+            }
+        }
+
+        async fn create_telegram_link(
+            telegram_service: Data<TelegramServiceDependency>,
+            user: ReqData<User>,
+        ) -> HttpResponse;
+
+        async fn delete_telegram_link(
+            telegram_service: Data<TelegramServiceDependency>,
+            user: ReqData<User>,
+        ) -> HttpResponse;
+
+        async fn get_notification_settings(
+            notification_service: Data<NotificationServiceDependency>,
+            user: ReqData<User>,
+        ) -> Json<NotificationSettings>;
+
+        async fn update_notification_settings(
+            notification_service: Data<NotificationServiceDependency>,
+            user: ReqData<User>,
+            body: Validated<Json<UpsertNotificationSettings>>,
+        ) -> Json<NotificationSettings>;
+    }
+}
@@ -0,0 +1,356 @@
+use actix_web::{
+    HttpResponse, delete, get, patch, post, put,
+    web::{Data, Json},
+};
+use actix_web_lab::extract::{Path, Query};
+use actix_web_validation::Validated;
+use dto::{
+    Pagination,
+    team::{Team, TeamFilter},
+    tour::{CreateTour, Tour, TourUpdate},
+};
+use macros::handler_implementation;
+use service::{
+    permission::Actor, team::TeamServiceDependency,
+    tour::TourServiceDependency, tour_staff::TourStaffServiceDependency,
+};
+use tracing::instrument;
+use ulid::Ulid;
+
+use super::{TourHandler, TourHandlerHelper, TourHandlerResult};
+use crate::common::{
+    ApiError, ValidationError,
+    extractor::{Authorized, Organizer, Superadmin},
+    openapi,
+};
+
+handler_implementation! {
+    TourHandler as TourHandlerImpl {
+        ///
+        ///
+        ///
+        #[openapi(
+            security(
+                ("superadmin" = []),
+            ),
+            request_body(
+                description = "",
+                content = CreateTour
+            ),
+            responses(
+                (status = 201, description = "", body = Tour),
+                (status = 409, description = "", body = ApiError),
+                (status = 404, description = "", body = ApiError),
+                (status = 400, description = "", body = ValidationError),
+                (status = 403, description = "", body = ApiError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[post("")]
+        #[instrument(skip_all, name = "TourHandler::create_tour")]
+        async fn create_tour(
+            tour_service: Data<TourServiceDependency>,
+            _user: Authorized<Superadmin>,
+            Validated(Json(body)): Validated<Json<CreateTour>>,
+        ) -> HttpResponse {
+            let resp = tour_service
+                .create(body)
+                .await?;
+            HttpResponse::Created().json(resp)
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                Pagination,
+            ),
+            security(
+                ("participant" = []),
+                ("mentor" = []),
+                ("organizer" = []),
+            ),
+            responses(
+                (status = 200, description = "", body = Vec<Tour>),
+                (status = 400, description = "", body = ValidationError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[get("")]
+        #[instrument(skip_all, name = "TourHandler::get_tours_paginated")]
+        async fn get_tours_paginated(
+            tour_service: Data<TourServiceDependency>,
+            Validated(Query(pagination)): Validated<Query<Pagination>>,
+        ) -> Json<Vec<Tour>> {
+            let resp = tour_service
+                .find_all(pagination.into())
+                .await?;
+            Json(resp)
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                ("tour_id" = Ulid, description = ""),
+            ),
+            security(
+                ("participant" = []),
+                ("mentor" = []),
+                ("organizer" = []),
+            ),
+            responses(
+                (status = 200, description = "", body = Tour),
+                (status = 404, description = "", body = ApiError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[get("/{tour_id}")]
+        #[instrument(skip_all, name = "TourHandler::get_tour_by_id")]
+        async fn get_tour_by_id(
+            tour_service: Data<TourServiceDependency>,
+            Path(tour_id): Path<Ulid>,
+        ) -> Json<Tour> {
+            let resp = tour_service
+                .get_by_id(tour_id)
+                .await?;
+            Json(resp)
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                ("tour_id" = Ulid, description = ""),
+                Pagination,
+                TeamFilter,
+            ),
+            security(
+                ("participant" = []),
+                ("mentor" = []),
+                ("organizer" = []),
+            ),
+            responses(
+                (status = 200, description = "", body = Vec<Team>),
+                (status = 404, description = "", body = ApiError),
+                (status = 400, description = "", body = ValidationError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[get("/{tour_id}/teams")]
+        #[instrument(skip_all, name = "TourHandler::get_teams_by_tour_id_and_technology_id_paginated")]
+        async fn get_teams_by_tour_id_and_technology_id_paginated(
+            team_service: Data<TeamServiceDependency>,
+            Path(tour_id): Path<Ulid>,
+            Validated(Query(pagination)): Validated<Query<Pagination>>,
+            Validated(Query(filter)): Validated<Query<TeamFilter>>,
+        ) -> Json<Vec<Team>> {
+            let resp = team_service
+                .find_all_by_tour_and_technology(
+                    tour_id,
+                    filter.technology_id,
+                    pagination.into(),
+                )
+                .await?;
+            Json(resp)
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                ("tour_id" = Ulid, description = ""),
+            ),
+            security(
+                ("organizer" = []),
+            ),
+            request_body(
+                description = "",
+                content = TourUpdate
+            ),
+            responses(
+                (status = 200, description = "", body = Tour),
+                (status = 409, description = "", body = ApiError),
+                (status = 404, description = "", body = ApiError),
+                (status = 400, description = "", body = ValidationError),
+                (status = 403, description = "", body = ApiError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[patch("/{tour_id}")]
+        #[instrument(skip_all, name = "TourHandler::update_tour_by_id")]
+        async fn update_tour_by_id(
+            tour_service: Data<TourServiceDependency>,
+            user: Authorized<Organizer>,
+            Path(tour_id): Path<Ulid>,
+            Validated(Json(body)): Validated<Json<TourUpdate>>,
+        ) -> Json<Tour> {
+            let resp = tour_service
+                .update_by_id(tour_id, body, Actor::from(&*user))
+                .await?;
+            Json(resp)
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                ("tour_id" = Ulid, description = ""),
+            ),
+            security(
+                ("superadmin" = []),
+            ),
+            responses(
+                (status = 204, description = ""),
+                (status = 404, description = "", body = ApiError),
+                (status = 403, description = "", body = ApiError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[delete("/{tour_id}")]
+        #[instrument(skip_all, name = "TourHandler::delete_tour_by_id")]
+        async fn delete_tour_by_id(
+            tour_service: Data<TourServiceDependency>,
+            _user: Authorized<Superadmin>,
+            Path(tour_id): Path<Ulid>,
+        ) -> HttpResponse {
+            tour_service
+                .delete_by_id(tour_id)
+                .await?;
+            HttpResponse::NoContent().finish()
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                ("tour_id" = Ulid, description = ""),
+                ("user_id" = Ulid, description = ""),
+            ),
+            security(
+                ("superadmin" = []),
+            ),
+            responses(
+                (status = 204, description = ""),
+                (status = 409, description = "", body = ApiError),
+                (status = 400, description = "", body = ApiError),
+                (status = 404, description = "", body = ApiError),
+                (status = 403, description = "", body = ApiError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[put("/{tour_id}/organizers/{user_id}")]
+        #[instrument(skip_all, name = "TourHandler::assign_organizer_by_tour_id_and_user_id")]
+        async fn assign_organizer_by_tour_id_and_user_id(
+            tour_staff_service: Data<TourStaffServiceDependency>,
+            _user: Authorized<Superadmin>,
+            Path((tour_id, user_id)): Path<(Ulid, Ulid)>,
+        ) -> HttpResponse {
+            tour_staff_service
+                .assign_organizer(tour_id, user_id)
+                .await?;
+            HttpResponse::NoContent().finish()
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                ("tour_id" = Ulid, description = ""),
+                ("user_id" = Ulid, description = ""),
+            ),
+            security(
+                ("superadmin" = []),
+            ),
+            responses(
+                (status = 204, description = ""),
+                (status = 404, description = "", body = ApiError),
+                (status = 403, description = "", body = ApiError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[delete("/{tour_id}/organizers/{user_id}")]
+        #[instrument(skip_all, name = "TourHandler::unassign_organizer_by_tour_id_and_user_id")]
+        async fn unassign_organizer_by_tour_id_and_user_id(
+            tour_staff_service: Data<TourStaffServiceDependency>,
+            _user: Authorized<Superadmin>,
+            Path((tour_id, user_id)): Path<(Ulid, Ulid)>,
+        ) -> HttpResponse {
+            tour_staff_service
+                .unassign_organizer(tour_id, user_id)
+                .await?;
+            HttpResponse::NoContent().finish()
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                ("tour_id" = Ulid, description = ""),
+                ("user_id" = Ulid, description = ""),
+            ),
+            security(
+                ("organizer" = []),
+            ),
+            responses(
+                (status = 204, description = ""),
+                (status = 409, description = "", body = ApiError),
+                (status = 400, description = "", body = ApiError),
+                (status = 404, description = "", body = ApiError),
+                (status = 403, description = "", body = ApiError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[put("/{tour_id}/mentors/{user_id}")]
+        #[instrument(skip_all, name = "TourHandler::assign_mentor_by_tour_id_and_user_id")]
+        async fn assign_mentor_by_tour_id_and_user_id(
+            tour_staff_service: Data<TourStaffServiceDependency>,
+            user: Authorized<Organizer>,
+            Path((tour_id, user_id)): Path<(Ulid, Ulid)>,
+        ) -> HttpResponse {
+            tour_staff_service
+                .assign_mentor(tour_id, user_id, Actor::from(&*user))
+                .await?;
+            HttpResponse::NoContent().finish()
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                ("tour_id" = Ulid, description = ""),
+                ("user_id" = Ulid, description = ""),
+            ),
+            security(
+                ("organizer" = []),
+            ),
+            responses(
+                (status = 204, description = ""),
+                (status = 404, description = "", body = ApiError),
+                (status = 403, description = "", body = ApiError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[delete("/{tour_id}/mentors/{user_id}")]
+        #[instrument(skip_all, name = "TourHandler::unassign_mentor_by_tour_id_and_user_id")]
+        async fn unassign_mentor_by_tour_id_and_user_id(
+            tour_staff_service: Data<TourStaffServiceDependency>,
+            user: Authorized<Organizer>,
+            Path((tour_id, user_id)): Path<(Ulid, Ulid)>,
+        ) -> HttpResponse {
+            tour_staff_service
+                .unassign_mentor(tour_id, user_id, Actor::from(&*user))
+                .await?;
+            HttpResponse::NoContent().finish()
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use actix_web::{
+    HttpResponse,
+    middleware::from_fn,
+    web::{Data, Json},
+};
+use actix_web_lab::extract::{Path, Query};
+use actix_web_validation::Validated;
+use dto::{
+    Pagination,
+    team::{Team, TeamFilter},
+    tour::{CreateTour, Tour, TourUpdate},
+};
+use macros::handler;
+use service::{
+    team::TeamServiceDependency, tour::TourServiceDependency,
+    tour_staff::TourStaffServiceDependency,
+};
+use ulid::Ulid;
+use utoipa_actix_web::{scope, service_config::ServiceConfig};
+
+use crate::common::{
+    HandlerError,
+    extractor::{Authorized, Organizer, Superadmin},
+    middleware::user_extractor_middleware,
+};
+
+pub mod implementation;
+
+handler! {
+    Tour
+        Err: HandlerError,
+        Impl: ImplementedTourHandler
+    {
+        fn routes(
+            tour_service: TourServiceDependency,
+            tour_staff_service: TourStaffServiceDependency,
+            team_service: TeamServiceDependency
+        ) {
+            move |cfg: &mut ServiceConfig| {
+                cfg.app_data(Data::new(tour_service))
+                    .app_data(Data::new(tour_staff_service))
+                    .app_data(Data::new(team_service))
+                    .service(scope("/tours")
+                        .wrap(from_fn(user_extractor_middleware))
+                        .service(Self::get_tours_paginated())
+                        .service(Self::get_tour_by_id())
+                        .service(Self::get_teams_by_tour_id_and_technology_id_paginated())
+                        .service(Self::create_tour())
+                        .service(Self::update_tour_by_id())
+                        .service(Self::delete_tour_by_id())
+                        .service(Self::assign_organizer_by_tour_id_and_user_id())
+                        .service(Self::unassign_organizer_by_tour_id_and_user_id())
+                        .service(Self::assign_mentor_by_tour_id_and_user_id())
+                        .service(Self::unassign_mentor_by_tour_id_and_user_id())
+                    );
+            }
+        }
+
+        async fn create_tour(
+            tour_service: Data<TourServiceDependency>,
+            _user: Authorized<Superadmin>,
+            body: Validated<Json<CreateTour>>,
+        ) -> HttpResponse;
+
+        async fn get_tours_paginated(
+            tour_service: Data<TourServiceDependency>,
+            query: Validated<Query<Pagination>>,
+        ) -> Json<Vec<Tour>>;
+
+        async fn get_tour_by_id(
+            tour_service: Data<TourServiceDependency>,
+            path: Path<Ulid>,
+        ) -> Json<Tour>;
+
+        async fn get_teams_by_tour_id_and_technology_id_paginated(
+            team_service: Data<TeamServiceDependency>,
+            path: Path<Ulid>,
+            query: Validated<Query<Pagination>>,
+            filter: Validated<Query<TeamFilter>>,
+        ) -> Json<Vec<Team>>;
+
+        async fn update_tour_by_id(
+            tour_service: Data<TourServiceDependency>,
+            user: Authorized<Organizer>,
+            path: Path<Ulid>,
+            body: Validated<Json<TourUpdate>>,
+        ) -> Json<Tour>;
+
+        async fn delete_tour_by_id(
+            tour_service: Data<TourServiceDependency>,
+            _user: Authorized<Superadmin>,
+            path: Path<Ulid>,
+        ) -> HttpResponse;
+
+        async fn assign_organizer_by_tour_id_and_user_id(
+            tour_staff_service: Data<TourStaffServiceDependency>,
+            _user: Authorized<Superadmin>,
+            path: Path<(Ulid, Ulid)>,
+        ) -> HttpResponse;
+
+        async fn unassign_organizer_by_tour_id_and_user_id(
+            tour_staff_service: Data<TourStaffServiceDependency>,
+            _user: Authorized<Superadmin>,
+            path: Path<(Ulid, Ulid)>,
+        ) -> HttpResponse;
+
+        async fn assign_mentor_by_tour_id_and_user_id(
+            tour_staff_service: Data<TourStaffServiceDependency>,
+            user: Authorized<Organizer>,
+            path: Path<(Ulid, Ulid)>,
+        ) -> HttpResponse;
+
+        async fn unassign_mentor_by_tour_id_and_user_id(
+            tour_staff_service: Data<TourStaffServiceDependency>,
+            user: Authorized<Organizer>,
+            path: Path<(Ulid, Ulid)>,
+        ) -> HttpResponse;
+    }
+}
@@ -0,0 +1,234 @@
+use actix_web::{
+    HttpResponse, delete, get, put,
+    web::{Data, Json, ReqData},
+};
+use actix_web_lab::extract::{Path, Query};
+use actix_web_validation::Validated;
+use dto::{
+    Pagination,
+    tour_result::{
+        LeaderboardFilter, TeamResultRequest, TourResult, UpsertTourResult,
+    },
+    user::User,
+};
+use macros::handler_implementation;
+use service::tour_result::TourResultServiceDependency;
+use tracing::instrument;
+use ulid::Ulid;
+
+use super::{
+    TourResultHandler, TourResultHandlerHelper, TourResultHandlerResult,
+};
+use crate::common::{
+    ApiError, ValidationError,
+    extractor::{Authorized, Organizer},
+    openapi,
+};
+
+handler_implementation! {
+    TourResultHandler as TourResultHandlerImpl {
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                Pagination,
+            ),
+            security(
+                ("participant" = []),
+                ("mentor" = []),
+                ("organizer" = []),
+            ),
+            responses(
+                (status = 200, description = "", body = Vec<TourResult>),
+                (status = 400, description = "", body = ValidationError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[get("/my")]
+        #[instrument(skip_all, name = "TourResultHandler::get_current_results_paginated")]
+        async fn get_current_results_paginated(
+            tour_result_service: Data<TourResultServiceDependency>,
+            user: ReqData<User>,
+            Validated(Query(pagination)): Validated<Query<Pagination>>,
+        ) -> Json<Vec<TourResult>> {
+            let resp = tour_result_service
+                .find_all_by_user(user.id, pagination.into(), false)
+                .await?;
+            Json(resp)
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                ("user_id" = Ulid, description = ""),
+                Pagination,
+            ),
+            security(
+                ("participant" = []),
+                ("mentor" = []),
+                ("organizer" = []),
+            ),
+            responses(
+                (status = 200, description = "", body = Vec<TourResult>),
+                (status = 404, description = "", body = ApiError),
+                (status = 400, description = "", body = ValidationError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[get("/user/{user_id}")]
+        #[instrument(skip_all, name = "TourResultHandler::get_results_by_user_id_paginated")]
+        async fn get_results_by_user_id_paginated(
+            tour_result_service: Data<TourResultServiceDependency>,
+            Path(user_id): Path<Ulid>,
+            Validated(Query(pagination)): Validated<Query<Pagination>>,
+        ) -> Json<Vec<TourResult>> {
+            let resp = tour_result_service
+                .find_all_by_user(user_id, pagination.into(), true)
+                .await?;
+            Json(resp)
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                ("tour_id" = Ulid, description = ""),
+                Pagination,
+                LeaderboardFilter,
+            ),
+            security(
+                ("participant" = []),
+                ("mentor" = []),
+                ("organizer" = []),
+            ),
+            responses(
+                (status = 200, description = "", body = Vec<TourResult>),
+                (status = 404, description = "", body = ApiError),
+                (status = 400, description = "", body = ValidationError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[get("/{tour_id}")]
+        #[instrument(skip_all, name = "TourResultHandler::get_leaderboard_by_tour_id_paginated")]
+        async fn get_leaderboard_by_tour_id_paginated(
+            tour_result_service: Data<TourResultServiceDependency>,
+            Path(tour_id): Path<Ulid>,
+            Validated(Query(pagination)): Validated<Query<Pagination>>,
+            Validated(Query(filter)): Validated<Query<LeaderboardFilter>>,
+        ) -> Json<Vec<TourResult>> {
+            let resp = tour_result_service
+                .find_leaderboard(tour_id, filter.specialization, pagination.into())
+                .await?;
+            Json(resp)
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                ("tour_id" = Ulid, description = ""),
+                ("team_id" = Ulid, description = ""),
+            ),
+            security(
+                ("organizer" = []),
+            ),
+            request_body(
+                description = "",
+                content = TeamResultRequest
+            ),
+            responses(
+                (status = 200, description = "", body = Vec<TourResult>),
+                (status = 404, description = "", body = ApiError),
+                (status = 400, description = "", body = ValidationError),
+                (status = 403, description = "", body = ApiError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[put("/{tour_id}/team/{team_id}")]
+        #[instrument(skip_all, name = "TourResultHandler::record_team_results_by_tour_id_and_team_id")]
+        async fn record_team_results_by_tour_id_and_team_id(
+            tour_result_service: Data<TourResultServiceDependency>,
+            _user: Authorized<Organizer>,
+            Path((tour_id, team_id)): Path<(Ulid, Ulid)>,
+            Validated(Json(body)): Validated<Json<TeamResultRequest>>,
+        ) -> Json<Vec<TourResult>> {
+            let resp = tour_result_service
+                .record_for_team(tour_id, team_id, body)
+                .await?;
+            Json(resp)
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                ("tour_id" = Ulid, description = ""),
+                ("user_id" = Ulid, description = ""),
+            ),
+            security(
+                ("organizer" = []),
+            ),
+            request_body(
+                description = "",
+                content = UpsertTourResult
+            ),
+            responses(
+                (status = 200, description = "", body = TourResult),
+                (status = 404, description = "", body = ApiError),
+                (status = 400, description = "", body = ValidationError),
+                (status = 403, description = "", body = ApiError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[put("/{tour_id}/user/{user_id}")]
+        #[instrument(skip_all, name = "TourResultHandler::record_result_by_tour_id_and_user_id")]
+        async fn record_result_by_tour_id_and_user_id(
+            tour_result_service: Data<TourResultServiceDependency>,
+            _user: Authorized<Organizer>,
+            Path((tour_id, user_id)): Path<(Ulid, Ulid)>,
+            Validated(Json(body)): Validated<Json<UpsertTourResult>>,
+        ) -> Json<TourResult> {
+            let resp = tour_result_service
+                .record_for_user(tour_id, user_id, body)
+                .await?;
+            Json(resp)
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            params(
+                ("tour_id" = Ulid, description = ""),
+                ("user_id" = Ulid, description = ""),
+            ),
+            security(
+                ("organizer" = []),
+            ),
+            responses(
+                (status = 204, description = ""),
+                (status = 404, description = "", body = ApiError),
+                (status = 403, description = "", body = ApiError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[delete("/{tour_id}/user/{user_id}")]
+        #[instrument(skip_all, name = "TourResultHandler::delete_result_by_tour_id_and_user_id")]
+        async fn delete_result_by_tour_id_and_user_id(
+            tour_result_service: Data<TourResultServiceDependency>,
+            _user: Authorized<Organizer>,
+            Path((tour_id, user_id)): Path<(Ulid, Ulid)>,
+        ) -> HttpResponse {
+            tour_result_service
+                .delete_by_id(tour_id, user_id)
+                .await?;
+            HttpResponse::NoContent().finish()
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use actix_web::{
+    HttpResponse,
+    middleware::from_fn,
+    web::{Data, Json, ReqData},
+};
+use actix_web_lab::extract::{Path, Query};
+use actix_web_validation::Validated;
+use dto::{
+    Pagination,
+    tour_result::{
+        LeaderboardFilter, TeamResultRequest, TourResult, UpsertTourResult,
+    },
+    user::User,
+};
+use macros::handler;
+use service::tour_result::TourResultServiceDependency;
+use ulid::Ulid;
+use utoipa_actix_web::{scope, service_config::ServiceConfig};
+
+use crate::common::{
+    HandlerError,
+    extractor::{Authorized, Organizer},
+    middleware::user_extractor_middleware,
+};
+
+pub mod implementation;
+
+handler! {
+    TourResult
+        Err: HandlerError,
+        Impl: ImplementedTourResultHandler
+    {
+        fn routes(tour_result_service: TourResultServiceDependency) {
+            move |cfg: &mut ServiceConfig| {
+                cfg.app_data(Data::new(tour_result_service))
+                    .service(scope("/results")
+                        .wrap(from_fn(user_extractor_middleware))
+                        .service(Self::get_current_results_paginated())
+                        .service(Self::get_results_by_user_id_paginated())
+                        .service(Self::get_leaderboard_by_tour_id_paginated())
+                        .service(Self::record_team_results_by_tour_id_and_team_id())
+                        .service(Self::record_result_by_tour_id_and_user_id())
+                        .service(Self::delete_result_by_tour_id_and_user_id())
+                    );
+            }
+        }
+
+        async fn get_current_results_paginated(
+            tour_result_service: Data<TourResultServiceDependency>,
+            user: ReqData<User>,
+            query: Validated<Query<Pagination>>,
+        ) -> Json<Vec<TourResult>>;
+
+        async fn get_results_by_user_id_paginated(
+            tour_result_service: Data<TourResultServiceDependency>,
+            path: Path<Ulid>,
+            query: Validated<Query<Pagination>>,
+        ) -> Json<Vec<TourResult>>;
+
+        async fn get_leaderboard_by_tour_id_paginated(
+            tour_result_service: Data<TourResultServiceDependency>,
+            path: Path<Ulid>,
+            query: Validated<Query<Pagination>>,
+            filter: Validated<Query<LeaderboardFilter>>,
+        ) -> Json<Vec<TourResult>>;
+
+        async fn record_team_results_by_tour_id_and_team_id(
+            tour_result_service: Data<TourResultServiceDependency>,
+            _user: Authorized<Organizer>,
+            path: Path<(Ulid, Ulid)>,
+            body: Validated<Json<TeamResultRequest>>,
+        ) -> Json<Vec<TourResult>>;
+
+        async fn record_result_by_tour_id_and_user_id(
+            tour_result_service: Data<TourResultServiceDependency>,
+            _user: Authorized<Organizer>,
+            path: Path<(Ulid, Ulid)>,
+            body: Validated<Json<UpsertTourResult>>,
+        ) -> Json<TourResult>;
+
+        async fn delete_result_by_tour_id_and_user_id(
+            tour_result_service: Data<TourResultServiceDependency>,
+            _user: Authorized<Organizer>,
+            path: Path<(Ulid, Ulid)>,
+        ) -> HttpResponse;
+    }
+}
@@ -1,22 +1,40 @@
 use actix_web::{
-    HttpResponse, delete, get, patch, post, put,
+    HttpRequest, HttpResponse, delete, get, patch, post, put,
     web::{Data, Json, ReqData},
 };
 use actix_web_lab::extract::Path;
 use actix_web_validation::Validated;
 use dto::{
-    auth::{LoginRequest, PasswordChangeRequest},
+    auth::{
+        EmailVerificationRequest, LoginRequest, PasswordChangeRequest,
+        PasswordResetConfirmRequest, PasswordResetRequest, RefreshRequest,
+        TwoFactorCodeRequest, TwoFactorLoginRequest, TwoFactorSetupRequest,
+    },
+    session::Session,
+    two_factor::{
+        TwoFactorChallenge, TwoFactorEnrollment, TwoFactorRecoveryCodes,
+    },
     user::{CreateUser, User, UserUpdate},
 };
 use macros::handler_implementation;
-use service::user::UserServiceDependency;
+use service::{
+    common::ServiceError, permission::Actor, session::SessionServiceDependency,
+    two_factor::TwoFactorServiceDependency, user::UserServiceDependency,
+};
 use tracing::instrument;
 use ulid::Ulid;
 
 use super::{
     UserAuthResponse, UserHandler, UserHandlerHelper, UserHandlerResult,
+    login_response,
+};
+use crate::common::{
+    ApiError, ValidationError,
+    extractor::{Authorized, Organizer},
+    middleware::RateLimiter,
+    openapi,
+    wrapper::CurrentSession,
 };
-use crate::common::{ApiError, ValidationError, openapi};
 
 handler_implementation! {
     UserHandler as UserHandlerImpl {
@@ -41,10 +59,11 @@ handler_implementation! {
         #[instrument(skip_all, name = "UserHandler::register_user")]
         async fn register_user(
             user_service: Data<UserServiceDependency>,
+            user: Authorized<Organizer>,
             Validated(Json(body)): Validated<Json<CreateUser>>
         ) -> HttpResponse {
             let resp: UserAuthResponse = user_service
-                .register(body)
+                .register(body, Actor::from(&*user))
                 .await?
                 .into();
             HttpResponse::Created().json(resp)
@@ -60,22 +79,191 @@ handler_implementation! {
             ),
             responses(
                 (status = 200, description = "", body = UserAuthResponse),
+                (status = 202, description = "", body = TwoFactorChallenge),
                 (status = 401, description = "", body = ApiError),
                 (status = 400, description = "", body = ValidationError),
+                (status = 429, description = "", body = ApiError),
             ),
         )]
         #[post("/login")]
         #[instrument(skip_all, name = "ReviewHandler::user_login")]
         async fn user_login(
             user_service: Data<UserServiceDependency>,
+            rate_limiter: Data<RateLimiter>,
+            req: HttpRequest,
             Validated(Json(body)): Validated<Json<LoginRequest>>,
+        ) -> HttpResponse {
+            let ip = rate_limiter.client_ip(&req);
+            let account = body.email.clone();
+            rate_limiter.check_login(&account, &ip).await?;
+
+            let res = match user_service.login(body).await {
+                Ok(res) => {
+                    rate_limiter.login_succeeded(&account).await;
+                    res
+                },
+                Err(err) => {
+                    if matches!(
+                        err,
+                        ServiceError::InvalidPassword | ServiceError::NotFound(..)
+                    ) {
+                        rate_limiter.login_failed(&account, &ip).await;
+                    }
+                    Err(err)?
+                },
+            };
+            login_response(res)
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            request_body(
+                description = "",
+                content = TwoFactorLoginRequest
+            ),
+            responses(
+                (status = 200, description = "", body = UserAuthResponse),
+                (status = 401, description = "", body = ApiError),
+                (status = 400, description = "", body = ValidationError),
+                (status = 429, description = "", body = ApiError),
+            ),
+        )]
+        #[post("/login/2fa")]
+        #[instrument(skip_all, name = "UserHandler::user_login_two_factor")]
+        async fn user_login_two_factor(
+            two_factor_service: Data<TwoFactorServiceDependency>,
+            rate_limiter: Data<RateLimiter>,
+            req: HttpRequest,
+            Validated(Json(body)): Validated<Json<TwoFactorLoginRequest>>,
         ) -> Json<UserAuthResponse> {
-            let res = user_service
-                .login(body)
+            let ip = rate_limiter.client_ip(&req);
+            let account = two_factor_service
+                .challenged_user(&body.challenge_token)
+                .await?
+                .to_string();
+            rate_limiter.check_login(&account, &ip).await?;
+
+            let res = match two_factor_service.login(body).await {
+                Ok(res) => {
+                    rate_limiter.login_succeeded(&account).await;
+                    res
+                },
+                Err(err) => {
+                    if matches!(err, ServiceError::Unauthorized(..)) {
+                        rate_limiter.login_failed(&account, &ip).await;
+                    }
+                    Err(err)?
+                },
+            };
+            Json(res.into())
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            request_body(
+                description = "",
+                content = RefreshRequest
+            ),
+            responses(
+                (status = 200, description = "", body = UserAuthResponse),
+                (status = 401, description = "", body = ApiError),
+                (status = 400, description = "", body = ValidationError),
+            ),
+        )]
+        #[post("/refresh")]
+        #[instrument(skip_all, name = "UserHandler::refresh_session")]
+        async fn refresh_session(
+            session_service: Data<SessionServiceDependency>,
+            Validated(Json(body)): Validated<Json<RefreshRequest>>,
+        ) -> Json<UserAuthResponse> {
+            let res = session_service
+                .refresh(body)
                 .await?;
             Json(res.into())
         }
 
+        ///
+        ///
+        ///
+        #[openapi(
+            request_body(
+                description = "",
+                content = EmailVerificationRequest
+            ),
+            responses(
+                (status = 200, description = "", body = User),
+                (status = 404, description = "", body = ApiError),
+                (status = 400, description = "", body = ValidationError),
+            ),
+        )]
+        #[post("/verify-email")]
+        #[instrument(skip_all, name = "UserHandler::verify_email")]
+        async fn verify_email(
+            user_service: Data<UserServiceDependency>,
+            Validated(Json(body)): Validated<Json<EmailVerificationRequest>>,
+        ) -> Json<User> {
+            let res = user_service
+                .verify_email(body)
+                .await?;
+            Json(res)
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            request_body(
+                description = "",
+                content = PasswordResetRequest
+            ),
+            responses(
+                (status = 204, description = ""),
+                (status = 400, description = "", body = ValidationError),
+            ),
+        )]
+        #[post("/password-reset")]
+        #[instrument(skip_all, name = "UserHandler::request_password_reset")]
+        async fn request_password_reset(
+            user_service: Data<UserServiceDependency>,
+            Validated(Json(body)): Validated<Json<PasswordResetRequest>>,
+        ) -> HttpResponse {
+            user_service
+                .request_password_reset(body)
+                .await?;
+            HttpResponse::NoContent().finish()
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            request_body(
+                description = "",
+                content = PasswordResetConfirmRequest
+            ),
+            responses(
+                (status = 200, description = "", body = UserAuthResponse),
+                (status = 202, description = "", body = TwoFactorChallenge),
+                (status = 404, description = "", body = ApiError),
+                (status = 400, description = "", body = ValidationError),
+            ),
+        )]
+        #[post("/password-reset/confirm")]
+        #[instrument(skip_all, name = "UserHandler::reset_password")]
+        async fn reset_password(
+            user_service: Data<UserServiceDependency>,
+            Validated(Json(body)): Validated<Json<PasswordResetConfirmRequest>>,
+        ) -> HttpResponse {
+            let res = user_service
+                .reset_password(body)
+                .await?;
+            login_response(res)
+        }
+
         ///
         ///
         ///
@@ -101,6 +289,35 @@ handler_implementation! {
             Json(user)
         }
 
+        ///
+        ///
+        ///
+        #[openapi(
+            security(
+                ("participant" = []),
+                ("mentor" = []),
+                ("organizer" = []),
+            ),
+            responses(
+                (status = 204, description = ""),
+                (status = 409, description = "", body = ApiError),
+                (status = 403, description = "", body = ApiError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[post("/me/verify-email")]
+        #[instrument(skip_all, name = "UserHandler::send_current_user_email_verification")]
+        async fn send_current_user_email_verification(
+            user_service: Data<UserServiceDependency>,
+            user: ReqData<User>,
+        ) -> HttpResponse {
+            let user: User = user.into_inner();
+            user_service
+                .send_email_verification(user.id)
+                .await?;
+            HttpResponse::NoContent().finish()
+        }
+
         ///
         ///
         ///
@@ -136,7 +353,7 @@ handler_implementation! {
             }
 
             let res = user_service
-                .update_by_id(user.id, body, true)
+                .update_by_id(user.id, body, Actor::from(&user))
                 .await?;
             Json(res)
         }
@@ -170,7 +387,7 @@ handler_implementation! {
         ) -> Json<UserAuthResponse> {
             let user: User = user.into_inner();
             let res = user_service
-                .change_password_by_id(user.id, body, true)
+                .change_password_by_id(user.id, body, Actor::from(&user))
                 .await?;
             Json(res.into())
         }
@@ -199,7 +416,225 @@ handler_implementation! {
         ) -> HttpResponse {
             let user: User = user.into_inner();
             user_service
-                .delete_by_id(user.id, false)
+                .delete_by_id(user.id, Actor::from(&user))
+                .await?;
+            HttpResponse::NoContent().finish()
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            security(
+                ("participant" = []),
+                ("mentor" = []),
+                ("organizer" = []),
+            ),
+            request_body(
+                description = "",
+                content = TwoFactorSetupRequest
+            ),
+            responses(
+                (status = 200, description = "", body = TwoFactorEnrollment),
+                (status = 409, description = "", body = ApiError),
+                (status = 400, description = "", body = ValidationError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[post("/me/2fa")]
+        #[instrument(skip_all, name = "UserHandler::enroll_two_factor")]
+        async fn enroll_two_factor(
+            two_factor_service: Data<TwoFactorServiceDependency>,
+            user: ReqData<User>,
+            Validated(Json(body)): Validated<Json<TwoFactorSetupRequest>>,
+        ) -> Json<TwoFactorEnrollment> {
+            let user: User = user.into_inner();
+            let res = two_factor_service
+                .enroll(user.id, body)
+                .await?;
+            Json(res)
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            security(
+                ("participant" = []),
+                ("mentor" = []),
+                ("organizer" = []),
+            ),
+            request_body(
+                description = "",
+                content = TwoFactorCodeRequest
+            ),
+            responses(
+                (status = 200, description = "", body = TwoFactorRecoveryCodes),
+                (status = 409, description = "", body = ApiError),
+                (status = 400, description = "", body = ValidationError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[post("/me/2fa/confirm")]
+        #[instrument(skip_all, name = "UserHandler::confirm_two_factor")]
+        async fn confirm_two_factor(
+            two_factor_service: Data<TwoFactorServiceDependency>,
+            user: ReqData<User>,
+            Validated(Json(body)): Validated<Json<TwoFactorCodeRequest>>,
+        ) -> Json<TwoFactorRecoveryCodes> {
+            let user: User = user.into_inner();
+            let res = two_factor_service
+                .confirm(user.id, body)
+                .await?;
+            Json(res)
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            security(
+                ("participant" = []),
+                ("mentor" = []),
+                ("organizer" = []),
+            ),
+            request_body(
+                description = "",
+                content = TwoFactorCodeRequest
+            ),
+            responses(
+                (status = 200, description = "", body = TwoFactorRecoveryCodes),
+                (status = 409, description = "", body = ApiError),
+                (status = 400, description = "", body = ValidationError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[post("/me/2fa/recovery-codes")]
+        #[instrument(skip_all, name = "UserHandler::regenerate_recovery_codes")]
+        async fn regenerate_recovery_codes(
+            two_factor_service: Data<TwoFactorServiceDependency>,
+            user: ReqData<User>,
+            Validated(Json(body)): Validated<Json<TwoFactorCodeRequest>>,
+        ) -> Json<TwoFactorRecoveryCodes> {
+            let user: User = user.into_inner();
+            let res = two_factor_service
+                .regenerate_recovery_codes(user.id, body)
+                .await?;
+            Json(res)
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            security(
+                ("participant" = []),
+                ("mentor" = []),
+                ("organizer" = []),
+            ),
+            request_body(
+                description = "",
+                content = TwoFactorCodeRequest
+            ),
+            responses(
+                (status = 204, description = ""),
+                (status = 409, description = "", body = ApiError),
+                (status = 400, description = "", body = ValidationError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[post("/me/2fa/disable")]
+        #[instrument(skip_all, name = "UserHandler::disable_two_factor")]
+        async fn disable_two_factor(
+            two_factor_service: Data<TwoFactorServiceDependency>,
+            user: ReqData<User>,
+            Validated(Json(body)): Validated<Json<TwoFactorCodeRequest>>,
+        ) -> HttpResponse {
+            let user: User = user.into_inner();
+            two_factor_service
+                .disable(user.id, body)
+                .await?;
+            HttpResponse::NoContent().finish()
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            security(
+                ("participant" = []),
+                ("mentor" = []),
+                ("organizer" = []),
+            ),
+            responses(
+                (status = 200, description = "", body = Vec<Session>),
+                (status = 403, description = "", body = ApiError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[get("/me/sessions")]
+        #[instrument(skip_all, name = "UserHandler::get_current_user_sessions")]
+        async fn get_current_user_sessions(
+            session_service: Data<SessionServiceDependency>,
+            user: ReqData<User>,
+            session: ReqData<CurrentSession>,
+        ) -> Json<Vec<Session>> {
+            let res = session_service
+                .find_all_by_user(user.id, session.0)
+                .await?;
+            Json(res)
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            security(
+                ("participant" = []),
+                ("mentor" = []),
+                ("organizer" = []),
+            ),
+            responses(
+                (status = 204, description = ""),
+                (status = 403, description = "", body = ApiError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[post("/me/logout")]
+        #[instrument(skip_all, name = "UserHandler::logout")]
+        async fn logout(
+            session_service: Data<SessionServiceDependency>,
+            session: ReqData<CurrentSession>,
+        ) -> HttpResponse {
+            session_service
+                .revoke(session.0)
+                .await?;
+            HttpResponse::NoContent().finish()
+        }
+
+        ///
+        ///
+        ///
+        #[openapi(
+            security(
+                ("participant" = []),
+                ("mentor" = []),
+                ("organizer" = []),
+            ),
+            responses(
+                (status = 204, description = ""),
+                (status = 403, description = "", body = ApiError),
+                (status = 401, description = "", body = ApiError),
+            ),
+        )]
+        #[post("/me/logout-all")]
+        #[instrument(skip_all, name = "UserHandler::logout_all")]
+        async fn logout_all(
+            session_service: Data<SessionServiceDependency>,
+            user: ReqData<User>,
+        ) -> HttpResponse {
+            session_service
+                .revoke_all(user.id)
                 .await?;
             HttpResponse::NoContent().finish()
         }
@@ -268,12 +703,12 @@ handler_implementation! {
         #[instrument(skip_all, name = "ReviewHandler::update_user_by_id")]
         async fn update_user_by_id(
             user_service: Data<UserServiceDependency>,
-            user: ReqData<User>,
+            user: Authorized<Organizer>,
             Path(user_id): Path<Ulid>,
             Validated(Json(body)): Validated<Json<UserUpdate>>,
         ) -> Json<User> {
             let res = user_service
-                .update_by_id(user_id, body, user_id == user.id)
+                .update_by_id(user_id, body, Actor::from(&*user))
                 .await?;
             Json(res)
         }
@@ -304,12 +739,12 @@ handler_implementation! {
         #[instrument(skip_all, name = "ReviewHandler::change_user_password_by_id")]
         async fn change_user_password_by_id(
             user_service: Data<UserServiceDependency>,
-            user: ReqData<User>,
+            user: Authorized<Organizer>,
             Path(user_id): Path<Ulid>,
             Validated(Json(body)): Validated<Json<PasswordChangeRequest>>,
         ) -> Json<UserAuthResponse> {
             let res = user_service
-                .change_password_by_id(user_id, body, user_id == user.id)
+                .change_password_by_id(user_id, body, Actor::from(&*user))
                 .await?;
             Json(res.into())
         }
@@ -335,11 +770,11 @@ handler_implementation! {
         #[instrument(skip_all, name = "ReviewHandler::delete_user_by_id")]
         async fn delete_user_by_id(
             user_service: Data<UserServiceDependency>,
-            user: ReqData<User>,
+            user: Authorized<Organizer>,
             Path(user_id): Path<Ulid>,
         ) -> HttpResponse {
             user_service
-                .delete_by_id(user_id, user_id != user.id)
+                .delete_by_id(user_id, Actor::from(&*user))
                 .await?;
             HttpResponse::NoContent().finish()
         }
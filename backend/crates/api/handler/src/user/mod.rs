@@ -1,21 +1,33 @@
 use actix_web::{
-    HttpResponse,
+    HttpRequest, HttpResponse,
     middleware::from_fn,
     web::{Data, Json, ReqData},
 };
 use actix_web_lab::extract::Path;
 use actix_web_validation::Validated;
 use dto::{
-    auth::{LoginRequest, PasswordChangeRequest},
-    user::{CreateUser, User, UserRole, UserUpdate},
+    auth::{
+        EmailVerificationRequest, LoginRequest, PasswordChangeRequest,
+        PasswordResetConfirmRequest, PasswordResetRequest, RefreshRequest,
+        TwoFactorCodeRequest, TwoFactorLoginRequest, TwoFactorSetupRequest,
+    },
+    session::{Session, SessionTokens},
+    two_factor::{LoginOutcome, TwoFactorEnrollment, TwoFactorRecoveryCodes},
+    user::{CreateUser, User, UserUpdate},
 };
 use macros::{handler, response};
-use service::user::UserServiceDependency;
+use service::{
+    session::SessionServiceDependency, two_factor::TwoFactorServiceDependency,
+    user::UserServiceDependency,
+};
 use ulid::Ulid;
 use utoipa_actix_web::{scope, service_config::ServiceConfig};
 
 use crate::common::{
-    HandlerError, guard::UserRoleGuard, middleware::user_extractor_middleware,
+    HandlerError,
+    extractor::{Authorized, Organizer},
+    middleware::{RateLimiter, user_extractor_middleware},
+    wrapper::CurrentSession,
 };
 
 pub mod implementation;
@@ -25,25 +37,41 @@ handler! {
         Err: HandlerError,
         Impl: ImplementedUserHandler
     {
-        fn routes(user_service: UserServiceDependency) {
+        fn routes(
+            user_service: UserServiceDependency,
+            session_service: SessionServiceDependency,
+            two_factor_service: TwoFactorServiceDependency
+        ) {
             move |cfg: &mut ServiceConfig| {
                 cfg.app_data(Data::new(user_service))
+                    .app_data(Data::new(session_service))
+                    .app_data(Data::new(two_factor_service))
                     .service(scope("/users")
                         .service(Self::user_login())
+                        .service(Self::user_login_two_factor())
+                        .service(Self::refresh_session())
+                        .service(Self::verify_email())
+                        .service(Self::request_password_reset())
+                        .service(Self::reset_password())
                         .service(scope("")
                             .wrap(from_fn(user_extractor_middleware))
                             .service(Self::get_current_user())
+                            .service(Self::send_current_user_email_verification())
                             .service(Self::update_current_user())
                             .service(Self::change_current_user_password())
                             .service(Self::delete_current_user())
+                            .service(Self::enroll_two_factor())
+                            .service(Self::confirm_two_factor())
+                            .service(Self::regenerate_recovery_codes())
+                            .service(Self::disable_two_factor())
+                            .service(Self::get_current_user_sessions())
+                            .service(Self::logout())
+                            .service(Self::logout_all())
                             .service(Self::get_user_by_id())
-                            .service(scope("")
-                                .guard(UserRoleGuard::new(&[UserRole::Organizer]))
-                                .service(Self::register_user())
-                                .service(Self::update_user_by_id())
-                                .service(Self::change_user_password_by_id())
-                                .service(Self::delete_user_by_id())
-                            )
+                            .service(Self::register_user())
+                            .service(Self::update_user_by_id())
+                            .service(Self::change_user_password_by_id())
+                            .service(Self::delete_user_by_id())
                         )
                     );
             }
@@ -51,18 +79,53 @@ handler! {
 
         async fn register_user(
             user_service: Data<UserServiceDependency>,
+            user: Authorized<Organizer>,
             body: Validated<Json<CreateUser>>
         ) -> HttpResponse;
 
         async fn user_login(
             user_service: Data<UserServiceDependency>,
+            rate_limiter: Data<RateLimiter>,
+            req: HttpRequest,
             body: Validated<Json<LoginRequest>>
+        ) -> HttpResponse;
+
+        async fn user_login_two_factor(
+            two_factor_service: Data<TwoFactorServiceDependency>,
+            rate_limiter: Data<RateLimiter>,
+            req: HttpRequest,
+            body: Validated<Json<TwoFactorLoginRequest>>
         ) -> Json<UserAuthResponse>;
 
+        async fn refresh_session(
+            session_service: Data<SessionServiceDependency>,
+            body: Validated<Json<RefreshRequest>>
+        ) -> Json<UserAuthResponse>;
+
+        async fn verify_email(
+            user_service: Data<UserServiceDependency>,
+            body: Validated<Json<EmailVerificationRequest>>
+        ) -> Json<User>;
+
+        async fn request_password_reset(
+            user_service: Data<UserServiceDependency>,
+            body: Validated<Json<PasswordResetRequest>>
+        ) -> HttpResponse;
+
+        async fn reset_password(
+            user_service: Data<UserServiceDependency>,
+            body: Validated<Json<PasswordResetConfirmRequest>>
+        ) -> HttpResponse;
+
         async fn get_current_user(
             user: ReqData<User>,
         ) -> Json<User>;
 
+        async fn send_current_user_email_verification(
+            user_service: Data<UserServiceDependency>,
+            user: ReqData<User>,
+        ) -> HttpResponse;
+
         async fn update_current_user(
             user_service: Data<UserServiceDependency>,
             user: ReqData<User>,
@@ -80,6 +143,46 @@ handler! {
             user: ReqData<User>,
         ) -> HttpResponse;
 
+        async fn enroll_two_factor(
+            two_factor_service: Data<TwoFactorServiceDependency>,
+            user: ReqData<User>,
+            body: Validated<Json<TwoFactorSetupRequest>>
+        ) -> Json<TwoFactorEnrollment>;
+
+        async fn confirm_two_factor(
+            two_factor_service: Data<TwoFactorServiceDependency>,
+            user: ReqData<User>,
+            body: Validated<Json<TwoFactorCodeRequest>>
+        ) -> Json<TwoFactorRecoveryCodes>;
+
+        async fn regenerate_recovery_codes(
+            two_factor_service: Data<TwoFactorServiceDependency>,
+            user: ReqData<User>,
+            body: Validated<Json<TwoFactorCodeRequest>>
+        ) -> Json<TwoFactorRecoveryCodes>;
+
+        async fn disable_two_factor(
+            two_factor_service: Data<TwoFactorServiceDependency>,
+            user: ReqData<User>,
+            body: Validated<Json<TwoFactorCodeRequest>>
+        ) -> HttpResponse;
+
+        async fn get_current_user_sessions(
+            session_service: Data<SessionServiceDependency>,
+            user: ReqData<User>,
+            session: ReqData<CurrentSession>,
+        ) -> Json<Vec<Session>>;
+
+        async fn logout(
+            session_service: Data<SessionServiceDependency>,
+            session: ReqData<CurrentSession>,
+        ) -> HttpResponse;
+
+        async fn logout_all(
+            session_service: Data<SessionServiceDependency>,
+            user: ReqData<User>,
+        ) -> HttpResponse;
+
         async fn get_user_by_id(
             user_service: Data<UserServiceDependency>,
             user: ReqData<User>,
@@ -88,21 +191,21 @@ handler! {
 
         async fn update_user_by_id(
             user_service: Data<UserServiceDependency>,
-            user: ReqData<User>,
+            user: Authorized<Organizer>,
             user_id: Path<Ulid>,
             body: Validated<Json<UserUpdate>>
         ) -> Json<User>;
 
         async fn change_user_password_by_id(
             user_service: Data<UserServiceDependency>,
-            user: ReqData<User>,
+            user: Authorized<Organizer>,
             user_id: Path<Ulid>,
             body: Validated<Json<PasswordChangeRequest>>
         ) -> Json<UserAuthResponse>;
 
         async fn delete_user_by_id(
             user_service: Data<UserServiceDependency>,
-            user: ReqData<User>,
+            user: Authorized<Organizer>,
             user_id: Path<Ulid>
         ) -> HttpResponse;
     }
@@ -114,16 +217,33 @@ response! {
         ///
         token: String,
         ///
+        refresh_token: String,
+        ///
         user: User
     }
 }
 
-impl From<(User, String)> for UserAuthResponse {
+impl From<(User, SessionTokens)> for UserAuthResponse {
     #[tracing::instrument(skip_all, level = "trace")]
-    fn from((user, token): (User, String)) -> Self {
+    fn from((user, tokens): (User, SessionTokens)) -> Self {
         Self {
-            token,
+            token: tokens.token,
+            refresh_token: tokens.refresh_token,
             user,
         }
     }
 }
+
+/// Responds with the session, or with the challenge to complete when the
+/// user has two-factor authentication enabled.
+#[tracing::instrument(skip_all, level = "trace")]
+fn login_response(outcome: LoginOutcome) -> HttpResponse {
+    match outcome {
+        LoginOutcome::Authenticated(user, tokens) => {
+            HttpResponse::Ok().json(UserAuthResponse::from((user, tokens)))
+        },
+        LoginOutcome::TwoFactorRequired(challenge) => {
+            HttpResponse::Accepted().json(challenge)
+        },
+    }
+}
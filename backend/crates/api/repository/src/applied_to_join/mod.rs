@@ -1,4 +1,9 @@
-use entity::{applied_to_join, team::TeamId, user::UserId};
+use entity::{
+    applied_to_join::{self, AppliedToJoin, CreateAppliedToJoin},
+    specialization::SpecializationId,
+    team::TeamId,
+    user::UserId,
+};
 use macros::crud_repository;
 
 use crate::common::RepositoryError;
@@ -9,4 +14,9 @@ pub mod surreal;
 crud_repository! {
     UserId -> applied_to_join -> TeamId
         Err: RepositoryError
+    {
+        async fn save_with_event(&self, new: CreateAppliedToJoin) -> AppliedToJoin;
+        async fn find_by_specialization(&self, specialization: SpecializationId) -> Option<AppliedToJoin>;
+        async fn exists_by_specialization(&self, specialization: SpecializationId) -> bool;
+    }
 }
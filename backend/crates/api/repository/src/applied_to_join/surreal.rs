@@ -3,6 +3,8 @@ use entity::{
         AppliedToJoin, AppliedToJoinId, AppliedToJoinUpdate,
         CreateAppliedToJoin,
     },
+    outbox_event::{CreateOutboxEvent, DomainEvent, OutboxEvent},
+    specialization::SpecializationId,
     team::TeamId,
     user::UserId,
 };
@@ -28,6 +30,31 @@ implementation! {
                 .ok_or(RepositoryError::FailedToSaveObject)?
         }
 
+        #[instrument(skip_all, name = "AppliedToJoinRepository::save_with_event")]
+        async fn save_with_event(&self, new: CreateAppliedToJoin) -> AppliedToJoin {
+            let id = new.get_id();
+            let relation = AppliedToJoin::from(new);
+            let outbox = OutboxEvent::from(CreateOutboxEvent {
+                event: DomainEvent::ApplicationSubmitted {
+                    user: relation.r#in.clone().into(),
+                    team: relation.out.clone().into(),
+                },
+            });
+            self.pool
+                .get()
+                .await?
+                .query(surql_query!("relation/applied_to_join/save_with_event"))
+                .bind(("in", relation.r#in.clone()))
+                .bind(("id", id.record_id()))
+                .bind(("out", relation.out.clone()))
+                .bind(("object", relation))
+                .bind(("outbox_id", outbox.id.clone()))
+                .bind(("outbox", outbox))
+                .await?
+                .take::<Option<AppliedToJoin>>(0)?
+                .ok_or(RepositoryError::FailedToSaveObject)?
+        }
+
         #[instrument(skip_all, name = "AppliedToJoinRepository::find_all_by_in")]
         async fn find_all_by_in(&self, r#in: UserId, limit: u16, offset: u64) -> Vec<AppliedToJoin> {
             self.pool
@@ -98,5 +125,22 @@ implementation! {
                 .delete(self.get_id(&r#in, &out))
                 .await?
         }
+
+        #[instrument(skip_all, name = "AppliedToJoinRepository::find_by_specialization")]
+        async fn find_by_specialization(&self, specialization: SpecializationId) -> Option<AppliedToJoin> {
+            self.pool
+                .get()
+                .await?
+                .query(surql_query!("relation/find_by_specialization"))
+                .bind(("table", AppliedToJoinId::TABLE))
+                .bind(("specialization_id", specialization))
+                .await?
+                .take(0)?
+        }
+
+        #[instrument(skip_all, name = "AppliedToJoinRepository::exists_by_specialization")]
+        async fn exists_by_specialization(&self, specialization: SpecializationId) -> bool {
+            self.find_by_specialization(specialization).await?.is_some()
+        }
     }
 }
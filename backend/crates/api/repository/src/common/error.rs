@@ -24,4 +24,12 @@ pub enum RepositoryError {
     #[cfg(feature = "s3")]
     #[error("S3 error: {0}")]
     S3ByteStream(#[from] aws_sdk_s3::primitives::ByteStreamError),
+
+    #[cfg(feature = "grpc")]
+    #[error("Mail service error: {0}")]
+    Grpc(#[from] tonic::Status),
+
+    #[cfg(feature = "grpc")]
+    #[error("Failed to deliver message {0}")]
+    MailDeliveryFailed(String),
 }
@@ -0,0 +1,76 @@
+use entity::{
+    email_verification::{
+        CreateEmailVerification, EmailVerification, EmailVerificationId,
+        EmailVerificationUpdate,
+    },
+    user::UserId,
+};
+use macros::{EntityId, implementation, surql_query};
+use tracing::instrument;
+use utils::adapters::{MobcPool, SurrealPool};
+
+use super::{EmailVerificationRepository, EmailVerificationRepositoryResult};
+use crate::common::RepositoryError;
+
+implementation! {
+    EmailVerificationRepository {
+        pool: SurrealPool
+    } as SurrealEmailVerificationRepository {
+        #[instrument(skip_all, name = "EmailVerificationRepository::save")]
+        async fn save(&self, new: CreateEmailVerification) -> EmailVerification {
+            let entity: EmailVerification = new.into();
+            self.pool
+                .get()
+                .await?
+                .create(entity.id.record_id())
+                .content(entity)
+                .await?
+                .ok_or(RepositoryError::FailedToSaveObject)?
+        }
+
+        #[instrument(skip_all, name = "EmailVerificationRepository::find_by_id")]
+        async fn find_by_id(&self, id: EmailVerificationId) -> Option<EmailVerification> {
+            self.pool
+                .get()
+                .await?
+                .select(id.record_id())
+                .await?
+        }
+
+        #[instrument(skip_all, name = "EmailVerificationRepository::exists_by_id")]
+        async fn exists_by_id(&self, id: EmailVerificationId) -> bool {
+            self.find_by_id(id).await?.is_some()
+        }
+
+        #[instrument(skip_all, name = "EmailVerificationRepository::update_by_id")]
+        async fn update_by_id(&self, id: EmailVerificationId, update: EmailVerificationUpdate) -> Option<EmailVerification> {
+            self.pool
+                .get()
+                .await?
+                .update(id.record_id())
+                .merge(update)
+                .await?
+        }
+
+        #[instrument(skip_all, name = "EmailVerificationRepository::delete_by_id")]
+        async fn delete_by_id(&self, id: EmailVerificationId) -> Option<EmailVerification> {
+            self.pool
+                .get()
+                .await?
+                .delete(id.record_id())
+                .await?
+        }
+
+        #[instrument(skip_all, name = "EmailVerificationRepository::delete_all_by_user")]
+        async fn delete_all_by_user(&self, user: UserId) -> () {
+            self.pool
+                .get()
+                .await?
+                .query(surql_query!("table/delete_by_user"))
+                .bind(("table", EmailVerificationId::TABLE))
+                .bind(("user_id", user))
+                .await?
+                .check()?;
+        }
+    }
+}
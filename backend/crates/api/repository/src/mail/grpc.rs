@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use entity::mail::Mail;
+use macros::implementation;
+use proto::{
+    auth::BearerToken,
+    mail::{
+        DeliveryStatus, SendTemplatedRequest,
+        mail_service_client::MailServiceClient,
+    },
+};
+use tonic::{
+    codegen::{InterceptedService, http::uri::InvalidUri},
+    metadata::errors::InvalidMetadataValue,
+    transport::Channel,
+};
+use tracing::instrument;
+
+use super::{MailRepository, MailRepositoryResult};
+use crate::common::RepositoryError;
+
+#[derive(thiserror::Error, Debug)]
+pub enum GrpcMailRepositoryError {
+    #[error("Invalid mail service address: {0}")]
+    Address(#[from] InvalidUri),
+
+    #[error("Invalid internal token: {0}")]
+    Token(#[from] InvalidMetadataValue),
+}
+
+implementation! {
+    MailRepository {
+        client: MailServiceClient<InterceptedService<Channel, BearerToken>>
+    } as GrpcMailRepository {
+        #[instrument(skip_all, name = "MailRepository::send")]
+        async fn send(&self, mail: Mail) -> String {
+            let response = self.client
+                .clone()
+                .send_templated(SendTemplatedRequest {
+                    recipient: mail.recipient,
+                    template: mail.template,
+                    locale: mail.locale.unwrap_or_default(),
+                    variables: mail.variables,
+                })
+                .await?
+                .into_inner();
+
+            if response.status() == DeliveryStatus::Failed {
+                Err(RepositoryError::MailDeliveryFailed(response.message_id.clone()))?
+            }
+
+            response.message_id
+        }
+    }
+}
+
+impl GrpcMailRepository {
+    /// The connection is established on the first request, so the API
+    /// starts even when the mail service is not reachable yet.
+    #[tracing::instrument(skip_all, level = "trace")]
+    pub fn connect_lazy(
+        address: String,
+        token: &str,
+    ) -> Result<Arc<Self>, GrpcMailRepositoryError> {
+        let channel = Channel::from_shared(address)?.connect_lazy();
+        let token = BearerToken::new(token)?;
+        Ok(Self::new(MailServiceClient::with_interceptor(
+            channel, token,
+        )))
+    }
+}
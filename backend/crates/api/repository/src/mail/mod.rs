@@ -0,0 +1,17 @@
+use entity::mail::Mail;
+use macros::async_trait::async_trait;
+
+use crate::common::RepositoryError;
+
+#[cfg(feature = "grpc")]
+pub mod grpc;
+
+type MailRepositoryResult<T> = Result<T, RepositoryError>;
+
+#[async_trait]
+pub trait MailRepository {
+    async fn send(&self, mail: Mail) -> MailRepositoryResult<String>;
+}
+
+pub type MailRepositoryDependency =
+    std::sync::Arc<dyn MailRepository + Send + Sync>;
@@ -1,4 +1,11 @@
-use entity::{member_of, team::TeamId, user::UserId};
+use entity::{
+    applied_to_join::AppliedToJoin,
+    member_of::{self, MemberOf},
+    specialization::SpecializationId,
+    team::TeamId,
+    tour::TourId,
+    user::UserId,
+};
 use macros::crud_repository;
 
 use crate::common::RepositoryError;
@@ -9,4 +16,13 @@ pub mod surreal;
 crud_repository! {
     UserId -> member_of -> TeamId
         Err: RepositoryError
+    {
+        async fn accept_with_event(&self, application: AppliedToJoin) -> MemberOf;
+        async fn find_by_in_and_tour(&self, r#in: UserId, tour: TourId) -> Option<MemberOf>;
+        async fn exists_by_in_and_tour(&self, r#in: UserId, tour: TourId) -> bool;
+        async fn count_by_out(&self, out: TeamId) -> u64;
+        async fn find_all_accepted_by_outs(&self, outs: Vec<TeamId>) -> Vec<MemberOf>;
+        async fn find_by_specialization(&self, specialization: SpecializationId) -> Option<MemberOf>;
+        async fn exists_by_specialization(&self, specialization: SpecializationId) -> bool;
+    }
 }
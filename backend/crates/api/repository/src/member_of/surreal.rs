@@ -1,9 +1,14 @@
 use entity::{
+    applied_to_join::{AppliedToJoin, AppliedToJoinId},
     member_of::{CreateMemberOf, MemberOf, MemberOfId, MemberOfUpdate},
+    outbox_event::{CreateOutboxEvent, DomainEvent, OutboxEvent},
+    specialization::SpecializationId,
     team::TeamId,
+    tour::TourId,
     user::UserId,
 };
 use macros::{EntityId, implementation, surql_query};
+use surrealdb::RecordId;
 use tracing::instrument;
 use utils::adapters::{MobcPool, SurrealPool};
 
@@ -25,6 +30,42 @@ implementation! {
                 .ok_or(RepositoryError::FailedToSaveObject)?
         }
 
+        #[instrument(skip_all, name = "MemberOfRepository::accept_with_event")]
+        async fn accept_with_event(&self, application: AppliedToJoin) -> MemberOf {
+            let application_id = RecordId::from_table_key(
+                AppliedToJoinId::TABLE,
+                self.get_id_string(&application.r#in, &application.out),
+            );
+            let new = CreateMemberOf {
+                r#in: application.r#in,
+                out: application.out,
+                specialization: application.specialization,
+            };
+            let id = new.get_id();
+            let mut relation = MemberOf::from(new);
+            relation.accepted = true;
+            let outbox = OutboxEvent::from(CreateOutboxEvent {
+                event: DomainEvent::MemberAccepted {
+                    user: relation.r#in.clone().into(),
+                    team: relation.out.clone().into(),
+                },
+            });
+            self.pool
+                .get()
+                .await?
+                .query(surql_query!("relation/member_of/accept_with_event"))
+                .bind(("in", relation.r#in.clone()))
+                .bind(("id", id.record_id()))
+                .bind(("out", relation.out.clone()))
+                .bind(("object", relation))
+                .bind(("application_id", application_id))
+                .bind(("outbox_id", outbox.id.clone()))
+                .bind(("outbox", outbox))
+                .await?
+                .take::<Option<MemberOf>>(0)?
+                .ok_or(RepositoryError::FailedToSaveObject)?
+        }
+
         #[instrument(skip_all, name = "MemberOfRepository::find_all_by_in")]
         async fn find_all_by_in(&self, r#in: UserId, limit: u16, offset: u64) -> Vec<MemberOf> {
             self.pool
@@ -95,5 +136,65 @@ implementation! {
                 .delete(self.get_id(&r#in, &out))
                 .await?
         }
+
+        #[instrument(skip_all, name = "MemberOfRepository::find_by_in_and_tour")]
+        async fn find_by_in_and_tour(&self, r#in: UserId, tour: TourId) -> Option<MemberOf> {
+            self.pool
+                .get()
+                .await?
+                .query(surql_query!("relation/member_of/find_by_in_and_tour"))
+                .bind(("table", MemberOfId::TABLE))
+                .bind(("in", r#in))
+                .bind(("tour_id", tour))
+                .await?
+                .take(0)?
+        }
+
+        #[instrument(skip_all, name = "MemberOfRepository::exists_by_in_and_tour")]
+        async fn exists_by_in_and_tour(&self, r#in: UserId, tour: TourId) -> bool {
+            self.find_by_in_and_tour(r#in, tour).await?.is_some()
+        }
+
+        #[instrument(skip_all, name = "MemberOfRepository::count_by_out")]
+        async fn count_by_out(&self, out: TeamId) -> u64 {
+            self.pool
+                .get()
+                .await?
+                .query(surql_query!("relation/count_by_out"))
+                .bind(("table", MemberOfId::TABLE))
+                .bind(("out", out))
+                .await?
+                .take::<Option<u64>>(0)?
+                .unwrap_or_default()
+        }
+
+        #[instrument(skip_all, name = "MemberOfRepository::find_all_accepted_by_outs")]
+        async fn find_all_accepted_by_outs(&self, outs: Vec<TeamId>) -> Vec<MemberOf> {
+            self.pool
+                .get()
+                .await?
+                .query(surql_query!("relation/member_of/find_all_accepted_by_outs"))
+                .bind(("table", MemberOfId::TABLE))
+                .bind(("outs", outs))
+                .await?
+                .take(0)?
+        }
+
+        #[instrument(skip_all, name = "MemberOfRepository::find_by_specialization")]
+        async fn find_by_specialization(&self, specialization: SpecializationId) -> Option<MemberOf> {
+            self.pool
+                .get()
+                .await?
+                .query(surql_query!("relation/find_by_specialization"))
+                .bind(("table", MemberOfId::TABLE))
+                .bind(("specialization_id", specialization))
+                .await?
+                .take(0)?
+        }
+
+        #[instrument(skip_all, name = "MemberOfRepository::exists_by_specialization")]
+        async fn exists_by_specialization(&self, specialization: SpecializationId) -> bool {
+            self.find_by_specialization(specialization).await?.is_some()
+        }
     }
 }
@@ -9,4 +9,7 @@ pub mod surreal;
 crud_repository! {
     UserId -> mentors -> TeamId
         Err: RepositoryError
+    {
+        async fn count_by_out(&self, out: TeamId) -> u64;
+    }
 }
@@ -95,5 +95,18 @@ implementation! {
                 .delete(self.get_id(&r#in, &out))
                 .await?
         }
+
+        #[instrument(skip_all, name = "MentorsRepository::count_by_out")]
+        async fn count_by_out(&self, out: TeamId) -> u64 {
+            self.pool
+                .get()
+                .await?
+                .query(surql_query!("relation/count_by_out"))
+                .bind(("table", MentorsId::TABLE))
+                .bind(("out", out))
+                .await?
+                .take::<Option<u64>>(0)?
+                .unwrap_or_default()
+        }
     }
 }
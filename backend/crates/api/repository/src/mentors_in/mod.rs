@@ -0,0 +1,12 @@
+use entity::{mentors_in, tour::TourId, user::UserId};
+use macros::crud_repository;
+
+use crate::common::RepositoryError;
+
+#[cfg(feature = "surrealdb")]
+pub mod surreal;
+
+crud_repository! {
+    UserId -> mentors_in -> TourId
+        Err: RepositoryError
+}
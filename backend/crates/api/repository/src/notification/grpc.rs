@@ -0,0 +1,78 @@
+use std::sync::Arc;
+
+use entity::notification::{Notification, NotificationCategory};
+use macros::implementation;
+use proto::{
+    auth::BearerToken,
+    telegram::{
+        NotificationCategory as ProtoNotificationCategory, NotifyRequest,
+        notification_service_client::NotificationServiceClient,
+    },
+};
+use tonic::{
+    codegen::{InterceptedService, http::uri::InvalidUri},
+    metadata::errors::InvalidMetadataValue,
+    transport::Channel,
+};
+use tracing::instrument;
+
+use super::{NotificationRepository, NotificationRepositoryResult};
+use crate::common::RepositoryError;
+
+#[derive(thiserror::Error, Debug)]
+pub enum GrpcNotificationRepositoryError {
+    #[error("Invalid Telegram service address: {0}")]
+    Address(#[from] InvalidUri),
+
+    #[error("Invalid internal token: {0}")]
+    Token(#[from] InvalidMetadataValue),
+}
+
+implementation! {
+    NotificationRepository {
+        client: NotificationServiceClient<InterceptedService<Channel, BearerToken>>
+    } as GrpcNotificationRepository {
+        #[instrument(skip_all, name = "NotificationRepository::send")]
+        async fn send(&self, notification: Notification) -> String {
+            self.client
+                .clone()
+                .notify(NotifyRequest {
+                    chat_id: notification.chat_id,
+                    category: ProtoNotificationCategory::from(notification.category).into(),
+                    variables: notification.variables,
+                })
+                .await?
+                .into_inner()
+                .notification_id
+        }
+    }
+}
+
+impl GrpcNotificationRepository {
+    /// The connection is established on the first request, so the API
+    /// starts even when the Telegram bot is not reachable yet.
+    #[tracing::instrument(skip_all, level = "trace")]
+    pub fn connect_lazy(
+        address: String,
+        token: &str,
+    ) -> Result<Arc<Self>, GrpcNotificationRepositoryError> {
+        let channel = Channel::from_shared(address)?.connect_lazy();
+        let token = BearerToken::new(token)?;
+        Ok(Self::new(NotificationServiceClient::with_interceptor(
+            channel, token,
+        )))
+    }
+}
+
+impl From<NotificationCategory> for ProtoNotificationCategory {
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn from(category: NotificationCategory) -> Self {
+        use NotificationCategory as NC;
+        match category {
+            NC::ApplicationAccepted => Self::ApplicationAccepted,
+            NC::NewApplicant => Self::NewApplicant,
+            NC::ReviewReceived => Self::ReviewReceived,
+            NC::TourStarting => Self::TourStarting,
+        }
+    }
+}
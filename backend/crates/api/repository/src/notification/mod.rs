@@ -0,0 +1,20 @@
+use entity::notification::Notification;
+use macros::async_trait::async_trait;
+
+use crate::common::RepositoryError;
+
+#[cfg(feature = "grpc")]
+pub mod grpc;
+
+type NotificationRepositoryResult<T> = Result<T, RepositoryError>;
+
+#[async_trait]
+pub trait NotificationRepository {
+    async fn send(
+        &self,
+        notification: Notification,
+    ) -> NotificationRepositoryResult<String>;
+}
+
+pub type NotificationRepositoryDependency =
+    std::sync::Arc<dyn NotificationRepository + Send + Sync>;
@@ -0,0 +1,12 @@
+use entity::notification_settings;
+use macros::urd_repository;
+
+use crate::common::RepositoryError;
+
+#[cfg(feature = "surrealdb")]
+pub mod surreal;
+
+urd_repository! {
+    NotificationSettings
+        Err: RepositoryError
+}
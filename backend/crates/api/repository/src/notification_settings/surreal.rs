@@ -0,0 +1,54 @@
+use entity::notification_settings::{
+    NotificationSettings, NotificationSettingsId, UpsertNotificationSettings,
+};
+use macros::{EntityId, implementation, surql_query};
+use tracing::instrument;
+use utils::adapters::{MobcPool, SurrealPool};
+
+use super::{
+    NotificationSettingsRepository, NotificationSettingsRepositoryResult,
+};
+use crate::common::RepositoryError;
+
+implementation! {
+    NotificationSettingsRepository {
+        pool: SurrealPool
+    } as SurrealNotificationSettingsRepository {
+        #[instrument(skip_all, name = "NotificationSettingsRepository::upsert_by_id")]
+        async fn upsert_by_id(&self, id: NotificationSettingsId, object: UpsertNotificationSettings) -> NotificationSettings {
+            let entity = NotificationSettings::from((object, id));
+            self.pool
+                .get()
+                .await?
+                .query(surql_query!("table/upsert_by_id"))
+                .bind(("id", entity.id.clone()))
+                .bind(("object", entity))
+                .await?
+                .take::<Option<NotificationSettings>>(0)?
+                .ok_or(RepositoryError::FailedToSaveObject)?
+        }
+
+        #[instrument(skip_all, name = "NotificationSettingsRepository::find_by_id")]
+        async fn find_by_id(&self, id: NotificationSettingsId) -> Option<NotificationSettings> {
+            self.pool
+                .get()
+                .await?
+                .select(id.record_id())
+                .await?
+        }
+
+        #[instrument(skip_all, name = "NotificationSettingsRepository::exists_by_id")]
+        async fn exists_by_id(&self, id: NotificationSettingsId) -> bool {
+            self.find_by_id(id).await?.is_some()
+        }
+
+        #[instrument(skip_all, name = "NotificationSettingsRepository::delete_by_id")]
+        async fn delete_by_id(&self, id: NotificationSettingsId) -> Option<NotificationSettings> {
+            self.pool
+                .get()
+                .await?
+                .delete(id.record_id())
+                .await?
+        }
+    }
+}
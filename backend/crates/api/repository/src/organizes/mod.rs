@@ -0,0 +1,17 @@
+use entity::{organizes, tour::TourId, user::UserId};
+use macros::crud_repository;
+
+use crate::common::RepositoryError;
+
+#[cfg(feature = "surrealdb")]
+pub mod surreal;
+
+crud_repository! {
+    UserId -> organizes -> TourId
+        Err: RepositoryError
+    {
+        /// Whether `participant` takes part in any tour organized by `in`,
+        /// as a team member, applicant, mentor or past participant.
+        async fn exists_by_in_and_participant(&self, r#in: UserId, participant: UserId) -> bool;
+    }
+}
@@ -0,0 +1,112 @@
+use entity::{
+    organizes::{CreateOrganizes, Organizes, OrganizesId, OrganizesUpdate},
+    tour::TourId,
+    user::UserId,
+};
+use macros::{EntityId, implementation, surql_query};
+use tracing::instrument;
+use utils::adapters::{MobcPool, SurrealPool};
+
+use super::{OrganizesRepository, OrganizesRepositoryResult};
+use crate::common::RepositoryError;
+
+implementation! {
+    OrganizesRepository {
+        pool: SurrealPool
+    } as SurrealOrganizesRepository {
+        #[instrument(skip_all, name = "OrganizesRepository::save")]
+        async fn save(&self, new: CreateOrganizes) -> Organizes {
+            self.pool
+                .get()
+                .await?
+                .create(new.get_id().record_id())
+                .content(Organizes::from(new))
+                .await?
+                .ok_or(RepositoryError::FailedToSaveObject)?
+        }
+
+        #[instrument(skip_all, name = "OrganizesRepository::find_all_by_in")]
+        async fn find_all_by_in(&self, r#in: UserId, limit: u16, offset: u64) -> Vec<Organizes> {
+            self.pool
+                .get()
+                .await?
+                .query(surql_query!("relation/find_all_by_in"))
+                .bind(("table", OrganizesId::TABLE))
+                .bind(("in", r#in))
+                .bind(("limit", limit))
+                .bind(("offset", offset))
+                .await?
+                .take(0)?
+        }
+
+        #[instrument(skip_all, name = "OrganizesRepository::exists_by_in")]
+        async fn exists_by_in(&self, r#in: UserId) -> bool {
+            !self.find_all_by_in(r#in, 1, 0).await?.is_empty()
+        }
+
+        #[instrument(skip_all, name = "OrganizesRepository::find_all_by_out")]
+        async fn find_all_by_out(&self, out: TourId, limit: u16, offset: u64) -> Vec<Organizes> {
+            self.pool
+                .get()
+                .await?
+                .query(surql_query!("relation/find_all_by_out"))
+                .bind(("table", OrganizesId::TABLE))
+                .bind(("out", out))
+                .bind(("limit", limit))
+                .bind(("offset", offset))
+                .await?
+                .take(0)?
+        }
+
+        #[instrument(skip_all, name = "OrganizesRepository::exists_by_out")]
+        async fn exists_by_out(&self, out: TourId) -> bool {
+            !self.find_all_by_out(out, 1, 0).await?.is_empty()
+        }
+
+        #[instrument(skip_all, name = "OrganizesRepository::find_by_in_and_out")]
+        async fn find_by_in_and_out(&self, r#in: UserId, out: TourId) -> Option<Organizes> {
+            self.pool
+                .get()
+                .await?
+                .select(self.get_id(&r#in, &out))
+                .await?
+        }
+
+        #[instrument(skip_all, name = "OrganizesRepository::exists_by_in_and_out")]
+        async fn exists_by_in_and_out(&self, r#in: UserId, out: TourId) -> bool {
+            self.find_by_in_and_out(r#in, out).await?.is_some()
+        }
+
+        #[instrument(skip_all, name = "OrganizesRepository::update_by_in_and_out")]
+        async fn update_by_in_and_out(&self, r#in: UserId, out: TourId, update: OrganizesUpdate) -> Option<Organizes> {
+            self.pool
+                .get()
+                .await?
+                .update(self.get_id(&r#in, &out))
+                .merge(update)
+                .await?
+        }
+
+        #[instrument(skip_all, name = "OrganizesRepository::delete_by_in_and_out")]
+        async fn delete_by_in_and_out(&self, r#in: UserId, out: TourId) -> Option<Organizes> {
+            self.pool
+                .get()
+                .await?
+                .delete(self.get_id(&r#in, &out))
+                .await?
+        }
+
+        #[instrument(skip_all, name = "OrganizesRepository::exists_by_in_and_participant")]
+        async fn exists_by_in_and_participant(&self, r#in: UserId, participant: UserId) -> bool {
+            self.pool
+                .get()
+                .await?
+                .query(surql_query!("relation/organizes/exists_by_in_and_participant"))
+                .bind(("in", r#in))
+                .bind(("participant", participant))
+                .await?
+                .take::<Option<bool>>(0)?
+                .unwrap_or_default()
+        }
+    }
+}
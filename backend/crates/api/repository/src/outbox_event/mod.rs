@@ -0,0 +1,19 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use entity::outbox_event::{self, OutboxEvent};
+use macros::crud_repository;
+
+use crate::common::RepositoryError;
+
+#[cfg(feature = "surrealdb")]
+pub mod surreal;
+
+crud_repository! {
+    OutboxEvent
+        Err: RepositoryError
+    {
+        async fn find_all_due(&self, now: DateTime<Utc>, limit: u16) -> Vec<OutboxEvent>;
+        async fn count_by_status(&self) -> HashMap<String, u32>;
+    }
+}
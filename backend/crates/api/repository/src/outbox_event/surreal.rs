@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use entity::outbox_event::{
+    CreateOutboxEvent, OutboxEvent, OutboxEventId, OutboxEventUpdate,
+};
+use macros::{EntityId, implementation, surql_query};
+use surrealdb::Value;
+use tracing::instrument;
+use utils::adapters::{MobcPool, SurrealPool};
+
+use super::{OutboxEventRepository, OutboxEventRepositoryResult};
+use crate::common::{ExtractValue as _, RepositoryError};
+
+implementation! {
+    OutboxEventRepository {
+        pool: SurrealPool
+    } as SurrealOutboxEventRepository {
+        #[instrument(skip_all, name = "OutboxEventRepository::save")]
+        async fn save(&self, new: CreateOutboxEvent) -> OutboxEvent {
+            let entity: OutboxEvent = new.into();
+            self.pool
+                .get()
+                .await?
+                .create(entity.id.record_id())
+                .content(entity)
+                .await?
+                .ok_or(RepositoryError::FailedToSaveObject)?
+        }
+
+        #[instrument(skip_all, name = "OutboxEventRepository::find_by_id")]
+        async fn find_by_id(&self, id: OutboxEventId) -> Option<OutboxEvent> {
+            self.pool
+                .get()
+                .await?
+                .select(id.record_id())
+                .await?
+        }
+
+        #[instrument(skip_all, name = "OutboxEventRepository::exists_by_id")]
+        async fn exists_by_id(&self, id: OutboxEventId) -> bool {
+            self.find_by_id(id).await?.is_some()
+        }
+
+        #[instrument(skip_all, name = "OutboxEventRepository::update_by_id")]
+        async fn update_by_id(&self, id: OutboxEventId, update: OutboxEventUpdate) -> Option<OutboxEvent> {
+            self.pool
+                .get()
+                .await?
+                .update(id.record_id())
+                .merge(update)
+                .await?
+        }
+
+        #[instrument(skip_all, name = "OutboxEventRepository::delete_by_id")]
+        async fn delete_by_id(&self, id: OutboxEventId) -> Option<OutboxEvent> {
+            self.pool
+                .get()
+                .await?
+                .delete(id.record_id())
+                .await?
+        }
+
+        #[instrument(skip_all, name = "OutboxEventRepository::find_all_due")]
+        async fn find_all_due(&self, now: DateTime<Utc>, limit: u16) -> Vec<OutboxEvent> {
+            self.pool
+                .get()
+                .await?
+                .query(surql_query!("table/outbox_event/find_all_due"))
+                .bind(("table", OutboxEventId::TABLE))
+                .bind(("now", now))
+                .bind(("limit", limit))
+                .await?
+                .take(0)?
+        }
+
+        #[instrument(skip_all, name = "OutboxEventRepository::count_by_status")]
+        async fn count_by_status(&self) -> HashMap<String, u32> {
+            self.pool
+                .get()
+                .await?
+                .query(surql_query!("table/count_by_field"))
+                .bind(("table", OutboxEventId::TABLE))
+                .bind(("field", "status"))
+                .await?
+                .take::<Value>(0)?
+                .extract()
+        }
+    }
+}
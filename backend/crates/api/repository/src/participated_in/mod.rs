@@ -1,4 +1,10 @@
-use entity::{participated_in, tour::TourId, user::UserId};
+use entity::{
+    participated_in::{self, ParticipatedIn},
+    specialization::SpecializationId,
+    technology::TechnologyId,
+    tour::TourId,
+    user::UserId,
+};
 use macros::crud_repository;
 
 use crate::common::RepositoryError;
@@ -9,4 +15,11 @@ pub mod surreal;
 crud_repository! {
     UserId -> participated_in -> TourId
         Err: RepositoryError
+    {
+        async fn find_all_by_out_order_by_score(&self, out: TourId, specialization: Option<SpecializationId>, limit: u16, offset: u64) -> Vec<ParticipatedIn>;
+        async fn find_by_specialization(&self, specialization: SpecializationId) -> Option<ParticipatedIn>;
+        async fn exists_by_specialization(&self, specialization: SpecializationId) -> bool;
+        async fn find_by_technology(&self, technology: TechnologyId) -> Option<ParticipatedIn>;
+        async fn exists_by_technology(&self, technology: TechnologyId) -> bool;
+    }
 }
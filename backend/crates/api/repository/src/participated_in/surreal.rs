@@ -3,6 +3,8 @@ use entity::{
         CreateParticipatedIn, ParticipatedIn, ParticipatedInId,
         ParticipatedInUpdate,
     },
+    specialization::SpecializationId,
+    technology::TechnologyId,
     tour::TourId,
     user::UserId,
 };
@@ -98,5 +100,54 @@ implementation! {
                 .delete(self.get_id(&r#in, &out))
                 .await?
         }
+
+        #[instrument(skip_all, name = "ParticipatedInRepository::find_all_by_out_order_by_score")]
+        async fn find_all_by_out_order_by_score(&self, out: TourId, specialization: Option<SpecializationId>, limit: u16, offset: u64) -> Vec<ParticipatedIn> {
+            self.pool
+                .get()
+                .await?
+                .query(surql_query!("relation/participated_in/find_all_by_out_order_by_score"))
+                .bind(("table", ParticipatedInId::TABLE))
+                .bind(("out", out))
+                .bind(("specialization_id", specialization))
+                .bind(("limit", limit))
+                .bind(("offset", offset))
+                .await?
+                .take(0)?
+        }
+
+        #[instrument(skip_all, name = "ParticipatedInRepository::find_by_specialization")]
+        async fn find_by_specialization(&self, specialization: SpecializationId) -> Option<ParticipatedIn> {
+            self.pool
+                .get()
+                .await?
+                .query(surql_query!("relation/find_by_specialization"))
+                .bind(("table", ParticipatedInId::TABLE))
+                .bind(("specialization_id", specialization))
+                .await?
+                .take(0)?
+        }
+
+        #[instrument(skip_all, name = "ParticipatedInRepository::exists_by_specialization")]
+        async fn exists_by_specialization(&self, specialization: SpecializationId) -> bool {
+            self.find_by_specialization(specialization).await?.is_some()
+        }
+
+        #[instrument(skip_all, name = "ParticipatedInRepository::find_by_technology")]
+        async fn find_by_technology(&self, technology: TechnologyId) -> Option<ParticipatedIn> {
+            self.pool
+                .get()
+                .await?
+                .query(surql_query!("relation/participated_in/find_by_technology"))
+                .bind(("table", ParticipatedInId::TABLE))
+                .bind(("technology_id", technology))
+                .await?
+                .take(0)?
+        }
+
+        #[instrument(skip_all, name = "ParticipatedInRepository::exists_by_technology")]
+        async fn exists_by_technology(&self, technology: TechnologyId) -> bool {
+            self.find_by_technology(technology).await?.is_some()
+        }
     }
 }
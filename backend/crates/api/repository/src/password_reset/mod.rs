@@ -0,0 +1,15 @@
+use entity::{password_reset, user::UserId};
+use macros::crud_repository;
+
+use crate::common::RepositoryError;
+
+#[cfg(feature = "surrealdb")]
+pub mod surreal;
+
+crud_repository! {
+    PasswordReset
+        Err: RepositoryError
+    {
+        async fn delete_all_by_user(&self, user: UserId) -> ();
+    }
+}
@@ -0,0 +1,76 @@
+use entity::{
+    password_reset::{
+        CreatePasswordReset, PasswordReset, PasswordResetId,
+        PasswordResetUpdate,
+    },
+    user::UserId,
+};
+use macros::{EntityId, implementation, surql_query};
+use tracing::instrument;
+use utils::adapters::{MobcPool, SurrealPool};
+
+use super::{PasswordResetRepository, PasswordResetRepositoryResult};
+use crate::common::RepositoryError;
+
+implementation! {
+    PasswordResetRepository {
+        pool: SurrealPool
+    } as SurrealPasswordResetRepository {
+        #[instrument(skip_all, name = "PasswordResetRepository::save")]
+        async fn save(&self, new: CreatePasswordReset) -> PasswordReset {
+            let entity: PasswordReset = new.into();
+            self.pool
+                .get()
+                .await?
+                .create(entity.id.record_id())
+                .content(entity)
+                .await?
+                .ok_or(RepositoryError::FailedToSaveObject)?
+        }
+
+        #[instrument(skip_all, name = "PasswordResetRepository::find_by_id")]
+        async fn find_by_id(&self, id: PasswordResetId) -> Option<PasswordReset> {
+            self.pool
+                .get()
+                .await?
+                .select(id.record_id())
+                .await?
+        }
+
+        #[instrument(skip_all, name = "PasswordResetRepository::exists_by_id")]
+        async fn exists_by_id(&self, id: PasswordResetId) -> bool {
+            self.find_by_id(id).await?.is_some()
+        }
+
+        #[instrument(skip_all, name = "PasswordResetRepository::update_by_id")]
+        async fn update_by_id(&self, id: PasswordResetId, update: PasswordResetUpdate) -> Option<PasswordReset> {
+            self.pool
+                .get()
+                .await?
+                .update(id.record_id())
+                .merge(update)
+                .await?
+        }
+
+        #[instrument(skip_all, name = "PasswordResetRepository::delete_by_id")]
+        async fn delete_by_id(&self, id: PasswordResetId) -> Option<PasswordReset> {
+            self.pool
+                .get()
+                .await?
+                .delete(id.record_id())
+                .await?
+        }
+
+        #[instrument(skip_all, name = "PasswordResetRepository::delete_all_by_user")]
+        async fn delete_all_by_user(&self, user: UserId) -> () {
+            self.pool
+                .get()
+                .await?
+                .query(surql_query!("table/delete_by_user"))
+                .bind(("table", PasswordResetId::TABLE))
+                .bind(("user_id", user))
+                .await?
+                .check()?;
+        }
+    }
+}
@@ -1,6 +1,9 @@
 use std::collections::HashMap;
 
-use entity::{reviewed, user::UserId};
+use entity::{
+    reviewed::{self, Reviewed, UpsertReviewed},
+    user::UserId,
+};
 use macros::urd_repository;
 
 use crate::common::RepositoryError;
@@ -12,6 +15,7 @@ urd_repository! {
     UserId -> reviewed -> UserId
         Err: RepositoryError
     {
+        async fn upsert_by_in_and_out_with_event(&self, r#in: UserId, out: UserId, object: UpsertReviewed) -> Reviewed;
         async fn count_by_score(&self) -> HashMap<u16, u32>;
     }
 }
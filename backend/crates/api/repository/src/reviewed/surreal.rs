@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
 use entity::{
+    outbox_event::{CreateOutboxEvent, DomainEvent, OutboxEvent},
     reviewed::{Reviewed, ReviewedId, UpsertReviewed},
     user::UserId,
 };
@@ -31,6 +32,30 @@ implementation! {
                 .ok_or(RepositoryError::FailedToSaveObject)?
         }
 
+        #[instrument(skip_all, name = "ReviewedRepository::upsert_by_in_and_out_with_event")]
+        async fn upsert_by_in_and_out_with_event(&self, r#in: UserId, out: UserId, object: UpsertReviewed) -> Reviewed {
+            let outbox = OutboxEvent::from(CreateOutboxEvent {
+                event: DomainEvent::ReviewUpserted {
+                    reviewer: r#in.clone().into(),
+                    reviewee: out.clone().into(),
+                    score: object.score,
+                },
+            });
+            self.pool
+                .get()
+                .await?
+                .query(surql_query!("relation/reviewed/upsert_by_in_and_out_with_event"))
+                .bind(("in", r#in))
+                .bind(("id", object.get_id().record_id()))
+                .bind(("out", out))
+                .bind(("object", object))
+                .bind(("outbox_id", outbox.id.clone()))
+                .bind(("outbox", outbox))
+                .await?
+                .take::<Option<Reviewed>>(0)?
+                .ok_or(RepositoryError::FailedToSaveObject)?
+        }
+
         #[instrument(skip_all, name = "ReviewedRepository::find_all_by_in")]
         async fn find_all_by_in(&self, r#in: UserId, limit: u16, offset: u64) -> Vec<Reviewed> {
             self.pool
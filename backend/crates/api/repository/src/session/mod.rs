@@ -0,0 +1,28 @@
+use entity::{
+    session::{self, Session, SessionId},
+    user::UserId,
+};
+use macros::crud_repository;
+use ulid::Ulid;
+
+use crate::common::RepositoryError;
+
+#[cfg(feature = "surrealdb")]
+pub mod surreal;
+
+crud_repository! {
+    Session
+        Err: RepositoryError
+    {
+        /// Marks the session as rotated unless it already is, returning
+        /// `None` when another exchange of the same token got there first.
+        async fn rotate_by_id(&self, id: SessionId) -> Option<Session>;
+        async fn find_active_by_family(&self, family: Ulid) -> Option<Session>;
+        async fn exists_active_by_family(&self, family: Ulid) -> bool;
+        async fn find_all_active_by_user(&self, user: UserId) -> Vec<Session>;
+        async fn delete_all_by_family(&self, family: Ulid) -> ();
+        async fn delete_all_by_user(&self, user: UserId) -> ();
+        /// Deletes the sessions whose every refresh token has expired.
+        async fn delete_all_stale(&self) -> ();
+    }
+}
@@ -0,0 +1,137 @@
+use entity::{
+    session::{CreateSession, Session, SessionId, SessionUpdate},
+    user::UserId,
+};
+use macros::{EntityId, implementation, surql_query};
+use tracing::instrument;
+use ulid::Ulid;
+use utils::adapters::{MobcPool, SurrealPool};
+
+use super::{SessionRepository, SessionRepositoryResult};
+use crate::common::RepositoryError;
+
+implementation! {
+    SessionRepository {
+        pool: SurrealPool
+    } as SurrealSessionRepository {
+        #[instrument(skip_all, name = "SessionRepository::save")]
+        async fn save(&self, new: CreateSession) -> Session {
+            let entity: Session = new.into();
+            self.pool
+                .get()
+                .await?
+                .create(entity.id.record_id())
+                .content(entity)
+                .await?
+                .ok_or(RepositoryError::FailedToSaveObject)?
+        }
+
+        #[instrument(skip_all, name = "SessionRepository::find_by_id")]
+        async fn find_by_id(&self, id: SessionId) -> Option<Session> {
+            self.pool
+                .get()
+                .await?
+                .select(id.record_id())
+                .await?
+        }
+
+        #[instrument(skip_all, name = "SessionRepository::exists_by_id")]
+        async fn exists_by_id(&self, id: SessionId) -> bool {
+            self.find_by_id(id).await?.is_some()
+        }
+
+        #[instrument(skip_all, name = "SessionRepository::update_by_id")]
+        async fn update_by_id(&self, id: SessionId, update: SessionUpdate) -> Option<Session> {
+            self.pool
+                .get()
+                .await?
+                .update(id.record_id())
+                .merge(update)
+                .await?
+        }
+
+        #[instrument(skip_all, name = "SessionRepository::delete_by_id")]
+        async fn delete_by_id(&self, id: SessionId) -> Option<Session> {
+            self.pool
+                .get()
+                .await?
+                .delete(id.record_id())
+                .await?
+        }
+
+        #[instrument(skip_all, name = "SessionRepository::rotate_by_id")]
+        async fn rotate_by_id(&self, id: SessionId) -> Option<Session> {
+            self.pool
+                .get()
+                .await?
+                .query(surql_query!("table/session/rotate_by_id"))
+                .bind(("id", id))
+                .await?
+                .take(0)?
+        }
+
+        #[instrument(skip_all, name = "SessionRepository::find_active_by_family")]
+        async fn find_active_by_family(&self, family: Ulid) -> Option<Session> {
+            self.pool
+                .get()
+                .await?
+                .query(surql_query!("table/session/find_active_by_family"))
+                .bind(("table", SessionId::TABLE))
+                .bind(("family", family))
+                .await?
+                .take(0)?
+        }
+
+        #[instrument(skip_all, name = "SessionRepository::exists_active_by_family")]
+        async fn exists_active_by_family(&self, family: Ulid) -> bool {
+            self.find_active_by_family(family).await?.is_some()
+        }
+
+        #[instrument(skip_all, name = "SessionRepository::find_all_active_by_user")]
+        async fn find_all_active_by_user(&self, user: UserId) -> Vec<Session> {
+            self.pool
+                .get()
+                .await?
+                .query(surql_query!("table/session/find_all_active_by_user"))
+                .bind(("table", SessionId::TABLE))
+                .bind(("user_id", user))
+                .await?
+                .take(0)?
+        }
+
+        #[instrument(skip_all, name = "SessionRepository::delete_all_by_family")]
+        async fn delete_all_by_family(&self, family: Ulid) -> () {
+            self.pool
+                .get()
+                .await?
+                .query(surql_query!("table/session/delete_by_family"))
+                .bind(("table", SessionId::TABLE))
+                .bind(("family", family))
+                .await?
+                .check()?;
+        }
+
+        #[instrument(skip_all, name = "SessionRepository::delete_all_by_user")]
+        async fn delete_all_by_user(&self, user: UserId) -> () {
+            self.pool
+                .get()
+                .await?
+                .query(surql_query!("table/delete_by_user"))
+                .bind(("table", SessionId::TABLE))
+                .bind(("user_id", user))
+                .await?
+                .check()?;
+        }
+
+        #[instrument(skip_all, name = "SessionRepository::delete_all_stale")]
+        async fn delete_all_stale(&self) -> () {
+            self.pool
+                .get()
+                .await?
+                .query(surql_query!("table/session/delete_stale"))
+                .bind(("table", SessionId::TABLE))
+                .await?
+                .check()?;
+        }
+    }
+}
@@ -74,5 +74,18 @@ implementation! {
                 .delete(id.record_id())
                 .await?
         }
+
+        #[instrument(skip_all, name = "SpecializationRepository::find_all")]
+        async fn find_all(&self, limit: u16, offset: u64) -> Vec<Specialization> {
+            self.pool
+                .get()
+                .await?
+                .query(surql_query!("table/find_all"))
+                .bind(("table", SpecializationId::TABLE))
+                .bind(("limit", limit))
+                .bind(("offset", offset))
+                .await?
+                .take(0)?
+        }
     }
 }
@@ -1,5 +1,7 @@
 use entity::{
-    team::{self, Team},
+    specialization::SpecializationId,
+    team::{self, CreateTeam, Team},
+    technology::TechnologyId,
     tour::TourId,
     user::UserId,
 };
@@ -14,11 +16,13 @@ crud_repository! {
     Team
         Err: RepositoryError
     {
+        async fn save_with_lead(&self, new: CreateTeam, specialization: SpecializationId) -> Team;
         async fn find_by_tour_and_name(&self, tour: TourId, name: &str) -> Option<Team>;
         async fn exists_by_tour_and_name(&self, tour: TourId, name: &str) -> bool;
         async fn find_by_tour_and_lead(&self, tour: TourId, lead: UserId) -> Option<Team>;
         async fn exists_by_tour_and_lead(&self, tour: TourId, lead: UserId) -> bool;
         async fn find_all_by_tour(&self, tour: TourId, limit: u64, offset: u64) -> Vec<Team>;
         async fn exists_by_tour(&self, tour: TourId) -> bool;
+        async fn find_all_by_tour_and_technology(&self, tour: TourId, technology: TechnologyId, limit: u64, offset: u64) -> Vec<Team>;
     }
 }
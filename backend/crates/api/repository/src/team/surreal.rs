@@ -1,5 +1,8 @@
 use entity::{
+    member_of::{CreateMemberOf, MemberOf},
+    specialization::SpecializationId,
     team::{CreateTeam, Team, TeamId, TeamUpdate},
+    technology::TechnologyId,
     tour::TourId,
     user::UserId,
 };
@@ -26,6 +29,29 @@ implementation! {
                 .ok_or(RepositoryError::FailedToSaveObject)?
         }
 
+        #[instrument(skip_all, name = "TeamRepository::save_with_lead")]
+        async fn save_with_lead(&self, new: CreateTeam, specialization: SpecializationId) -> Team {
+            let entity: Team = new.into();
+            let mut membership = MemberOf::from(CreateMemberOf {
+                r#in: entity.lead.clone(),
+                out: entity.id.clone(),
+                specialization,
+            });
+            membership.accepted = true;
+            self.pool
+                .get()
+                .await?
+                .query(surql_query!("table/team/save_with_lead"))
+                .bind(("id", entity.id.record_id()))
+                .bind(("lead", entity.lead.clone()))
+                .bind(("membership_id", membership.id.record_id()))
+                .bind(("object", entity))
+                .bind(("membership", membership))
+                .await?
+                .take::<Option<Team>>(0)?
+                .ok_or(RepositoryError::FailedToSaveObject)?
+        }
+
         #[instrument(skip_all, name = "TeamRepository::find_by_id")]
         async fn find_by_id(&self, id: TeamId) -> Option<Team> {
             self.pool
@@ -95,6 +121,21 @@ implementation! {
             !self.find_all_by_tour(tour, 1, 0).await?.is_empty()
         }
 
+        #[instrument(skip_all, name = "TeamRepository::find_all_by_tour_and_technology")]
+        async fn find_all_by_tour_and_technology(&self, tour: TourId, technology: TechnologyId, limit: u64, offset: u64) -> Vec<Team> {
+            self.pool
+                .get()
+                .await?
+                .query(surql_query!("relation/uses/find_teams_by_tour_and_technology"))
+                .bind(("table", TeamId::TABLE))
+                .bind(("tour_id", tour))
+                .bind(("technology_id", technology))
+                .bind(("limit", limit))
+                .bind(("offset", offset))
+                .await?
+                .take(0)?
+        }
+
         #[instrument(skip_all, name = "TeamRepository::update_by_id")]
         async fn update_by_id(&self, id: TeamId, update: TeamUpdate) -> Option<Team> {
             self.pool
@@ -12,5 +12,6 @@ crud_repository! {
     {
         async fn find_by_name(&self, name: &str) -> Option<Technology>;
         async fn exists_by_name(&self, name: &str) -> bool;
+        async fn find_all(&self, limit: u16, offset: u64) -> Vec<Technology>;
     }
 }
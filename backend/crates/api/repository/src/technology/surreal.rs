@@ -73,5 +73,18 @@ implementation! {
                 .delete(id.record_id())
                 .await?
         }
+
+        #[instrument(skip_all, name = "TechnologyRepository::find_all")]
+        async fn find_all(&self, limit: u16, offset: u64) -> Vec<Technology> {
+            self.pool
+                .get()
+                .await?
+                .query(surql_query!("table/find_all"))
+                .bind(("table", TechnologyId::TABLE))
+                .bind(("limit", limit))
+                .bind(("offset", offset))
+                .await?
+                .take(0)?
+        }
     }
 }
@@ -0,0 +1,19 @@
+use entity::{
+    telegram_link::{self, TelegramLink},
+    user::UserId,
+};
+use macros::crud_repository;
+
+use crate::common::RepositoryError;
+
+#[cfg(feature = "surrealdb")]
+pub mod surreal;
+
+crud_repository! {
+    TelegramLink
+        Err: RepositoryError
+    {
+        async fn find_by_code(&self, code: &str) -> Option<TelegramLink>;
+        async fn delete_all_by_user(&self, user: UserId) -> ();
+    }
+}
@@ -0,0 +1,87 @@
+use entity::{
+    telegram_link::{
+        CreateTelegramLink, TelegramLink, TelegramLinkId, TelegramLinkUpdate,
+    },
+    user::UserId,
+};
+use macros::{EntityId, implementation, surql_query};
+use tracing::instrument;
+use utils::adapters::{MobcPool, SurrealPool};
+
+use super::{TelegramLinkRepository, TelegramLinkRepositoryResult};
+use crate::common::RepositoryError;
+
+implementation! {
+    TelegramLinkRepository {
+        pool: SurrealPool
+    } as SurrealTelegramLinkRepository {
+        #[instrument(skip_all, name = "TelegramLinkRepository::save")]
+        async fn save(&self, new: CreateTelegramLink) -> TelegramLink {
+            let entity: TelegramLink = new.into();
+            self.pool
+                .get()
+                .await?
+                .create(entity.id.record_id())
+                .content(entity)
+                .await?
+                .ok_or(RepositoryError::FailedToSaveObject)?
+        }
+
+        #[instrument(skip_all, name = "TelegramLinkRepository::find_by_id")]
+        async fn find_by_id(&self, id: TelegramLinkId) -> Option<TelegramLink> {
+            self.pool
+                .get()
+                .await?
+                .select(id.record_id())
+                .await?
+        }
+
+        #[instrument(skip_all, name = "TelegramLinkRepository::exists_by_id")]
+        async fn exists_by_id(&self, id: TelegramLinkId) -> bool {
+            self.find_by_id(id).await?.is_some()
+        }
+
+        #[instrument(skip_all, name = "TelegramLinkRepository::update_by_id")]
+        async fn update_by_id(&self, id: TelegramLinkId, update: TelegramLinkUpdate) -> Option<TelegramLink> {
+            self.pool
+                .get()
+                .await?
+                .update(id.record_id())
+                .merge(update)
+                .await?
+        }
+
+        #[instrument(skip_all, name = "TelegramLinkRepository::delete_by_id")]
+        async fn delete_by_id(&self, id: TelegramLinkId) -> Option<TelegramLink> {
+            self.pool
+                .get()
+                .await?
+                .delete(id.record_id())
+                .await?
+        }
+
+        #[instrument(skip_all, name = "TelegramLinkRepository::find_by_code")]
+        async fn find_by_code(&self, code: &str) -> Option<TelegramLink> {
+            self.pool
+                .get()
+                .await?
+                .query(surql_query!("table/telegram_link/find_by_code"))
+                .bind(("table", TelegramLinkId::TABLE))
+                .bind(("code", code.to_string()))
+                .await?
+                .take(0)?
+        }
+
+        #[instrument(skip_all, name = "TelegramLinkRepository::delete_all_by_user")]
+        async fn delete_all_by_user(&self, user: UserId) -> () {
+            self.pool
+                .get()
+                .await?
+                .query(surql_query!("table/delete_by_user"))
+                .bind(("table", TelegramLinkId::TABLE))
+                .bind(("user_id", user))
+                .await?
+                .check()?;
+        }
+    }
+}
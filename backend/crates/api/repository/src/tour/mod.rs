@@ -1,4 +1,8 @@
-use entity::tour::{self, Tour};
+use chrono::{DateTime, Utc};
+use entity::{
+    specialization::SpecializationId,
+    tour::{self, Tour},
+};
 use macros::crud_repository;
 
 use crate::common::RepositoryError;
@@ -12,5 +16,10 @@ crud_repository! {
     {
         async fn find_by_name(&self, name: &str) -> Option<Tour>;
         async fn exists_by_name(&self, name: &str) -> bool;
+        async fn find_all(&self, limit: u16, offset: u64) -> Vec<Tour>;
+        async fn find_by_required_specialization(&self, specialization: SpecializationId) -> Option<Tour>;
+        async fn exists_by_required_specialization(&self, specialization: SpecializationId) -> bool;
+        async fn find_all_pending_reminders(&self, deadline: DateTime<Utc>) -> Vec<Tour>;
+        async fn find_current(&self) -> Option<Tour>;
     }
 }
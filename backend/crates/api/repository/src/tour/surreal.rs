@@ -1,4 +1,8 @@
-use entity::tour::{CreateTour, Tour, TourId, TourUpdate};
+use chrono::{DateTime, Utc};
+use entity::{
+    specialization::SpecializationId,
+    tour::{CreateTour, Tour, TourId, TourUpdate},
+};
 use macros::{EntityId, implementation, surql_query};
 use tracing::instrument;
 use utils::adapters::{MobcPool, SurrealPool};
@@ -53,6 +57,19 @@ implementation! {
             self.find_by_name(name).await?.is_some()
         }
 
+        #[instrument(skip_all, name = "TourRepository::find_all")]
+        async fn find_all(&self, limit: u16, offset: u64) -> Vec<Tour> {
+            self.pool
+                .get()
+                .await?
+                .query(surql_query!("table/find_all"))
+                .bind(("table", TourId::TABLE))
+                .bind(("limit", limit))
+                .bind(("offset", offset))
+                .await?
+                .take(0)?
+        }
+
         #[instrument(skip_all, name = "TourRepository::update_by_id")]
         async fn update_by_id(&self, id: TourId, update: TourUpdate) -> Option<Tour> {
             self.pool
@@ -71,5 +88,45 @@ implementation! {
                 .delete(id.record_id())
                 .await?
         }
+
+        #[instrument(skip_all, name = "TourRepository::find_by_required_specialization")]
+        async fn find_by_required_specialization(&self, specialization: SpecializationId) -> Option<Tour> {
+            self.pool
+                .get()
+                .await?
+                .query(surql_query!("table/tour/find_by_required_specialization"))
+                .bind(("table", TourId::TABLE))
+                .bind(("specialization_id", specialization))
+                .await?
+                .take(0)?
+        }
+
+        #[instrument(skip_all, name = "TourRepository::exists_by_required_specialization")]
+        async fn exists_by_required_specialization(&self, specialization: SpecializationId) -> bool {
+            self.find_by_required_specialization(specialization).await?.is_some()
+        }
+
+        #[instrument(skip_all, name = "TourRepository::find_all_pending_reminders")]
+        async fn find_all_pending_reminders(&self, deadline: DateTime<Utc>) -> Vec<Tour> {
+            self.pool
+                .get()
+                .await?
+                .query(surql_query!("table/tour/find_all_pending_reminders"))
+                .bind(("table", TourId::TABLE))
+                .bind(("deadline", deadline))
+                .await?
+                .take(0)?
+        }
+
+        #[instrument(skip_all, name = "TourRepository::find_current")]
+        async fn find_current(&self) -> Option<Tour> {
+            self.pool
+                .get()
+                .await?
+                .query(surql_query!("table/tour/find_current"))
+                .bind(("table", TourId::TABLE))
+                .await?
+                .take(0)?
+        }
     }
 }
@@ -1,6 +1,13 @@
 use std::collections::HashMap;
 
-use entity::user::{self, User};
+use entity::{
+    recommendation::Recommendation,
+    specialization::SpecializationId,
+    team::TeamId,
+    technology::TechnologyId,
+    tour::TourId,
+    user::{self, CreateUser, User},
+};
 use macros::crud_repository;
 
 use crate::common::RepositoryError;
@@ -12,10 +19,15 @@ crud_repository! {
     User
         Err: RepositoryError
     {
+        async fn save_with_event(&self, new: CreateUser) -> User;
         async fn find_by_email(&self, email: &str) -> Option<User>;
         async fn exists_by_email(&self, email: &str) -> bool;
         async fn find_by_username(&self, username: &str) -> Option<User>;
         async fn exists_by_username(&self, username: &str) -> bool;
+        async fn find_by_telegram_chat_id(&self, chat_id: i64) -> Option<User>;
         async fn count_by_role(&self) -> HashMap<String, u32>;
+        /// Participants of other teams of the tour, and the ones who already
+        /// applied to the team, are left out.
+        async fn find_all_recommended_by_team(&self, team: TeamId, tour: TourId, missing_specializations: Vec<SpecializationId>, technologies: Vec<TechnologyId>, limit: u16, offset: u64) -> Vec<Recommendation>;
     }
 }
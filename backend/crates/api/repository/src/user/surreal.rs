@@ -1,6 +1,14 @@
 use std::collections::HashMap;
 
-use entity::user::{CreateUser, User, UserId, UserUpdate};
+use entity::{
+    outbox_event::{CreateOutboxEvent, DomainEvent, OutboxEvent},
+    recommendation::Recommendation,
+    specialization::SpecializationId,
+    team::TeamId,
+    technology::TechnologyId,
+    tour::TourId,
+    user::{CreateUser, User, UserId, UserUpdate},
+};
 use macros::{EntityId, implementation, surql_query};
 use surrealdb::Value;
 use tracing::instrument;
@@ -25,6 +33,27 @@ implementation! {
                 .ok_or(RepositoryError::FailedToSaveObject)?
         }
 
+        #[instrument(skip_all, name = "UserRepository::save_with_event")]
+        async fn save_with_event(&self, new: CreateUser) -> User {
+            let entity: User = new.into();
+            let outbox = OutboxEvent::from(CreateOutboxEvent {
+                event: DomainEvent::UserRegistered {
+                    user: entity.id.clone().into(),
+                },
+            });
+            self.pool
+                .get()
+                .await?
+                .query(surql_query!("table/user/save_with_event"))
+                .bind(("id", entity.id.clone()))
+                .bind(("object", entity))
+                .bind(("outbox_id", outbox.id.clone()))
+                .bind(("outbox", outbox))
+                .await?
+                .take::<Option<User>>(0)?
+                .ok_or(RepositoryError::FailedToSaveObject)?
+        }
+
         #[instrument(skip_all, name = "UserRepository::find_by_id")]
         async fn find_by_id(&self, id: UserId) -> Option<User> {
             self.pool
@@ -73,6 +102,18 @@ implementation! {
             self.find_by_username(username).await?.is_some()
         }
 
+        #[instrument(skip_all, name = "UserRepository::find_by_telegram_chat_id")]
+        async fn find_by_telegram_chat_id(&self, chat_id: i64) -> Option<User> {
+            self.pool
+                .get()
+                .await?
+                .query(surql_query!("table/user/find_by_telegram_chat_id"))
+                .bind(("table", UserId::TABLE))
+                .bind(("chat_id", chat_id))
+                .await?
+                .take(0)?
+        }
+
         #[instrument(skip_all, name = "UserRepository::update_by_id")]
         async fn update_by_id(&self, id: UserId, update: UserUpdate) -> Option<User> {
             self.pool
@@ -104,5 +145,22 @@ implementation! {
                 .take::<Value>(0)?
                 .extract()
         }
+
+        #[instrument(skip_all, name = "UserRepository::find_all_recommended_by_team")]
+        async fn find_all_recommended_by_team(&self, team: TeamId, tour: TourId, missing_specializations: Vec<SpecializationId>, technologies: Vec<TechnologyId>, limit: u16, offset: u64) -> Vec<Recommendation> {
+            self.pool
+                .get()
+                .await?
+                .query(surql_query!("table/user/find_recommended_by_team"))
+                .bind(("table", UserId::TABLE))
+                .bind(("team_id", team))
+                .bind(("tour_id", tour))
+                .bind(("missing_specializations", missing_specializations))
+                .bind(("technologies", technologies))
+                .bind(("limit", limit))
+                .bind(("offset", offset))
+                .await?
+                .take(0)?
+        }
     }
 }
@@ -0,0 +1,210 @@
+use dto::{
+    application::{Application, CreateApplication},
+    member::Member,
+};
+use entity::{applied_to_join::CreateAppliedToJoin, team::Team};
+use macros::implementation;
+use repository::{
+    applied_to_join::AppliedToJoinRepositoryDependency,
+    member_of::MemberOfRepositoryDependency,
+    specialization::SpecializationRepositoryDependency,
+    team::TeamRepositoryDependency, tour::TourRepositoryDependency,
+};
+use tracing::instrument;
+use ulid::Ulid;
+
+use super::{ApplicationService, ApplicationServiceResult};
+use crate::{
+    common::ServiceError,
+    permission::{Actor, Permission, PermissionServiceDependency},
+};
+
+implementation! {
+    ApplicationService {
+        applied_to_join_repository: AppliedToJoinRepositoryDependency,
+        member_of_repository: MemberOfRepositoryDependency,
+        team_repository: TeamRepositoryDependency,
+        tour_repository: TourRepositoryDependency,
+        specialization_repository: SpecializationRepositoryDependency,
+        permission_service: PermissionServiceDependency,
+    } as ApplicationServiceImpl {
+        #[instrument(skip_all, name = "ApplicationService::apply")]
+        async fn apply(
+            &self,
+            user_id: Ulid,
+            team_id: Ulid,
+            new: CreateApplication,
+        ) -> Application {
+            let team = self.get_team(team_id).await?;
+            if !self.specialization_repository
+                .exists_by_id(new.specialization.into())
+                .await?
+            {
+                Err(ServiceError::NotFound(
+                    "Specialization with provided id".into(),
+                ))?
+            }
+            if self.applied_to_join_repository
+                .exists_by_in_and_out(user_id.into(), team.id.clone())
+                .await?
+            {
+                Err(ServiceError::AlreadyExists(
+                    "Application to provided team".into(),
+                ))?
+            }
+            self.check_can_join(user_id, &team).await?;
+
+            let application = self.applied_to_join_repository
+                .save_with_event(
+                    CreateAppliedToJoin {
+                        r#in: user_id.into(),
+                        out: team.id.clone(),
+                        application: new.application,
+                        specialization: new.specialization.into(),
+                    }
+                )
+                .await?;
+
+            application.into()
+        }
+
+        #[instrument(skip_all, name = "ApplicationService::withdraw")]
+        async fn withdraw(
+            &self,
+            user_id: Ulid,
+            team_id: Ulid,
+        ) -> () {
+            self.applied_to_join_repository
+                .delete_by_in_and_out(user_id.into(), team_id.into())
+                .await?
+                .ok_or(ServiceError::NotFound(
+                    "Application to provided team".into(),
+                ))?;
+        }
+
+        #[instrument(skip_all, name = "ApplicationService::find_all_by_user")]
+        async fn find_all_by_user(
+            &self,
+            user_id: Ulid,
+            (limit, offset): (u16, u64),
+        ) -> Vec<Application> {
+            self.applied_to_join_repository
+                .find_all_by_in(user_id.into(), limit, offset)
+                .await?
+                .into_iter()
+                .map(Application::from)
+                .collect()
+        }
+
+        #[instrument(skip_all, name = "ApplicationService::find_all_by_team")]
+        async fn find_all_by_team(
+            &self,
+            team_id: Ulid,
+            actor: Actor,
+            (limit, offset): (u16, u64),
+        ) -> Vec<Application> {
+            let team = self.get_led_team(team_id, actor).await?;
+
+            self.applied_to_join_repository
+                .find_all_by_out(team.id, limit, offset)
+                .await?
+                .into_iter()
+                .map(Application::from)
+                .collect()
+        }
+
+        #[instrument(skip_all, name = "ApplicationService::accept")]
+        async fn accept(
+            &self,
+            team_id: Ulid,
+            user_id: Ulid,
+            actor: Actor,
+        ) -> Member {
+            let team = self.get_led_team(team_id, actor).await?;
+            let application = self.applied_to_join_repository
+                .find_by_in_and_out(user_id.into(), team.id.clone())
+                .await?
+                .ok_or(ServiceError::NotFound(
+                    "Application to provided team".into(),
+                ))?;
+            self.check_can_join(user_id, &team).await?;
+
+            let member = self.member_of_repository
+                .accept_with_event(application)
+                .await?;
+
+            member.into()
+        }
+
+        #[instrument(skip_all, name = "ApplicationService::reject")]
+        async fn reject(
+            &self,
+            team_id: Ulid,
+            user_id: Ulid,
+            actor: Actor,
+        ) -> () {
+            let team = self.get_led_team(team_id, actor).await?;
+
+            self.applied_to_join_repository
+                .delete_by_in_and_out(user_id.into(), team.id)
+                .await?
+                .ok_or(ServiceError::NotFound(
+                    "Application to provided team".into(),
+                ))?;
+        }
+    }
+}
+
+impl ApplicationServiceImpl {
+    #[tracing::instrument(skip_all, level = "trace")]
+    async fn get_team(&self, team_id: Ulid) -> ApplicationServiceResult<Team> {
+        self.team_repository
+            .find_by_id(team_id.into())
+            .await?
+            .ok_or(ServiceError::NotFound("Team with provided id".into()))
+    }
+
+    #[tracing::instrument(skip_all, level = "trace")]
+    async fn get_led_team(
+        &self,
+        team_id: Ulid,
+        actor: Actor,
+    ) -> ApplicationServiceResult<Team> {
+        let team = self.get_team(team_id).await?;
+        self.permission_service
+            .require(actor, Permission::LeadTeam(team_id))
+            .await?;
+        Ok(team)
+    }
+
+    #[tracing::instrument(skip_all, level = "trace")]
+    async fn check_can_join(
+        &self,
+        user_id: Ulid,
+        team: &Team,
+    ) -> ApplicationServiceResult<()> {
+        if self
+            .member_of_repository
+            .exists_by_in_and_tour(user_id.into(), team.tour.clone())
+            .await?
+        {
+            Err(ServiceError::AlreadyExists(
+                "Membership in a team of provided tour".into(),
+            ))?
+        }
+
+        let tour = self
+            .tour_repository
+            .find_by_id(team.tour.clone())
+            .await?
+            .expect("Got unchecked tour ID");
+        let members = self
+            .member_of_repository
+            .count_by_out(team.id.clone())
+            .await?;
+        if members >= u64::from(tour.max_members) {
+            Err(ServiceError::Forbidden("Team is already full".into()))?
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+use dto::{
+    application::{Application, CreateApplication},
+    member::Member,
+};
+use macros::service;
+use ulid::Ulid;
+
+use crate::{common::ServiceError, permission::Actor};
+
+pub mod implementation;
+
+service! {
+    Application
+        Err: ServiceError
+    {
+        async fn apply(
+            &self,
+            user_id: Ulid,
+            team_id: Ulid,
+            new: CreateApplication,
+        ) -> Application;
+
+        async fn withdraw(&self, user_id: Ulid, team_id: Ulid) -> ();
+
+        async fn find_all_by_user(
+            &self,
+            user_id: Ulid,
+            pagination: (u16, u64),
+        ) -> Vec<Application>;
+
+        async fn find_all_by_team(
+            &self,
+            team_id: Ulid,
+            actor: Actor,
+            pagination: (u16, u64),
+        ) -> Vec<Application>;
+
+        async fn accept(
+            &self,
+            team_id: Ulid,
+            user_id: Ulid,
+            actor: Actor,
+        ) -> Member;
+
+        async fn reject(
+            &self,
+            team_id: Ulid,
+            user_id: Ulid,
+            actor: Actor,
+        ) -> ();
+    }
+}
@@ -8,15 +8,24 @@ pub enum ServiceError {
     #[error("Invalid password")]
     InvalidPassword,
 
+    #[error("{0}")]
+    Unauthorized(String),
+
     #[error("{0}")]
     Forbidden(String),
 
+    #[error("Access denied")]
+    AccessDenied,
+
     #[error("{0}")]
     NotFound(String),
 
     #[error("{0}")]
     AlreadyExists(String),
 
+    #[error("{0}")]
+    Conflict(String),
+
     #[error("{0}")]
     PayloadTooLarge(String),
 
@@ -48,6 +57,10 @@ impl From<RepositoryError> for ServiceError {
             RE::S3(..) | RE::S3BrokenImage | RE::S3ByteStream(..) => {
                 Self::Database(err.to_string())
             },
+            #[cfg(feature = "grpc")]
+            RE::Grpc(..) | RE::MailDeliveryFailed(..) => {
+                Self::Internal(err.to_string())
+            },
         }
     }
 }
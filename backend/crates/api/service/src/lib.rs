@@ -1,5 +1,21 @@
+pub mod application;
 pub mod common;
+pub mod mentor;
+pub mod notification;
+pub mod outbox;
+pub mod permission;
 pub mod profile;
 pub mod profile_image;
+pub mod recommendation;
 pub mod review;
+pub mod session;
+pub mod skill;
+pub mod specialization;
+pub mod team;
+pub mod technology;
+pub mod telegram;
+pub mod tour;
+pub mod tour_result;
+pub mod tour_staff;
+pub mod two_factor;
 pub mod user;
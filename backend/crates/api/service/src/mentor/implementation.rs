@@ -0,0 +1,155 @@
+use dto::{mentor::Mentor, team::Team, user::UserRole};
+use entity::mentors::CreateMentors;
+use macros::implementation;
+use repository::{
+    mentors::MentorsRepositoryDependency,
+    mentors_in::MentorsInRepositoryDependency, team::TeamRepositoryDependency,
+    tour::TourRepositoryDependency,
+};
+use tracing::instrument;
+use ulid::Ulid;
+
+use super::{MentorService, MentorServiceResult};
+use crate::{
+    common::ServiceError,
+    permission::{Actor, Permission, PermissionServiceDependency},
+    user::UserServiceDependency,
+};
+
+implementation! {
+    MentorService {
+        mentors_repository: MentorsRepositoryDependency,
+        team_repository: TeamRepositoryDependency,
+        tour_repository: TourRepositoryDependency,
+        user_service: UserServiceDependency,
+        mentors_in_repository: MentorsInRepositoryDependency,
+        permission_service: PermissionServiceDependency,
+    } as MentorServiceImpl {
+        #[instrument(skip_all, name = "MentorService::assign")]
+        async fn assign(
+            &self,
+            team_id: Ulid,
+            user_id: Ulid,
+            actor: Actor,
+        ) -> Mentor {
+            let team = self.team_repository
+                .find_by_id(team_id.into())
+                .await?
+                .ok_or(ServiceError::NotFound("Team with provided id".into()))?;
+            self.permission_service
+                .require(actor, Permission::OrganizeTour(team.tour.clone().into()))
+                .await?;
+            let user = self.user_service
+                .get_by_id(user_id)
+                .await?;
+            if user.role != UserRole::Mentor {
+                Err(ServiceError::BadRequest(
+                    "Only users with the mentor role can mentor teams".into(),
+                ))?
+            }
+            if !self.mentors_in_repository
+                .exists_by_in_and_out(user_id.into(), team.tour.clone())
+                .await?
+            {
+                Err(ServiceError::BadRequest(
+                    "Only mentors assigned to the team's tour can mentor the team".into(),
+                ))?
+            }
+            if self.mentors_repository
+                .exists_by_in_and_out(user_id.into(), team.id.clone())
+                .await?
+            {
+                Err(ServiceError::AlreadyExists(
+                    "Mentor assignment to provided team".into(),
+                ))?
+            }
+
+            let tour = self.tour_repository
+                .find_by_id(team.tour.clone())
+                .await?
+                .expect("Got unchecked tour ID");
+            if let Some(max_mentors) = tour.max_mentors {
+                let mentors = self.mentors_repository
+                    .count_by_out(team.id.clone())
+                    .await?;
+                if mentors >= u64::from(max_mentors) {
+                    Err(ServiceError::Forbidden(
+                        "Team already has the maximum number of mentors".into(),
+                    ))?
+                }
+            }
+
+            self.mentors_repository
+                .save(
+                    CreateMentors {
+                        r#in: user_id.into(),
+                        out: team.id,
+                    }
+                )
+                .await?
+                .into()
+        }
+
+        #[instrument(skip_all, name = "MentorService::unassign")]
+        async fn unassign(
+            &self,
+            team_id: Ulid,
+            user_id: Ulid,
+            actor: Actor,
+        ) -> () {
+            let team = self.team_repository
+                .find_by_id(team_id.into())
+                .await?
+                .ok_or(ServiceError::NotFound("Team with provided id".into()))?;
+            self.permission_service
+                .require(actor, Permission::OrganizeTour(team.tour.into()))
+                .await?;
+            self.mentors_repository
+                .delete_by_in_and_out(user_id.into(), team_id.into())
+                .await?
+                .ok_or(ServiceError::NotFound(
+                    "Mentor assignment to provided team".into(),
+                ))?;
+        }
+
+        #[instrument(skip_all, name = "MentorService::find_all_teams_by_mentor")]
+        async fn find_all_teams_by_mentor(
+            &self,
+            user_id: Ulid,
+            (limit, offset): (u16, u64),
+        ) -> Vec<Team> {
+            let assignments = self.mentors_repository
+                .find_all_by_in(user_id.into(), limit, offset)
+                .await?;
+
+            let mut teams = Vec::with_capacity(assignments.len());
+            for assignment in assignments {
+                if let Some(team) = self.team_repository
+                    .find_by_id(assignment.out)
+                    .await?
+                {
+                    teams.push(team.into());
+                }
+            }
+            teams
+        }
+
+        #[instrument(skip_all, name = "MentorService::find_all_by_team")]
+        async fn find_all_by_team(
+            &self,
+            team_id: Ulid,
+            (limit, offset): (u16, u64),
+        ) -> Vec<Mentor> {
+            if !self.team_repository.exists_by_id(team_id.into()).await? {
+                Err(ServiceError::NotFound("Team with provided id".into()))?
+            }
+
+            self.mentors_repository
+                .find_all_by_out(team_id.into(), limit, offset)
+                .await?
+                .into_iter()
+                .map(Mentor::from)
+                .collect()
+        }
+    }
+}
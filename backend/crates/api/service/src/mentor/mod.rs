@@ -0,0 +1,40 @@
+use dto::{mentor::Mentor, team::Team};
+use macros::service;
+use ulid::Ulid;
+
+use crate::{common::ServiceError, permission::Actor};
+
+pub mod implementation;
+
+service! {
+    Mentor
+        Err: ServiceError
+    {
+        /// Only mentors assigned to the team's tour can mentor the team.
+        async fn assign(
+            &self,
+            team_id: Ulid,
+            user_id: Ulid,
+            actor: Actor,
+        ) -> Mentor;
+
+        async fn unassign(
+            &self,
+            team_id: Ulid,
+            user_id: Ulid,
+            actor: Actor,
+        ) -> ();
+
+        async fn find_all_teams_by_mentor(
+            &self,
+            user_id: Ulid,
+            pagination: (u16, u64),
+        ) -> Vec<Team>;
+
+        async fn find_all_by_team(
+            &self,
+            team_id: Ulid,
+            pagination: (u16, u64),
+        ) -> Vec<Mentor>;
+    }
+}
@@ -0,0 +1,185 @@
+use std::collections::{BTreeSet, HashMap};
+
+use chrono::Utc;
+use dto::notification::{NotificationSettings, UpsertNotificationSettings};
+use entity::{
+    notification::{Notification, NotificationCategory},
+    notification_settings::{
+        NotificationSettings as NotificationSettingsEntity,
+        NotificationSettingsId,
+    },
+    tour::{Tour, TourUpdate},
+};
+use macros::implementation;
+use repository::{
+    member_of::MemberOfRepositoryDependency,
+    notification::NotificationRepositoryDependency,
+    notification_settings::NotificationSettingsRepositoryDependency,
+    team::TeamRepositoryDependency, tour::TourRepositoryDependency,
+    user::UserRepositoryDependency,
+};
+use tracing::instrument;
+use ulid::Ulid;
+
+use super::{
+    NotificationService, NotificationServiceResult, TOUR_REMINDER_LEAD_TIME,
+    TOUR_REMINDER_TEAMS_PAGE_SIZE,
+};
+use crate::common::ServiceError;
+
+implementation! {
+    NotificationService {
+        notification_repository: NotificationRepositoryDependency,
+        notification_settings_repository: NotificationSettingsRepositoryDependency,
+        user_repository: UserRepositoryDependency,
+        tour_repository: TourRepositoryDependency,
+        team_repository: TeamRepositoryDependency,
+        member_of_repository: MemberOfRepositoryDependency,
+    } as NotificationServiceImpl {
+        #[instrument(skip_all, name = "NotificationService::get_settings")]
+        async fn get_settings(
+            &self,
+            user_id: Ulid,
+        ) -> NotificationSettings {
+            self.find_settings(user_id)
+                .await?
+                .into()
+        }
+
+        #[instrument(skip_all, name = "NotificationService::update_settings")]
+        async fn update_settings(
+            &self,
+            user_id: Ulid,
+            settings: UpsertNotificationSettings,
+        ) -> NotificationSettings {
+            self.notification_settings_repository
+                .upsert_by_id(user_id.into(), settings.into())
+                .await?
+                .into()
+        }
+
+        #[instrument(skip_all, name = "NotificationService::notify")]
+        async fn notify(
+            &self,
+            user_id: Ulid,
+            category: NotificationCategory,
+            variables: HashMap<String, String>,
+        ) -> () {
+            let user = self.user_repository
+                .find_by_id(user_id.into())
+                .await?
+                .ok_or(
+                    ServiceError::NotFound("User with provided id".into())
+                )?;
+            let Some(chat_id) = user.telegram_chat_id else {
+                return Ok(());
+            };
+            if !self.find_settings(user_id).await?.is_enabled(category) {
+                return Ok(());
+            }
+
+            self.notification_repository
+                .send(
+                    Notification {
+                        chat_id,
+                        category,
+                        variables,
+                    }
+                )
+                .await?;
+        }
+
+        #[instrument(skip_all, name = "NotificationService::send_tour_reminders")]
+        async fn send_tour_reminders(&self) -> () {
+            let deadline = Utc::now()
+                .checked_add_signed(TOUR_REMINDER_LEAD_TIME)
+                .expect("Got out of range tour reminder deadline");
+
+            for tour in self.tour_repository
+                .find_all_pending_reminders(deadline)
+                .await?
+            {
+                let variables = HashMap::from([
+                    ("tour".to_string(), tour.name.clone()),
+                    (
+                        "starts_at".to_string(),
+                        tour.starts_at.format("%d.%m.%Y %H:%M UTC").to_string(),
+                    ),
+                ]);
+                for user_id in self.find_participants(&tour).await? {
+                    if let Err(err) = self
+                        .notify(user_id, NotificationCategory::TourStarting, variables.clone())
+                        .await
+                    {
+                        tracing::warn!("Failed to remind user {user_id} about tour {}: {err}", tour.id);
+                    }
+                }
+
+                self.tour_repository
+                    .update_by_id(
+                        tour.id,
+                        TourUpdate {
+                            reminder_sent: Some(true),
+                            ..Default::default()
+                        }
+                    )
+                    .await?;
+            }
+        }
+    }
+}
+
+impl NotificationServiceImpl {
+    #[tracing::instrument(skip_all, level = "trace")]
+    async fn find_settings(
+        &self,
+        user_id: Ulid,
+    ) -> NotificationServiceResult<NotificationSettingsEntity> {
+        let id: NotificationSettingsId = user_id.into();
+        Ok(self
+            .notification_settings_repository
+            .find_by_id(id.clone())
+            .await?
+            .unwrap_or_else(|| {
+                NotificationSettingsEntity::enabled_by_default(id)
+            }))
+    }
+
+    /// Collects the leads and accepted members of every team in the tour.
+    #[tracing::instrument(skip_all, level = "trace")]
+    async fn find_participants(
+        &self,
+        tour: &Tour,
+    ) -> NotificationServiceResult<BTreeSet<Ulid>> {
+        let mut participants = BTreeSet::new();
+        let mut offset = 0;
+        loop {
+            let teams = self
+                .team_repository
+                .find_all_by_tour(
+                    tour.id.clone(),
+                    TOUR_REMINDER_TEAMS_PAGE_SIZE,
+                    offset,
+                )
+                .await?;
+            for team in &teams {
+                participants.insert(Ulid::from(team.lead.clone()));
+                participants.extend(
+                    self.member_of_repository
+                        .find_all_by_out(team.id.clone(), tour.max_members, 0)
+                        .await?
+                        .into_iter()
+                        .filter(|member| member.accepted)
+                        .map(|member| Ulid::from(member.r#in)),
+                );
+            }
+
+            if u64::try_from(teams.len())
+                .is_ok_and(|len| len < TOUR_REMINDER_TEAMS_PAGE_SIZE)
+            {
+                return Ok(participants);
+            }
+            offset = offset.saturating_add(TOUR_REMINDER_TEAMS_PAGE_SIZE);
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use std::collections::HashMap;
+
+use chrono::TimeDelta;
+use dto::notification::{NotificationSettings, UpsertNotificationSettings};
+use entity::notification::NotificationCategory;
+use macros::service;
+use ulid::Ulid;
+
+use crate::common::ServiceError;
+
+pub mod implementation;
+
+const TOUR_REMINDER_LEAD_TIME: TimeDelta = TimeDelta::hours(24);
+const TOUR_REMINDER_TEAMS_PAGE_SIZE: u64 = 100;
+
+service! {
+    Notification
+        Err: ServiceError
+    {
+        async fn get_settings(&self, user_id: Ulid) -> NotificationSettings;
+
+        async fn update_settings(
+            &self,
+            user_id: Ulid,
+            settings: UpsertNotificationSettings,
+        ) -> NotificationSettings;
+
+        async fn notify(
+            &self,
+            user_id: Ulid,
+            category: NotificationCategory,
+            variables: HashMap<String, String>,
+        ) -> ();
+
+        async fn send_tour_reminders(&self) -> ();
+    }
+}
@@ -0,0 +1,204 @@
+use std::collections::HashMap;
+
+use chrono::Utc;
+use entity::{
+    notification::NotificationCategory,
+    outbox_event::{DomainEvent, OutboxEvent, OutboxEventUpdate, OutboxStatus},
+    team::Team,
+};
+use macros::implementation;
+use metrics::{describe_gauge, gauge};
+use repository::{
+    outbox_event::OutboxEventRepositoryDependency,
+    team::TeamRepositoryDependency,
+};
+use tracing::instrument;
+use ulid::Ulid;
+use utils::LGTM;
+
+use super::{
+    OUTBOX_BACKLOG_METRIC_NAME, OUTBOX_BATCH_SIZE,
+    OUTBOX_DEAD_LETTERS_METRIC_NAME, OUTBOX_MAX_ATTEMPTS, OutboxService,
+    OutboxServiceResult, is_permanent, retry_delay,
+};
+use crate::{
+    common::ServiceError, notification::NotificationServiceDependency,
+    user::UserServiceDependency,
+};
+
+implementation! {
+    OutboxService {
+        outbox_event_repository: OutboxEventRepositoryDependency,
+        team_repository: TeamRepositoryDependency,
+        user_service: UserServiceDependency,
+        notification_service: NotificationServiceDependency,
+    } as OutboxServiceImpl {
+        #[instrument(skip_all, name = "OutboxService::dispatch")]
+        async fn dispatch(&self) -> u32 {
+            let mut delivered: u32 = 0;
+            for outbox_event in self.outbox_event_repository
+                .find_all_due(Utc::now(), OUTBOX_BATCH_SIZE)
+                .await?
+            {
+                match self.deliver(&outbox_event.event).await {
+                    Ok(()) => {
+                        self.outbox_event_repository
+                            .delete_by_id(outbox_event.id)
+                            .await?;
+                        delivered = delivered.saturating_add(1);
+                    },
+                    Err(err) => self.reschedule(outbox_event, err).await?,
+                }
+            }
+            delivered
+        }
+
+        #[instrument(skip_all, name = "OutboxService::init_metrics")]
+        async fn init_metrics(&self) {
+            describe_gauge!(OUTBOX_BACKLOG_METRIC_NAME, "The number of outbox events waiting for delivery");
+            describe_gauge!(OUTBOX_DEAD_LETTERS_METRIC_NAME, "The number of outbox events that failed to be delivered");
+
+            let outbox_event_repository = self.outbox_event_repository.clone();
+            tokio::spawn(async move {
+                loop {
+                    if let Ok(events_by_status) = outbox_event_repository
+                        .count_by_status()
+                        .await
+                    {
+                        let count = |status: OutboxStatus| {
+                            events_by_status
+                                .get(&status.to_string())
+                                .copied()
+                                .unwrap_or_default()
+                        };
+                        gauge!(OUTBOX_BACKLOG_METRIC_NAME).set(count(OutboxStatus::Pending));
+                        gauge!(OUTBOX_DEAD_LETTERS_METRIC_NAME).set(count(OutboxStatus::DeadLettered));
+                    }
+
+                    tokio::time::sleep(LGTM::METRIC_SCRAPE_INTERVAL).await;
+                }
+            });
+        }
+    }
+}
+
+impl OutboxServiceImpl {
+    /// Delivery must be idempotent, as an event is delivered again whenever
+    /// removing it from the outbox fails.
+    #[tracing::instrument(skip_all, level = "trace")]
+    async fn deliver(&self, event: &DomainEvent) -> OutboxServiceResult<()> {
+        match event {
+            DomainEvent::UserRegistered {
+                user,
+            } => {
+                match self.user_service.send_email_verification(*user).await {
+                    // Verified before the event got delivered
+                    Err(ServiceError::Conflict(..)) => Ok(()),
+                    res => res,
+                }
+            },
+            DomainEvent::ApplicationSubmitted {
+                team, ..
+            } => {
+                let team = self.get_team(*team).await?;
+                self.notify_about_team(
+                    team.lead.clone().into(),
+                    NotificationCategory::NewApplicant,
+                    &team,
+                )
+                .await
+            },
+            DomainEvent::MemberAccepted {
+                user,
+                team,
+            } => {
+                let team = self.get_team(*team).await?;
+                self.notify_about_team(
+                    *user,
+                    NotificationCategory::ApplicationAccepted,
+                    &team,
+                )
+                .await
+            },
+            DomainEvent::ReviewUpserted {
+                reviewee,
+                score,
+                ..
+            } => {
+                let variables =
+                    HashMap::from([("score".to_string(), score.to_string())]);
+                self.notification_service
+                    .notify(
+                        *reviewee,
+                        NotificationCategory::ReviewReceived,
+                        variables,
+                    )
+                    .await
+            },
+        }
+    }
+
+    /// Schedules another attempt with a backoff, or dead-letters the event
+    /// once retrying is pointless.
+    #[tracing::instrument(skip_all, level = "trace")]
+    async fn reschedule(
+        &self,
+        outbox_event: OutboxEvent,
+        err: ServiceError,
+    ) -> OutboxServiceResult<()> {
+        let attempts = outbox_event.attempts.saturating_add(1);
+        let status = if is_permanent(&err) || attempts >= OUTBOX_MAX_ATTEMPTS {
+            tracing::error!(
+                "Dead-lettering {} event {} after {attempts} attempts: {err}",
+                outbox_event.event,
+                outbox_event.id
+            );
+            OutboxStatus::DeadLettered
+        } else {
+            tracing::warn!(
+                "Failed to deliver {} event {}: {err}",
+                outbox_event.event,
+                outbox_event.id
+            );
+            OutboxStatus::Pending
+        };
+        let next_attempt_at = Utc::now()
+            .checked_add_signed(retry_delay(attempts))
+            .expect("Got out of range outbox retry time");
+
+        self.outbox_event_repository
+            .update_by_id(
+                outbox_event.id,
+                OutboxEventUpdate {
+                    status: Some(status),
+                    attempts: Some(attempts),
+                    next_attempt_at: Some(next_attempt_at),
+                    last_error: Some(err.to_string()),
+                },
+            )
+            .await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all, level = "trace")]
+    async fn get_team(&self, team_id: Ulid) -> OutboxServiceResult<Team> {
+        self.team_repository
+            .find_by_id(team_id.into())
+            .await?
+            .ok_or(ServiceError::NotFound("Team with provided id".into()))
+    }
+
+    #[tracing::instrument(skip_all, level = "trace")]
+    async fn notify_about_team(
+        &self,
+        user_id: Ulid,
+        category: NotificationCategory,
+        team: &Team,
+    ) -> OutboxServiceResult<()> {
+        let variables =
+            HashMap::from([("team".to_string(), team.name.clone())]);
+        self.notification_service
+            .notify(user_id, category, variables)
+            .await
+    }
+}
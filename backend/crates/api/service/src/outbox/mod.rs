@@ -0,0 +1,72 @@
+use chrono::TimeDelta;
+use macros::{metric_name, service};
+
+use crate::common::ServiceError;
+
+pub mod implementation;
+
+const OUTBOX_BATCH_SIZE: u16 = 100;
+const OUTBOX_MAX_ATTEMPTS: u32 = 10;
+const OUTBOX_RETRY_BASE_DELAY: TimeDelta = TimeDelta::seconds(5);
+const OUTBOX_RETRY_MAX_DELAY: TimeDelta = TimeDelta::hours(1);
+
+service! {
+    Outbox
+        Err: ServiceError
+    {
+        /// Delivers the due events and returns how many of them were
+        /// delivered.
+        async fn dispatch(&self) -> u32;
+
+        async fn init_metrics(&self);
+    }
+}
+
+metric_name!(OUTBOX_BACKLOG, "outbox_backlog");
+metric_name!(OUTBOX_DEAD_LETTERS, "outbox_dead_letters");
+
+/// Errors that won't go away on retry, such as a deleted user or team.
+#[tracing::instrument(skip_all, level = "trace")]
+fn is_permanent(err: &ServiceError) -> bool {
+    matches!(
+        err,
+        ServiceError::NotFound(..) | ServiceError::BadRequest(..)
+    )
+}
+
+/// Exponential backoff after the given number of failed attempts.
+#[tracing::instrument(skip_all, level = "trace")]
+fn retry_delay(attempts: u32) -> TimeDelta {
+    2i32.checked_pow(attempts.saturating_sub(1))
+        .and_then(|factor| OUTBOX_RETRY_BASE_DELAY.checked_mul(factor))
+        .map_or(OUTBOX_RETRY_MAX_DELAY, |delay| {
+            delay.min(OUTBOX_RETRY_MAX_DELAY)
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::TimeDelta;
+    use rstest::rstest;
+
+    use super::{ServiceError, is_permanent, retry_delay};
+
+    #[rstest]
+    #[case::first(1, TimeDelta::seconds(5))]
+    #[case::second(2, TimeDelta::seconds(10))]
+    #[case::tenth(10, TimeDelta::seconds(2560))]
+    #[case::capped(11, TimeDelta::hours(1))]
+    #[case::overflow(u32::MAX, TimeDelta::hours(1))]
+    fn backoff(#[case] attempts: u32, #[case] expected: TimeDelta) {
+        assert_eq!(retry_delay(attempts), expected);
+    }
+
+    #[rstest]
+    #[case::not_found(ServiceError::NotFound("User".into()), true)]
+    #[case::bad_request(ServiceError::BadRequest("Event".into()), true)]
+    #[case::database(ServiceError::Database("Timeout".into()), false)]
+    #[case::internal(ServiceError::Internal("Unavailable".into()), false)]
+    fn permanent(#[case] err: ServiceError, #[case] expected: bool) {
+        assert_eq!(is_permanent(&err), expected);
+    }
+}
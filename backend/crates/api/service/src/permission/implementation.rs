@@ -0,0 +1,93 @@
+use dto::user::UserRole;
+use entity::user::UserId;
+use macros::implementation;
+use repository::{
+    mentors::MentorsRepositoryDependency,
+    organizes::OrganizesRepositoryDependency, team::TeamRepositoryDependency,
+};
+use tracing::instrument;
+
+use super::{
+    Actor, Permission, PermissionService, PermissionServiceResult,
+    policy::{self, Relations},
+};
+use crate::common::ServiceError;
+
+implementation! {
+    PermissionService {
+        team_repository: TeamRepositoryDependency,
+        mentors_repository: MentorsRepositoryDependency,
+        organizes_repository: OrganizesRepositoryDependency,
+    } as PermissionServiceImpl {
+        #[instrument(skip_all, name = "PermissionService::require")]
+        async fn require(&self, actor: Actor, permission: Permission) -> () {
+            if !self.is_granted(actor, permission).await? {
+                Err(ServiceError::AccessDenied)?
+            }
+        }
+
+        #[instrument(skip_all, name = "PermissionService::is_granted")]
+        async fn is_granted(
+            &self,
+            actor: Actor,
+            permission: Permission,
+        ) -> bool {
+            let relations = self.resolve_relations(&actor, permission).await?;
+            policy::is_granted(&actor, permission, relations)
+        }
+    }
+}
+
+impl PermissionServiceImpl {
+    #[tracing::instrument(skip_all, level = "trace")]
+    async fn resolve_relations(
+        &self,
+        actor: &Actor,
+        permission: Permission,
+    ) -> PermissionServiceResult<Relations> {
+        let relations = match permission {
+            Permission::LeadTeam(team_id) => Relations {
+                leads_team: self
+                    .team_repository
+                    .find_by_id(team_id.into())
+                    .await?
+                    .is_some_and(|team| team.lead == UserId::from(actor.id)),
+                ..Default::default()
+            },
+            Permission::MentorTeam(team_id) => Relations {
+                mentors_team: self
+                    .mentors_repository
+                    .exists_by_in_and_out(actor.id.into(), team_id.into())
+                    .await?,
+                ..Default::default()
+            },
+            Permission::OrganizeTour(tour_id)
+                if actor.role == UserRole::Organizer =>
+            {
+                Relations {
+                    organizes_tour: self
+                        .organizes_repository
+                        .exists_by_in_and_out(actor.id.into(), tour_id.into())
+                        .await?,
+                    ..Default::default()
+                }
+            },
+            Permission::ManageUser(user_id)
+                if actor.role == UserRole::Organizer && actor.id != user_id =>
+            {
+                Relations {
+                    organizes_participant: self
+                        .organizes_repository
+                        .exists_by_in_and_participant(
+                            actor.id.into(),
+                            user_id.into(),
+                        )
+                        .await?,
+                    ..Default::default()
+                }
+            },
+            _ => Relations::default(),
+        };
+        Ok(relations)
+    }
+}
@@ -0,0 +1,58 @@
+use dto::user::{User, UserRole};
+use macros::service;
+use ulid::Ulid;
+
+use crate::common::ServiceError;
+
+pub mod implementation;
+pub mod policy;
+
+/// User a permission is checked for.
+#[derive(Clone, Copy, Debug)]
+pub struct Actor {
+    pub id: Ulid,
+    pub role: UserRole,
+}
+impl From<&User> for Actor {
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn from(user: &User) -> Self {
+        Self {
+            id: user.id,
+            role: user.role,
+        }
+    }
+}
+
+/// Something only some users are allowed to do. Resource-scoped variants
+/// carry the id of the resource they are about.
+#[derive(Clone, Copy, Debug)]
+pub enum Permission {
+    /// Acting with one of the roles.
+    Role(&'static [UserRole]),
+    /// Acting on a resource owned by the user.
+    Own(Ulid),
+    /// Managing the account of the user.
+    ManageUser(Ulid),
+    /// Managing the team as its lead.
+    LeadTeam(Ulid),
+    /// Working with the team as its mentor.
+    MentorTeam(Ulid),
+    /// Organizing the tour.
+    OrganizeTour(Ulid),
+}
+
+service! {
+    Permission
+        Err: ServiceError
+    {
+        /// Fails with [`ServiceError::AccessDenied`] unless `actor` is
+        /// granted `permission`.
+        async fn require(&self, actor: Actor, permission: Permission) -> ();
+
+        async fn is_granted(
+            &self,
+            actor: Actor,
+            permission: Permission,
+        ) -> bool;
+    }
+}
@@ -0,0 +1,269 @@
+use dto::user::UserRole;
+
+use super::{Actor, Permission};
+use crate::user::DEFAULT_ADMIN_ID;
+
+/// Relations between the actor and the resource a permission is about.
+/// Looking them up takes the repositories, so they are resolved before the
+/// policy is evaluated.
+#[derive(Clone, Copy, Default, Debug)]
+pub struct Relations {
+    pub leads_team: bool,
+    pub mentors_team: bool,
+    pub organizes_tour: bool,
+    /// The actor organizes a tour the user the permission is about takes
+    /// part in.
+    pub organizes_participant: bool,
+}
+
+/// Decides whether `actor` is granted `permission`.
+///
+/// Superadmins may manage any account except the seeded admin, which only
+/// manages itself, and organize any tour. Organizers are scoped to the
+/// tours they are assigned to: they organize only those and manage only
+/// the users taking part in them. Team permissions come from the actor's
+/// relations to the team alone, so no role implies them.
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn is_granted(
+    actor: &Actor,
+    permission: Permission,
+    relations: Relations,
+) -> bool {
+    let is_superadmin = actor.role == UserRole::Superadmin;
+    let is_organizer = actor.role == UserRole::Organizer;
+    match permission {
+        Permission::Role(roles) => roles.contains(&actor.role),
+        Permission::Own(owner) => actor.id == owner,
+        Permission::ManageUser(user) => {
+            actor.id == user
+                || (user.to_string() != DEFAULT_ADMIN_ID
+                    && (is_superadmin
+                        || (is_organizer && relations.organizes_participant)))
+        },
+        Permission::LeadTeam(..) => relations.leads_team,
+        Permission::MentorTeam(..) => relations.mentors_team,
+        Permission::OrganizeTour(..) => {
+            is_superadmin || (is_organizer && relations.organizes_tour)
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use dto::user::UserRole;
+    use rstest::rstest;
+    use ulid::Ulid;
+
+    use super::{Relations, is_granted};
+    use crate::{
+        permission::{Actor, Permission},
+        user::DEFAULT_ADMIN_ID,
+    };
+
+    const ACTOR: Ulid = Ulid::from_parts(1, 1);
+    const OTHER: Ulid = Ulid::from_parts(2, 2);
+    const NONE: Relations = Relations {
+        leads_team: false,
+        mentors_team: false,
+        organizes_tour: false,
+        organizes_participant: false,
+    };
+    const LEAD: Relations = Relations {
+        leads_team: true,
+        ..NONE
+    };
+    const MENTOR: Relations = Relations {
+        mentors_team: true,
+        ..NONE
+    };
+    const ORGANIZER: Relations = Relations {
+        organizes_tour: true,
+        organizes_participant: true,
+        ..NONE
+    };
+
+    fn admin() -> Ulid {
+        Ulid::from_string(DEFAULT_ADMIN_ID).expect("Got invalid admin ID")
+    }
+
+    #[rstest]
+    #[case::role_allowed(
+        UserRole::Organizer,
+        Permission::Role(&[UserRole::Organizer]),
+        NONE,
+        true
+    )]
+    #[case::role_one_of(
+        UserRole::Mentor,
+        Permission::Role(&[UserRole::Organizer, UserRole::Mentor]),
+        NONE,
+        true
+    )]
+    #[case::role_denied(
+        UserRole::Participant,
+        Permission::Role(&[UserRole::Organizer]),
+        NONE,
+        false
+    )]
+    #[case::own_resource(
+        UserRole::Participant,
+        Permission::Own(ACTOR),
+        NONE,
+        true
+    )]
+    #[case::others_resource(
+        UserRole::Participant,
+        Permission::Own(OTHER),
+        NONE,
+        false
+    )]
+    #[case::others_resource_as_organizer(
+        UserRole::Organizer,
+        Permission::Own(OTHER),
+        NONE,
+        false
+    )]
+    #[case::manage_self(
+        UserRole::Participant,
+        Permission::ManageUser(ACTOR),
+        NONE,
+        true
+    )]
+    #[case::manage_other(
+        UserRole::Participant,
+        Permission::ManageUser(OTHER),
+        NONE,
+        false
+    )]
+    #[case::manage_other_as_mentor(
+        UserRole::Mentor,
+        Permission::ManageUser(OTHER),
+        NONE,
+        false
+    )]
+    #[case::manage_participant_as_organizer(
+        UserRole::Organizer,
+        Permission::ManageUser(OTHER),
+        ORGANIZER,
+        true
+    )]
+    #[case::manage_outsider_as_organizer(
+        UserRole::Organizer,
+        Permission::ManageUser(OTHER),
+        NONE,
+        false
+    )]
+    #[case::manage_participant_as_former_organizer(
+        UserRole::Participant,
+        Permission::ManageUser(OTHER),
+        ORGANIZER,
+        false
+    )]
+    #[case::manage_other_as_superadmin(
+        UserRole::Superadmin,
+        Permission::ManageUser(OTHER),
+        NONE,
+        true
+    )]
+    #[case::manage_admin_as_superadmin(
+        UserRole::Superadmin,
+        Permission::ManageUser(admin()),
+        NONE,
+        false
+    )]
+    #[case::lead_team(
+        UserRole::Participant,
+        Permission::LeadTeam(OTHER),
+        LEAD,
+        true
+    )]
+    #[case::recommend_for_unled_team(
+        UserRole::Participant,
+        Permission::LeadTeam(OTHER),
+        NONE,
+        false
+    )]
+    #[case::lead_team_as_mentor(
+        UserRole::Mentor,
+        Permission::LeadTeam(OTHER),
+        MENTOR,
+        false
+    )]
+    #[case::lead_team_as_organizer(
+        UserRole::Organizer,
+        Permission::LeadTeam(OTHER),
+        NONE,
+        false
+    )]
+    #[case::mentor_team(
+        UserRole::Mentor,
+        Permission::MentorTeam(OTHER),
+        MENTOR,
+        true
+    )]
+    #[case::mentor_team_as_lead(
+        UserRole::Participant,
+        Permission::MentorTeam(OTHER),
+        LEAD,
+        false
+    )]
+    #[case::mentor_team_unassigned(
+        UserRole::Mentor,
+        Permission::MentorTeam(OTHER),
+        NONE,
+        false
+    )]
+    #[case::organize_tour(
+        UserRole::Organizer,
+        Permission::OrganizeTour(OTHER),
+        ORGANIZER,
+        true
+    )]
+    #[case::organize_other_tour(
+        UserRole::Organizer,
+        Permission::OrganizeTour(OTHER),
+        NONE,
+        false
+    )]
+    #[case::organize_tour_as_superadmin(
+        UserRole::Superadmin,
+        Permission::OrganizeTour(OTHER),
+        NONE,
+        true
+    )]
+    #[case::organize_tour_as_mentor(
+        UserRole::Mentor,
+        Permission::OrganizeTour(OTHER),
+        MENTOR,
+        false
+    )]
+    #[case::organize_tour_as_participant(
+        UserRole::Participant,
+        Permission::OrganizeTour(OTHER),
+        LEAD,
+        false
+    )]
+    fn policy(
+        #[case] role: UserRole,
+        #[case] permission: Permission,
+        #[case] relations: Relations,
+        #[case] expected: bool,
+    ) {
+        let actor = Actor {
+            id: ACTOR,
+            role,
+        };
+
+        assert_eq!(is_granted(&actor, permission, relations), expected);
+    }
+
+    #[rstest]
+    fn admin_manages_itself() {
+        let actor = Actor {
+            id: admin(),
+            role: UserRole::Superadmin,
+        };
+
+        assert!(is_granted(&actor, Permission::ManageUser(admin()), NONE));
+    }
+}
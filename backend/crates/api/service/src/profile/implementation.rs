@@ -15,13 +15,19 @@ use utils::LGTM;
 use super::{
     PROFILES_BY_CITY_COUNT_METRIC_NAME, ProfileService, ProfileServiceResult,
 };
-use crate::{common::ServiceError, user::UserServiceDependency};
+use crate::{
+    common::ServiceError,
+    permission::{Actor, Permission, PermissionServiceDependency},
+    telegram::is_telegram_verified,
+    user::UserServiceDependency,
+};
 
 implementation! {
     ProfileService {
         user_repository: UserRepositoryDependency,
         profile_repository: ProfileRepositoryDependency,
         user_service: UserServiceDependency,
+        permission_service: PermissionServiceDependency,
     } as ProfileServiceImpl {
         #[instrument(skip_all, name = "ProfileService::update_by_id")]
         async fn upsert_by_id(
@@ -29,9 +35,12 @@ implementation! {
             id: Ulid,
             object: UpsertProfile,
             has_avatar: Option<bool>,
-            check_user: bool,
+            actor: Actor,
         ) -> Profile {
-            if check_user {
+            self.permission_service
+                .require(actor, Permission::ManageUser(id))
+                .await?;
+            if actor.id != id {
                 self.user_service
                     .get_by_id(id)
                     .await?;
@@ -41,6 +50,12 @@ implementation! {
             let profile = self.profile_repository
                 .find_by_id(profile_id.clone())
                 .await?;
+            let telegram_verified = self.user_repository
+                .find_by_id(id.into())
+                .await?
+                .is_some_and(|user| {
+                    is_telegram_verified(&user, &object.telegram)
+                });
             let profile = self.profile_repository
                 .upsert_by_id(
                     profile_id.clone(),
@@ -54,6 +69,7 @@ implementation! {
                         has_avatar: has_avatar.unwrap_or(
                             profile.map(|p| p.has_avatar).unwrap_or(false)
                         ),
+                        telegram_verified,
                     }
                 )
                 .await?;
@@ -73,9 +89,9 @@ implementation! {
         async fn find_by_id(
             &self,
             id: Ulid,
-            check_user: bool,
+            actor: Actor,
         ) -> Option<Profile> {
-            if check_user {
+            if actor.id != id {
                 self.user_service
                     .get_by_id(id)
                     .await?;
@@ -91,10 +107,10 @@ implementation! {
         async fn get_by_id(
             &self,
             id: Ulid,
-            check_user: bool,
+            actor: Actor,
         ) -> Profile {
             self
-                .find_by_id(id, check_user)
+                .find_by_id(id, actor)
                 .await?
                 .ok_or(
                     ServiceError::NotFound("Profile with provided id".into())
@@ -105,9 +121,12 @@ implementation! {
         async fn delete_by_id(
             &self,
             id: Ulid,
-            check_user: bool,
+            actor: Actor,
         ) -> () {
-            self.get_by_id(id, check_user).await?;
+            self.permission_service
+                .require(actor, Permission::ManageUser(id))
+                .await?;
+            self.get_by_id(id, actor).await?;
             self.profile_repository
                 .delete_by_id(id.into())
                 .await?;
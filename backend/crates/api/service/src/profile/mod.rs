@@ -2,7 +2,7 @@ use dto::profile::{Profile, UpsertProfile};
 use macros::{metric_name, service};
 use ulid::Ulid;
 
-use crate::common::ServiceError;
+use crate::{common::ServiceError, permission::Actor};
 
 pub mod implementation;
 
@@ -15,14 +15,14 @@ service! {
             id: Ulid,
             object: UpsertProfile,
             has_avatar: Option<bool>,
-            check_user: bool
+            actor: Actor
         ) -> Profile;
 
-        async fn find_by_id(&self, id: Ulid, check_user: bool) -> Option<Profile>;
+        async fn find_by_id(&self, id: Ulid, actor: Actor) -> Option<Profile>;
 
-        async fn get_by_id(&self, id: Ulid, check_user: bool) -> Profile;
+        async fn get_by_id(&self, id: Ulid, actor: Actor) -> Profile;
 
-        async fn delete_by_id(&self, id: Ulid, check_user: bool) -> ();
+        async fn delete_by_id(&self, id: Ulid, actor: Actor) -> ();
 
         async fn init_metrics(&self);
     }
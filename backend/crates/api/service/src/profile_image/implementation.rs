@@ -7,7 +7,11 @@ use tracing::instrument;
 use ulid::Ulid;
 
 use super::{ProfileImageService, ProfileImageServiceResult};
-use crate::{common::ServiceError, profile::ProfileServiceDependency};
+use crate::{
+    common::ServiceError,
+    permission::{Actor, Permission, PermissionServiceDependency},
+    profile::ProfileServiceDependency,
+};
 
 const MAX_IMAGE_SIZE: usize = 5_976_883;
 
@@ -15,16 +19,20 @@ implementation! {
     ProfileImageService {
         image_repository: ImageRepositoryDependency,
         profile_service: ProfileServiceDependency,
+        permission_service: PermissionServiceDependency,
     } as ProfileImageServiceImpl {
         #[instrument(skip_all, name = "ProfileImageService::upsert_by_id")]
         async fn upsert_by_id(
             &self,
             id: Ulid,
             file: TempFile,
-            check_user: bool,
+            actor: Actor,
         ) -> () {
+            self.permission_service
+                .require(actor, Permission::ManageUser(id))
+                .await?;
             let profile = self.profile_service
-                .get_by_id(id, check_user)
+                .get_by_id(id, actor)
                 .await?;
 
             if file.size > MAX_IMAGE_SIZE {
@@ -66,7 +74,7 @@ implementation! {
                     profile.id,
                     profile.into(),
                     Some(true),
-                    check_user
+                    actor
                 )
                 .await?;
         }
@@ -75,10 +83,10 @@ implementation! {
         async fn find_by_id(
             &self,
             id: Ulid,
-            check_user: bool,
+            actor: Actor,
         ) -> Option<Image> {
             let profile = self.profile_service
-                .get_by_id(id, check_user)
+                .get_by_id(id, actor)
                 .await?;
 
             if !profile.has_avatar {
@@ -95,10 +103,10 @@ implementation! {
         async fn get_by_id(
             &self,
             id: Ulid,
-            check_user: bool,
+            actor: Actor,
         ) -> Image {
             self
-                .find_by_id(id, check_user)
+                .find_by_id(id, actor)
                 .await?
                 .ok_or(
                     ServiceError::NotFound("Profile image with provided id".into())
@@ -109,11 +117,14 @@ implementation! {
         async fn delete_by_id(
             &self,
             id: Ulid,
-            check_user: bool,
+            actor: Actor,
         ) -> () {
+            self.permission_service
+                .require(actor, Permission::ManageUser(id))
+                .await?;
             let profile = self.profile_service
-                .get_by_id(id, check_user).await?;
-            self.get_by_id(id, false).await?;
+                .get_by_id(id, actor).await?;
+            self.get_by_id(id, actor).await?;
 
             self.image_repository
                 .delete_by_id(id.into())
@@ -124,7 +135,7 @@ implementation! {
                     profile.id,
                     profile.into(),
                     Some(false),
-                    false
+                    actor
                 )
                 .await?;
         }
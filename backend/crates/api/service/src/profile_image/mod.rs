@@ -3,7 +3,7 @@ use dto::image::Image;
 use macros::service;
 use ulid::Ulid;
 
-use crate::common::ServiceError;
+use crate::{common::ServiceError, permission::Actor};
 
 pub mod implementation;
 
@@ -11,12 +11,12 @@ service! {
     ProfileImage
         Err: ServiceError
     {
-        async fn upsert_by_id(&self, id: Ulid, file: TempFile, check_user: bool) -> ();
+        async fn upsert_by_id(&self, id: Ulid, file: TempFile, actor: Actor) -> ();
 
-        async fn find_by_id(&self, id: Ulid, check_user: bool) -> Option<Image>;
+        async fn find_by_id(&self, id: Ulid, actor: Actor) -> Option<Image>;
 
-        async fn get_by_id(&self, id: Ulid, check_user: bool) -> Image;
+        async fn get_by_id(&self, id: Ulid, actor: Actor) -> Image;
 
-        async fn delete_by_id(&self, id: Ulid, check_user: bool) -> ();
+        async fn delete_by_id(&self, id: Ulid, actor: Actor) -> ();
     }
 }
@@ -0,0 +1,91 @@
+use dto::recommendation::Recommendation;
+use macros::implementation;
+use repository::{
+    member_of::MemberOfRepositoryDependency, team::TeamRepositoryDependency,
+    tour::TourRepositoryDependency, user::UserRepositoryDependency,
+    uses::UsesRepositoryDependency,
+};
+use tracing::instrument;
+use ulid::Ulid;
+
+use super::{RecommendationService, RecommendationServiceResult};
+use crate::{
+    common::ServiceError,
+    permission::{Actor, Permission, PermissionServiceDependency},
+    team::composition,
+};
+
+implementation! {
+    RecommendationService {
+        team_repository: TeamRepositoryDependency,
+        tour_repository: TourRepositoryDependency,
+        member_of_repository: MemberOfRepositoryDependency,
+        uses_repository: UsesRepositoryDependency,
+        user_repository: UserRepositoryDependency,
+        permission_service: PermissionServiceDependency,
+    } as RecommendationServiceImpl {
+        #[instrument(skip_all, name = "RecommendationService::find_for_team")]
+        async fn find_for_team(
+            &self,
+            team_id: Ulid,
+            actor: Actor,
+            (limit, offset): (u16, u64),
+        ) -> Vec<Recommendation> {
+            let team = self.team_repository
+                .find_by_id(team_id.into())
+                .await?
+                .ok_or(ServiceError::NotFound("Team with provided id".into()))?;
+            self.permission_service
+                .require(actor, Permission::LeadTeam(team_id))
+                .await?;
+            let tour = self.tour_repository
+                .find_by_id(team.tour.clone())
+                .await?
+                .expect("Got unchecked tour ID");
+
+            let member_specializations: Vec<Ulid> = self.member_of_repository
+                .find_all_by_out(team.id.clone(), u16::MAX, 0)
+                .await?
+                .into_iter()
+                .filter(|member| member.accepted)
+                .map(|member| member.specialization.into())
+                .collect();
+            let required_specializations: Vec<Ulid> = tour
+                .required_specializations
+                .iter()
+                .cloned()
+                .map(Ulid::from)
+                .collect();
+            let missing_specializations = composition::evaluate(
+                team_id,
+                &required_specializations,
+                &member_specializations,
+                tour.max_members,
+            )
+            .missing
+            .into_iter()
+            .map(|missing| missing.specialization.into())
+            .collect();
+            let team_technologies = self.uses_repository
+                .find_all_by_in(team.id.clone(), u16::MAX, 0)
+                .await?
+                .into_iter()
+                .map(|uses| uses.out)
+                .collect();
+
+            self.user_repository
+                .find_all_recommended_by_team(
+                    team.id,
+                    team.tour,
+                    missing_specializations,
+                    team_technologies,
+                    limit,
+                    offset,
+                )
+                .await?
+                .into_iter()
+                .map(Recommendation::from)
+                .collect()
+        }
+    }
+}
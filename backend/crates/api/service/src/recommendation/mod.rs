@@ -0,0 +1,20 @@
+use dto::recommendation::Recommendation;
+use macros::service;
+use ulid::Ulid;
+
+use crate::{common::ServiceError, permission::Actor};
+
+pub mod implementation;
+
+service! {
+    Recommendation
+        Err: ServiceError
+    {
+        async fn find_for_team(
+            &self,
+            team_id: Ulid,
+            actor: Actor,
+            pagination: (u16, u64),
+        ) -> Vec<Recommendation>;
+    }
+}
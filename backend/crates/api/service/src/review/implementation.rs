@@ -11,12 +11,17 @@ use super::{
     REVIEWS_BY_SCORE_COUNT_METRIC_NAME, REVIEWS_BY_SCORE_SUM_METRIC_NAME,
     ReviewService, ReviewServiceResult,
 };
-use crate::{common::ServiceError, user::UserServiceDependency};
+use crate::{
+    common::ServiceError,
+    permission::{Actor, Permission, PermissionServiceDependency},
+    user::UserServiceDependency,
+};
 
 implementation! {
     ReviewService {
         reviewed_repository: ReviewedRepositoryDependency,
         user_service: UserServiceDependency,
+        permission_service: PermissionServiceDependency,
     } as ReviewServiceImpl {
         #[instrument(skip_all, name = "ReviewService::upsert_by_id")]
         async fn upsert_by_id(
@@ -33,8 +38,8 @@ implementation! {
                 .get_by_id(reviewee_id)
                 .await?;
 
-            self.reviewed_repository
-                .upsert_by_in_and_out(
+            let review = self.reviewed_repository
+                .upsert_by_in_and_out_with_event(
                     reviewer_id.into(),
                     reviewee_id.into(),
                     UpsertReviewed {
@@ -44,8 +49,9 @@ implementation! {
                         review: object.review,
                     }
                 )
-                .await?
-                .into()
+                .await?;
+
+            review.into()
         }
 
         #[instrument(skip_all, name = "ReviewService::find_by_id")]
@@ -141,14 +147,16 @@ implementation! {
             &self,
             reviewer_id: Ulid,
             reviewee_id: Ulid,
-            check_reviewer: bool,
-            check_reviewee: bool,
+            actor: Actor,
         ) -> () {
+            self.permission_service
+                .require(actor, Permission::ManageUser(reviewer_id))
+                .await?;
             self.get_by_id(
                 reviewer_id,
                 reviewee_id,
-                check_reviewer,
-                check_reviewee,
+                actor.id != reviewer_id,
+                actor.id != reviewee_id,
             ).await?;
 
             self.reviewed_repository
@@ -2,7 +2,7 @@ use dto::review::{Review, UpsertReview};
 use macros::{metric_name, service};
 use ulid::Ulid;
 
-use crate::common::ServiceError;
+use crate::{common::ServiceError, permission::Actor};
 
 pub mod implementation;
 
@@ -51,8 +51,7 @@ service! {
             &self,
             reviewer_id: Ulid,
             reviewee_id: Ulid,
-            check_reviewer: bool,
-            check_reviewee: bool,
+            actor: Actor,
         ) -> ();
 
         async fn init_metrics(&self);
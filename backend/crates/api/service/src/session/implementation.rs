@@ -0,0 +1,179 @@
+use chrono::{DateTime, Utc};
+use dto::{
+    auth::RefreshRequest,
+    session::{Session, SessionTokens},
+    user::{User, UserRole},
+};
+use entity::session::CreateSession;
+use macros::implementation;
+use repository::{
+    session::SessionRepositoryDependency, user::UserRepositoryDependency,
+};
+use tracing::instrument;
+use ulid::Ulid;
+use utils::auth::{JwtKeys, PasswordHasher, jwt, token};
+
+use super::{SESSION_LIFETIME, SessionService, SessionServiceResult};
+use crate::common::ServiceError;
+
+implementation! {
+    SessionService {
+        session_repository: SessionRepositoryDependency,
+        user_repository: UserRepositoryDependency,
+        jwt_keys: JwtKeys,
+        password_hasher: PasswordHasher<'static>
+    } as SessionServiceImpl {
+        #[instrument(skip_all, name = "SessionService::start")]
+        async fn start(
+            &self,
+            user_id: Ulid,
+            role: UserRole,
+        ) -> SessionTokens {
+            self.issue(user_id, role, Ulid::new(), Utc::now()).await?
+        }
+
+        #[instrument(skip_all, name = "SessionService::refresh")]
+        async fn refresh(
+            &self,
+            RefreshRequest {
+                refresh_token
+            }: RefreshRequest,
+        ) -> (User, SessionTokens) {
+            let invalid_token = || ServiceError::Unauthorized(
+                "Invalid or expired refresh token".into()
+            );
+
+            let (id, secret) = refresh_token
+                .split_once('.')
+                .ok_or_else(invalid_token)?;
+            let id = Ulid::from_string(id)
+                .map_err(|_| invalid_token())?;
+
+            let session = self.session_repository
+                .find_by_id(id.into())
+                .await?
+                .ok_or_else(invalid_token)?;
+            self.password_hasher
+                .verify(secret, &session.token_hash)
+                .map_err(|_| invalid_token())?;
+            if session.expires_at < Utc::now() {
+                Err(invalid_token())?
+            }
+            if self.session_repository
+                .rotate_by_id(session.id)
+                .await?
+                .is_none()
+            {
+                tracing::warn!("Refresh token of session {} was reused, revoking the session", session.family);
+                self.session_repository
+                    .delete_all_by_family(session.family)
+                    .await?;
+                Err(invalid_token())?
+            }
+
+            let user = self.user_repository
+                .find_by_id(session.user.clone())
+                .await?
+                .ok_or_else(invalid_token)?;
+
+            let tokens = self
+                .issue(
+                    user.id.clone().into(),
+                    user.role.into(),
+                    session.family,
+                    session.started_at,
+                )
+                .await?;
+
+            (user.into(), tokens)
+        }
+
+        #[instrument(skip_all, name = "SessionService::is_active")]
+        async fn is_active(
+            &self,
+            session_id: Ulid,
+        ) -> bool {
+            self.session_repository
+                .exists_active_by_family(session_id)
+                .await?
+        }
+
+        #[instrument(skip_all, name = "SessionService::find_all_by_user")]
+        async fn find_all_by_user(
+            &self,
+            user_id: Ulid,
+            current: Ulid,
+        ) -> Vec<Session> {
+            self.session_repository
+                .find_all_active_by_user(user_id.into())
+                .await?
+                .into_iter()
+                .map(|session| Session::from((session, current)))
+                .collect()
+        }
+
+        #[instrument(skip_all, name = "SessionService::revoke")]
+        async fn revoke(
+            &self,
+            session_id: Ulid,
+        ) -> () {
+            self.session_repository
+                .delete_all_by_family(session_id)
+                .await?;
+        }
+
+        #[instrument(skip_all, name = "SessionService::revoke_all")]
+        async fn revoke_all(
+            &self,
+            user_id: Ulid,
+        ) -> () {
+            self.session_repository
+                .delete_all_by_user(user_id.into())
+                .await?;
+        }
+
+        #[instrument(skip_all, name = "SessionService::purge")]
+        async fn purge(&self) -> () {
+            self.session_repository
+                .delete_all_stale()
+                .await?;
+        }
+    }
+}
+
+impl SessionServiceImpl {
+    /// Saves a new refresh token of the session and issues an access token
+    /// bound to it.
+    #[tracing::instrument(skip_all, level = "debug")]
+    async fn issue(
+        &self,
+        user_id: Ulid,
+        role: UserRole,
+        family: Ulid,
+        started_at: DateTime<Utc>,
+    ) -> SessionServiceResult<SessionTokens> {
+        let secret = token::generate();
+        let session = self
+            .session_repository
+            .save(CreateSession {
+                user: user_id.into(),
+                family,
+                token_hash: self.password_hasher.hash(&secret)?,
+                started_at,
+                expires_at: Utc::now()
+                    .checked_add_signed(SESSION_LIFETIME)
+                    .expect("Got out of range session expiration"),
+            })
+            .await?;
+
+        Ok(SessionTokens {
+            token: jwt::new_for_session(
+                &role.to_string(),
+                user_id,
+                family,
+                &self.jwt_keys,
+            ),
+            refresh_token: format!("{}.{secret}", session.id),
+        })
+    }
+}
@@ -0,0 +1,43 @@
+use chrono::TimeDelta;
+use dto::{
+    auth::RefreshRequest,
+    session::{Session, SessionTokens},
+    user::{User, UserRole},
+};
+use macros::service;
+use ulid::Ulid;
+
+use crate::common::ServiceError;
+
+pub mod implementation;
+
+const SESSION_LIFETIME: TimeDelta = TimeDelta::days(30);
+
+service! {
+    Session
+        Err: ServiceError
+    {
+        async fn start(&self, user_id: Ulid, role: UserRole) -> SessionTokens;
+
+        /// Exchanges the refresh token for a new pair of tokens. A token that
+        /// was already exchanged has leaked, so presenting it again revokes
+        /// the whole session.
+        async fn refresh(&self, req: RefreshRequest) -> (User, SessionTokens);
+
+        async fn is_active(&self, session_id: Ulid) -> bool;
+
+        async fn find_all_by_user(
+            &self,
+            user_id: Ulid,
+            current: Ulid,
+        ) -> Vec<Session>;
+
+        async fn revoke(&self, session_id: Ulid) -> ();
+
+        async fn revoke_all(&self, user_id: Ulid) -> ();
+
+        /// Deletes the sessions that have fully expired. Exchanged refresh
+        /// tokens are kept until then so that their reuse is still detected.
+        async fn purge(&self) -> ();
+    }
+}
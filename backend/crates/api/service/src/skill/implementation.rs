@@ -0,0 +1,155 @@
+use dto::skill::{
+    SpecializationSkill, TechnologySkill, UpsertSpecializationSkill,
+    UpsertTechnologySkill,
+};
+use entity::{has_experience_as::UpsertHasExperienceAs, knows::UpsertKnows};
+use macros::implementation;
+use repository::{
+    has_experience_as::HasExperienceAsRepositoryDependency,
+    knows::KnowsRepositoryDependency,
+    specialization::SpecializationRepositoryDependency,
+    technology::TechnologyRepositoryDependency,
+};
+use tracing::instrument;
+use ulid::Ulid;
+
+use super::{SkillService, SkillServiceResult};
+use crate::{common::ServiceError, user::UserServiceDependency};
+
+implementation! {
+    SkillService {
+        knows_repository: KnowsRepositoryDependency,
+        has_experience_as_repository: HasExperienceAsRepositoryDependency,
+        technology_repository: TechnologyRepositoryDependency,
+        specialization_repository: SpecializationRepositoryDependency,
+        user_service: UserServiceDependency,
+    } as SkillServiceImpl {
+        #[instrument(skip_all, name = "SkillService::upsert_technology")]
+        async fn upsert_technology(
+            &self,
+            user_id: Ulid,
+            technology_id: Ulid,
+            object: UpsertTechnologySkill,
+        ) -> TechnologySkill {
+            if !self.technology_repository
+                .exists_by_id(technology_id.into())
+                .await?
+            {
+                Err(ServiceError::NotFound("Technology with provided id".into()))?
+            }
+
+            self.knows_repository
+                .upsert_by_in_and_out(
+                    user_id.into(),
+                    technology_id.into(),
+                    UpsertKnows {
+                        r#in: user_id.into(),
+                        out: technology_id.into(),
+                        level: object.level,
+                    }
+                )
+                .await?
+                .into()
+        }
+
+        #[instrument(skip_all, name = "SkillService::find_all_technologies_by_user")]
+        async fn find_all_technologies_by_user(
+            &self,
+            user_id: Ulid,
+            (limit, offset): (u16, u64),
+            check_user: bool,
+        ) -> Vec<TechnologySkill> {
+            if check_user {
+                self.user_service
+                    .get_by_id(user_id)
+                    .await?;
+            }
+
+            self.knows_repository
+                .find_all_by_in(user_id.into(), limit, offset)
+                .await?
+                .into_iter()
+                .map(TechnologySkill::from)
+                .collect()
+        }
+
+        #[instrument(skip_all, name = "SkillService::delete_technology")]
+        async fn delete_technology(
+            &self,
+            user_id: Ulid,
+            technology_id: Ulid,
+        ) -> () {
+            self.knows_repository
+                .delete_by_in_and_out(user_id.into(), technology_id.into())
+                .await?
+                .ok_or(ServiceError::NotFound(
+                    "Technology skill with provided id".into(),
+                ))?;
+        }
+
+        #[instrument(skip_all, name = "SkillService::upsert_specialization")]
+        async fn upsert_specialization(
+            &self,
+            user_id: Ulid,
+            specialization_id: Ulid,
+            object: UpsertSpecializationSkill,
+        ) -> SpecializationSkill {
+            if !self.specialization_repository
+                .exists_by_id(specialization_id.into())
+                .await?
+            {
+                Err(ServiceError::NotFound(
+                    "Specialization with provided id".into(),
+                ))?
+            }
+
+            self.has_experience_as_repository
+                .upsert_by_in_and_out(
+                    user_id.into(),
+                    specialization_id.into(),
+                    UpsertHasExperienceAs {
+                        r#in: user_id.into(),
+                        out: specialization_id.into(),
+                        level: object.level,
+                    }
+                )
+                .await?
+                .into()
+        }
+
+        #[instrument(skip_all, name = "SkillService::find_all_specializations_by_user")]
+        async fn find_all_specializations_by_user(
+            &self,
+            user_id: Ulid,
+            (limit, offset): (u16, u64),
+            check_user: bool,
+        ) -> Vec<SpecializationSkill> {
+            if check_user {
+                self.user_service
+                    .get_by_id(user_id)
+                    .await?;
+            }
+
+            self.has_experience_as_repository
+                .find_all_by_in(user_id.into(), limit, offset)
+                .await?
+                .into_iter()
+                .map(SpecializationSkill::from)
+                .collect()
+        }
+
+        #[instrument(skip_all, name = "SkillService::delete_specialization")]
+        async fn delete_specialization(
+            &self,
+            user_id: Ulid,
+            specialization_id: Ulid,
+        ) -> () {
+            self.has_experience_as_repository
+                .delete_by_in_and_out(user_id.into(), specialization_id.into())
+                .await?
+                .ok_or(ServiceError::NotFound(
+                    "Specialization skill with provided id".into(),
+                ))?;
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use dto::skill::{
+    SpecializationSkill, TechnologySkill, UpsertSpecializationSkill,
+    UpsertTechnologySkill,
+};
+use macros::service;
+use ulid::Ulid;
+
+use crate::common::ServiceError;
+
+pub mod implementation;
+
+service! {
+    Skill
+        Err: ServiceError
+    {
+        async fn upsert_technology(
+            &self,
+            user_id: Ulid,
+            technology_id: Ulid,
+            object: UpsertTechnologySkill,
+        ) -> TechnologySkill;
+
+        async fn find_all_technologies_by_user(
+            &self,
+            user_id: Ulid,
+            pagination: (u16, u64),
+            check_user: bool,
+        ) -> Vec<TechnologySkill>;
+
+        async fn delete_technology(
+            &self,
+            user_id: Ulid,
+            technology_id: Ulid,
+        ) -> ();
+
+        async fn upsert_specialization(
+            &self,
+            user_id: Ulid,
+            specialization_id: Ulid,
+            object: UpsertSpecializationSkill,
+        ) -> SpecializationSkill;
+
+        async fn find_all_specializations_by_user(
+            &self,
+            user_id: Ulid,
+            pagination: (u16, u64),
+            check_user: bool,
+        ) -> Vec<SpecializationSkill>;
+
+        async fn delete_specialization(
+            &self,
+            user_id: Ulid,
+            specialization_id: Ulid,
+        ) -> ();
+    }
+}
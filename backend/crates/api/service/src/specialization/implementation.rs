@@ -0,0 +1,171 @@
+use dto::specialization::{
+    CreateSpecialization, Specialization, SpecializationUpdate,
+};
+use entity::specialization::{
+    CreateSpecialization as CreateSpecializationEntity, SpecializationId,
+    SpecializationUpdate as SpecializationEntityUpdate,
+};
+use macros::implementation;
+use repository::{
+    applied_to_join::AppliedToJoinRepositoryDependency,
+    has_experience_as::HasExperienceAsRepositoryDependency,
+    member_of::MemberOfRepositoryDependency,
+    participated_in::ParticipatedInRepositoryDependency,
+    specialization::SpecializationRepositoryDependency,
+    tour::TourRepositoryDependency,
+};
+use tracing::instrument;
+use ulid::Ulid;
+
+use super::{SpecializationService, SpecializationServiceResult};
+use crate::common::ServiceError;
+
+implementation! {
+    SpecializationService {
+        specialization_repository: SpecializationRepositoryDependency,
+        tour_repository: TourRepositoryDependency,
+        member_of_repository: MemberOfRepositoryDependency,
+        applied_to_join_repository: AppliedToJoinRepositoryDependency,
+        has_experience_as_repository: HasExperienceAsRepositoryDependency,
+        participated_in_repository: ParticipatedInRepositoryDependency,
+    } as SpecializationServiceImpl {
+        #[instrument(skip_all, name = "SpecializationService::create")]
+        async fn create(
+            &self,
+            new: CreateSpecialization,
+        ) -> Specialization {
+            if self.specialization_repository.exists_by_name(&new.name).await? {
+                Err(ServiceError::AlreadyExists("Specialization with provided name".into()))?
+            }
+
+            self.specialization_repository
+                .save(
+                    CreateSpecializationEntity {
+                        name: new.name,
+                    }
+                )
+                .await?
+                .into()
+        }
+
+        #[instrument(skip_all, name = "SpecializationService::find_by_id")]
+        async fn find_by_id(
+            &self,
+            id: Ulid,
+        ) -> Option<Specialization> {
+            self.specialization_repository
+                .find_by_id(id.into())
+                .await?
+                .map(Specialization::from)
+        }
+
+        #[instrument(skip_all, name = "SpecializationService::get_by_id")]
+        async fn get_by_id(
+            &self,
+            id: Ulid,
+        ) -> Specialization {
+            self
+                .find_by_id(id)
+                .await?
+                .ok_or(
+                    ServiceError::NotFound("Specialization with provided id".into())
+                )?
+        }
+
+        #[instrument(skip_all, name = "SpecializationService::find_all")]
+        async fn find_all(
+            &self,
+            (limit, offset): (u16, u64),
+        ) -> Vec<Specialization> {
+            self.specialization_repository
+                .find_all(limit, offset)
+                .await?
+                .into_iter()
+                .map(Specialization::from)
+                .collect()
+        }
+
+        #[instrument(skip_all, name = "SpecializationService::update_by_id")]
+        async fn update_by_id(
+            &self,
+            id: Ulid,
+            update: SpecializationUpdate,
+        ) -> Specialization {
+            self.get_by_id(id).await?;
+
+            let name_taken = match update.name.as_deref() {
+                Some(name) => self.specialization_repository
+                    .find_by_name(name)
+                    .await?
+                    .is_some_and(|existing| existing.id != SpecializationId::from(id)),
+                None => false,
+            };
+            if name_taken {
+                Err(ServiceError::AlreadyExists("Specialization with provided name".into()))?
+            }
+
+            self.specialization_repository
+                .update_by_id(
+                    id.into(),
+                    SpecializationEntityUpdate {
+                        name: update.name,
+                    }
+                )
+                .await?
+                .expect("Got unchecked specialization ID")
+                .into()
+        }
+
+        #[instrument(skip_all, name = "SpecializationService::delete_by_id")]
+        async fn delete_by_id(
+            &self,
+            id: Ulid,
+        ) -> () {
+            self.get_by_id(id).await?;
+            if self.tour_repository
+                .exists_by_required_specialization(id.into())
+                .await?
+            {
+                Err(ServiceError::Conflict(
+                    "Specialization is required by a tour".into(),
+                ))?
+            }
+            if self.member_of_repository
+                .exists_by_specialization(id.into())
+                .await?
+            {
+                Err(ServiceError::Conflict(
+                    "Specialization is held by a team member".into(),
+                ))?
+            }
+            if self.applied_to_join_repository
+                .exists_by_specialization(id.into())
+                .await?
+            {
+                Err(ServiceError::Conflict(
+                    "Specialization is chosen in a team application".into(),
+                ))?
+            }
+            if self.has_experience_as_repository
+                .exists_by_out(id.into())
+                .await?
+            {
+                Err(ServiceError::Conflict(
+                    "Specialization is listed in a user's experience".into(),
+                ))?
+            }
+            if self.participated_in_repository
+                .exists_by_specialization(id.into())
+                .await?
+            {
+                Err(ServiceError::Conflict(
+                    "Specialization is referenced by tour results".into(),
+                ))?
+            }
+
+            self.specialization_repository
+                .delete_by_id(id.into())
+                .await?;
+        }
+    }
+}
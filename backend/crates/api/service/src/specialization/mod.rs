@@ -0,0 +1,27 @@
+use dto::specialization::{
+    CreateSpecialization, Specialization, SpecializationUpdate,
+};
+use macros::service;
+use ulid::Ulid;
+
+use crate::common::ServiceError;
+
+pub mod implementation;
+
+service! {
+    Specialization
+        Err: ServiceError
+    {
+        async fn create(&self, new: CreateSpecialization) -> Specialization;
+
+        async fn find_by_id(&self, id: Ulid) -> Option<Specialization>;
+
+        async fn get_by_id(&self, id: Ulid) -> Specialization;
+
+        async fn find_all(&self, pagination: (u16, u64)) -> Vec<Specialization>;
+
+        async fn update_by_id(&self, id: Ulid, update: SpecializationUpdate) -> Specialization;
+
+        async fn delete_by_id(&self, id: Ulid) -> ();
+    }
+}
@@ -0,0 +1,144 @@
+use std::collections::BTreeMap;
+
+use dto::team::{SpecializationCount, TeamComposition};
+use ulid::Ulid;
+
+/// Checks the members' specializations against the ones the tour requires.
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn evaluate(
+    team_id: Ulid,
+    required_specializations: &[Ulid],
+    member_specializations: &[Ulid],
+    max_members: u16,
+) -> TeamComposition {
+    let required = count(required_specializations);
+    let present = count(member_specializations);
+
+    let missing: Vec<_> = required
+        .iter()
+        .filter_map(|(&specialization, &needed)| {
+            let held = present.get(&specialization).copied().unwrap_or(0);
+            (held < needed).then(|| SpecializationCount {
+                specialization,
+                count: needed.saturating_sub(held),
+            })
+        })
+        .collect();
+    let duplicated: Vec<_> = present
+        .iter()
+        .filter_map(|(&specialization, &held)| {
+            let needed = required.get(&specialization).copied().unwrap_or(1);
+            (held > needed).then(|| SpecializationCount {
+                specialization,
+                count: held.saturating_sub(needed),
+            })
+        })
+        .collect();
+
+    let members =
+        u16::try_from(member_specializations.len()).unwrap_or(u16::MAX);
+    let missing_total = missing
+        .iter()
+        .fold(0u16, |total, missing| total.saturating_add(missing.count));
+    let over_capacity = members.saturating_add(missing_total) > max_members;
+
+    TeamComposition {
+        team_id,
+        members,
+        max_members,
+        ready: missing.is_empty() && !over_capacity,
+        missing,
+        duplicated,
+        over_capacity,
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+fn count(specializations: &[Ulid]) -> BTreeMap<Ulid, u16> {
+    specializations.iter().fold(
+        BTreeMap::new(),
+        |mut counts, &specialization| {
+            let count = counts.entry(specialization).or_insert(0u16);
+            *count = count.saturating_add(1);
+            counts
+        },
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use rstest::rstest;
+    use ulid::Ulid;
+
+    use super::evaluate;
+
+    const TEAM: Ulid = Ulid(0);
+    const BACKEND: Ulid = Ulid(1);
+    const FRONTEND: Ulid = Ulid(2);
+    const DESIGN: Ulid = Ulid(3);
+
+    #[rstest]
+    fn ready() {
+        let composition = evaluate(
+            TEAM,
+            &[BACKEND, FRONTEND],
+            &[BACKEND, FRONTEND, DESIGN],
+            5,
+        );
+
+        assert!(composition.ready);
+        assert!(composition.missing.is_empty());
+        assert!(composition.duplicated.is_empty());
+        assert!(!composition.over_capacity);
+        assert_eq!(composition.members, 3);
+    }
+
+    #[rstest]
+    #[case::required_held_twice(
+        &[BACKEND, BACKEND, FRONTEND],
+        &[],
+        &[(BACKEND, 1)]
+    )]
+    #[case::other_held_twice(
+        &[BACKEND, DESIGN, DESIGN],
+        &[(FRONTEND, 1)],
+        &[(DESIGN, 1)]
+    )]
+    fn missing_and_duplicated(
+        #[case] members: &[Ulid],
+        #[case] expected_missing: &[(Ulid, u16)],
+        #[case] expected_duplicated: &[(Ulid, u16)],
+    ) {
+        let composition = evaluate(TEAM, &[BACKEND, FRONTEND], members, 5);
+
+        let missing: Vec<_> = composition
+            .missing
+            .iter()
+            .map(|missing| (missing.specialization, missing.count))
+            .collect();
+        let duplicated: Vec<_> = composition
+            .duplicated
+            .iter()
+            .map(|duplicated| (duplicated.specialization, duplicated.count))
+            .collect();
+        assert_eq!(missing, expected_missing);
+        assert_eq!(duplicated, expected_duplicated);
+        assert_eq!(composition.ready, expected_missing.is_empty());
+        assert!(!composition.over_capacity);
+    }
+
+    #[rstest]
+    #[case::full_without_required(&[DESIGN], 1, true)]
+    #[case::room_for_required(&[DESIGN], 2, false)]
+    #[case::exceeds_max_members(&[BACKEND, FRONTEND, DESIGN], 2, true)]
+    fn over_capacity(
+        #[case] members: &[Ulid],
+        #[case] max_members: u16,
+        #[case] expected: bool,
+    ) {
+        let composition = evaluate(TEAM, &[BACKEND], members, max_members);
+
+        assert_eq!(composition.over_capacity, expected);
+        assert!(!composition.ready);
+    }
+}
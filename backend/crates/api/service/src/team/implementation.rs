@@ -0,0 +1,408 @@
+use std::collections::HashMap;
+
+use dto::{
+    member::Member,
+    team::{CreateTeam, Team, TeamComposition, TeamUpdate},
+    technology::Technology,
+};
+use entity::{
+    team::{
+        CreateTeam as CreateTeamEntity, Team as TeamEntity,
+        TeamUpdate as TeamEntityUpdate,
+    },
+    tour::Tour as TourEntity,
+    uses::CreateUses,
+};
+use macros::implementation;
+use repository::{
+    member_of::MemberOfRepositoryDependency,
+    specialization::SpecializationRepositoryDependency,
+    team::TeamRepositoryDependency, technology::TechnologyRepositoryDependency,
+    tour::TourRepositoryDependency, uses::UsesRepositoryDependency,
+};
+use tracing::instrument;
+use ulid::Ulid;
+
+use super::{TeamService, TeamServiceResult, composition};
+use crate::{
+    common::ServiceError,
+    permission::{Actor, Permission, PermissionServiceDependency},
+};
+
+implementation! {
+    TeamService {
+        team_repository: TeamRepositoryDependency,
+        tour_repository: TourRepositoryDependency,
+        member_of_repository: MemberOfRepositoryDependency,
+        specialization_repository: SpecializationRepositoryDependency,
+        uses_repository: UsesRepositoryDependency,
+        technology_repository: TechnologyRepositoryDependency,
+        permission_service: PermissionServiceDependency,
+    } as TeamServiceImpl {
+        #[instrument(skip_all, name = "TeamService::create")]
+        async fn create(
+            &self,
+            lead_id: Ulid,
+            new: CreateTeam,
+        ) -> Team {
+            if !self.tour_repository.exists_by_id(new.tour.into()).await? {
+                Err(ServiceError::NotFound("Tour with provided id".into()))?
+            }
+            if self.team_repository
+                .exists_by_tour_and_name(new.tour.into(), &new.name)
+                .await?
+            {
+                Err(ServiceError::AlreadyExists("Team with provided name".into()))?
+            }
+            if self.team_repository
+                .exists_by_tour_and_lead(new.tour.into(), lead_id.into())
+                .await?
+            {
+                Err(ServiceError::AlreadyExists(
+                    "Team led by you in provided tour".into(),
+                ))?
+            }
+            if self.member_of_repository
+                .exists_by_in_and_tour(lead_id.into(), new.tour.into())
+                .await?
+            {
+                Err(ServiceError::AlreadyExists(
+                    "Membership in a team of provided tour".into(),
+                ))?
+            }
+            if !self.specialization_repository
+                .exists_by_id(new.specialization.into())
+                .await?
+            {
+                Err(ServiceError::NotFound(
+                    "Specialization with provided id".into(),
+                ))?
+            }
+
+            self.team_repository
+                .save_with_lead(
+                    CreateTeamEntity {
+                        name: new.name,
+                        lead: lead_id.into(),
+                        tour: new.tour.into(),
+                    },
+                    new.specialization.into(),
+                )
+                .await?
+                .into()
+        }
+
+        #[instrument(skip_all, name = "TeamService::find_by_id")]
+        async fn find_by_id(
+            &self,
+            id: Ulid,
+        ) -> Option<Team> {
+            self.team_repository
+                .find_by_id(id.into())
+                .await?
+                .map(Team::from)
+        }
+
+        #[instrument(skip_all, name = "TeamService::get_by_id")]
+        async fn get_by_id(
+            &self,
+            id: Ulid,
+        ) -> Team {
+            self
+                .find_by_id(id)
+                .await?
+                .ok_or(
+                    ServiceError::NotFound("Team with provided id".into())
+                )?
+        }
+
+        #[instrument(skip_all, name = "TeamService::get_by_tour_and_lead")]
+        async fn get_by_tour_and_lead(
+            &self,
+            tour_id: Ulid,
+            lead_id: Ulid,
+        ) -> Team {
+            self.team_repository
+                .find_by_tour_and_lead(tour_id.into(), lead_id.into())
+                .await?
+                .ok_or(
+                    ServiceError::NotFound("Team led by provided user in provided tour".into())
+                )?
+                .into()
+        }
+
+        #[instrument(skip_all, name = "TeamService::find_all_by_tour")]
+        async fn find_all_by_tour(
+            &self,
+            tour_id: Ulid,
+            (limit, offset): (u16, u64),
+        ) -> Vec<Team> {
+            if !self.tour_repository.exists_by_id(tour_id.into()).await? {
+                Err(ServiceError::NotFound("Tour with provided id".into()))?
+            }
+
+            self.team_repository
+                .find_all_by_tour(tour_id.into(), limit.into(), offset)
+                .await?
+                .into_iter()
+                .map(Team::from)
+                .collect()
+        }
+
+        #[instrument(skip_all, name = "TeamService::find_members")]
+        async fn find_members(
+            &self,
+            id: Ulid,
+            (limit, offset): (u16, u64),
+        ) -> Vec<Member> {
+            self.get_by_id(id).await?;
+
+            self.member_of_repository
+                .find_all_by_out(id.into(), limit, offset)
+                .await?
+                .into_iter()
+                .map(Member::from)
+                .collect()
+        }
+
+        #[instrument(skip_all, name = "TeamService::get_composition")]
+        async fn get_composition(
+            &self,
+            id: Ulid,
+        ) -> TeamComposition {
+            let team = self.team_repository
+                .find_by_id(id.into())
+                .await?
+                .ok_or(ServiceError::NotFound("Team with provided id".into()))?;
+            let tour = self.tour_repository
+                .find_by_id(team.tour.clone())
+                .await?
+                .expect("Got unchecked tour ID");
+
+            self.compose(vec![team], &tour)
+                .await?
+                .pop()
+                .expect("Got no composition of the team")
+        }
+
+        #[instrument(skip_all, name = "TeamService::find_compositions_by_tour")]
+        async fn find_compositions_by_tour(
+            &self,
+            tour_id: Ulid,
+            (limit, offset): (u16, u64),
+        ) -> Vec<TeamComposition> {
+            let tour = self.tour_repository
+                .find_by_id(tour_id.into())
+                .await?
+                .ok_or(ServiceError::NotFound("Tour with provided id".into()))?;
+
+            let teams = self.team_repository
+                .find_all_by_tour(tour_id.into(), limit.into(), offset)
+                .await?;
+            self.compose(teams, &tour).await?
+        }
+
+        #[instrument(skip_all, name = "TeamService::find_technologies")]
+        async fn find_technologies(
+            &self,
+            id: Ulid,
+            (limit, offset): (u16, u64),
+        ) -> Vec<Technology> {
+            self.get_by_id(id).await?;
+
+            let uses = self.uses_repository
+                .find_all_by_in(id.into(), limit, offset)
+                .await?;
+            let mut technologies = Vec::with_capacity(uses.len());
+            for relation in uses {
+                technologies.push(
+                    self.technology_repository
+                        .find_by_id(relation.out)
+                        .await?
+                        .expect("Got unchecked technology ID")
+                        .into()
+                );
+            }
+            technologies
+        }
+
+        #[instrument(skip_all, name = "TeamService::add_technology")]
+        async fn add_technology(
+            &self,
+            id: Ulid,
+            technology_id: Ulid,
+            actor: Actor,
+        ) -> Technology {
+            self.get_by_id(id).await?;
+            self.permission_service
+                .require(actor, Permission::LeadTeam(id))
+                .await?;
+            let technology = self.technology_repository
+                .find_by_id(technology_id.into())
+                .await?
+                .ok_or(
+                    ServiceError::NotFound("Technology with provided id".into())
+                )?;
+
+            if !self.uses_repository
+                .exists_by_in_and_out(id.into(), technology_id.into())
+                .await?
+            {
+                self.uses_repository
+                    .save(
+                        CreateUses {
+                            r#in: id.into(),
+                            out: technology.id.clone(),
+                        }
+                    )
+                    .await?;
+            }
+
+            technology.into()
+        }
+
+        #[instrument(skip_all, name = "TeamService::remove_technology")]
+        async fn remove_technology(
+            &self,
+            id: Ulid,
+            technology_id: Ulid,
+            actor: Actor,
+        ) -> () {
+            self.get_by_id(id).await?;
+            self.permission_service
+                .require(actor, Permission::LeadTeam(id))
+                .await?;
+
+            self.uses_repository
+                .delete_by_in_and_out(id.into(), technology_id.into())
+                .await?
+                .ok_or(
+                    ServiceError::NotFound("Technology used by the team".into())
+                )?;
+        }
+
+        #[instrument(skip_all, name = "TeamService::find_all_by_tour_and_technology")]
+        async fn find_all_by_tour_and_technology(
+            &self,
+            tour_id: Ulid,
+            technology_id: Ulid,
+            (limit, offset): (u16, u64),
+        ) -> Vec<Team> {
+            if !self.tour_repository.exists_by_id(tour_id.into()).await? {
+                Err(ServiceError::NotFound("Tour with provided id".into()))?
+            }
+            if !self.technology_repository
+                .exists_by_id(technology_id.into())
+                .await?
+            {
+                Err(ServiceError::NotFound("Technology with provided id".into()))?
+            }
+
+            self.team_repository
+                .find_all_by_tour_and_technology(
+                    tour_id.into(),
+                    technology_id.into(),
+                    limit.into(),
+                    offset,
+                )
+                .await?
+                .into_iter()
+                .map(Team::from)
+                .collect()
+        }
+
+        #[instrument(skip_all, name = "TeamService::update_by_id")]
+        async fn update_by_id(
+            &self,
+            id: Ulid,
+            update: TeamUpdate,
+            actor: Actor,
+        ) -> Team {
+            let team = self.get_by_id(id).await?;
+            self.permission_service
+                .require(actor, Permission::LeadTeam(id))
+                .await?;
+            if let Some(name) = update.name.as_ref() {
+                if name != &team.name
+                    && self.team_repository
+                        .exists_by_tour_and_name(team.tour.into(), name)
+                        .await?
+                {
+                    Err(ServiceError::AlreadyExists("Team with provided name".into()))?
+                }
+            }
+
+            self.team_repository
+                .update_by_id(
+                    id.into(),
+                    TeamEntityUpdate {
+                        name: update.name,
+                    }
+                )
+                .await?
+                .expect("Got unchecked team ID")
+                .into()
+        }
+
+        #[instrument(skip_all, name = "TeamService::delete_by_id")]
+        async fn delete_by_id(
+            &self,
+            id: Ulid,
+            actor: Actor,
+        ) -> () {
+            self.get_by_id(id).await?;
+            self.permission_service
+                .require(actor, Permission::LeadTeam(id))
+                .await?;
+
+            self.team_repository
+                .delete_by_id(id.into())
+                .await?;
+        }
+    }
+}
+
+impl TeamServiceImpl {
+    #[tracing::instrument(skip_all, level = "trace")]
+    async fn compose(
+        &self,
+        teams: Vec<TeamEntity>,
+        tour: &TourEntity,
+    ) -> TeamServiceResult<Vec<TeamComposition>> {
+        let mut member_specializations: HashMap<Ulid, Vec<Ulid>> =
+            HashMap::new();
+        for member in self
+            .member_of_repository
+            .find_all_accepted_by_outs(
+                teams.iter().map(|team| team.id.clone()).collect(),
+            )
+            .await?
+        {
+            member_specializations
+                .entry(member.out.into())
+                .or_default()
+                .push(member.specialization.into());
+        }
+        let required_specializations: Vec<Ulid> = tour
+            .required_specializations
+            .iter()
+            .cloned()
+            .map(Ulid::from)
+            .collect();
+
+        Ok(teams
+            .into_iter()
+            .map(|team| {
+                let team_id = Ulid::from(team.id);
+                composition::evaluate(
+                    team_id,
+                    &required_specializations,
+                    member_specializations
+                        .get(&team_id)
+                        .map_or(&[], Vec::as_slice),
+                    tour.max_members,
+                )
+            })
+            .collect())
+    }
+}
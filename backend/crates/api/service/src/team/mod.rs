@@ -0,0 +1,86 @@
+use dto::{
+    member::Member,
+    team::{CreateTeam, Team, TeamComposition, TeamUpdate},
+    technology::Technology,
+};
+use macros::service;
+use ulid::Ulid;
+
+use crate::{common::ServiceError, permission::Actor};
+
+pub mod composition;
+pub mod implementation;
+
+service! {
+    Team
+        Err: ServiceError
+    {
+        async fn create(&self, lead_id: Ulid, new: CreateTeam) -> Team;
+
+        async fn find_by_id(&self, id: Ulid) -> Option<Team>;
+
+        async fn get_by_id(&self, id: Ulid) -> Team;
+
+        async fn get_by_tour_and_lead(
+            &self,
+            tour_id: Ulid,
+            lead_id: Ulid,
+        ) -> Team;
+
+        async fn find_all_by_tour(
+            &self,
+            tour_id: Ulid,
+            pagination: (u16, u64),
+        ) -> Vec<Team>;
+
+        async fn find_members(
+            &self,
+            id: Ulid,
+            pagination: (u16, u64),
+        ) -> Vec<Member>;
+
+        async fn get_composition(&self, id: Ulid) -> TeamComposition;
+
+        async fn find_compositions_by_tour(
+            &self,
+            tour_id: Ulid,
+            pagination: (u16, u64),
+        ) -> Vec<TeamComposition>;
+
+        async fn find_technologies(
+            &self,
+            id: Ulid,
+            pagination: (u16, u64),
+        ) -> Vec<Technology>;
+
+        async fn add_technology(
+            &self,
+            id: Ulid,
+            technology_id: Ulid,
+            actor: Actor,
+        ) -> Technology;
+
+        async fn remove_technology(
+            &self,
+            id: Ulid,
+            technology_id: Ulid,
+            actor: Actor,
+        ) -> ();
+
+        async fn find_all_by_tour_and_technology(
+            &self,
+            tour_id: Ulid,
+            technology_id: Ulid,
+            pagination: (u16, u64),
+        ) -> Vec<Team>;
+
+        async fn update_by_id(
+            &self,
+            id: Ulid,
+            update: TeamUpdate,
+            actor: Actor,
+        ) -> Team;
+
+        async fn delete_by_id(&self, id: Ulid, actor: Actor) -> ();
+    }
+}
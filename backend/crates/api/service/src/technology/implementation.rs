@@ -0,0 +1,148 @@
+use dto::technology::{CreateTechnology, Technology, TechnologyUpdate};
+use entity::technology::{
+    CreateTechnology as CreateTechnologyEntity, TechnologyId,
+    TechnologyUpdate as TechnologyEntityUpdate,
+};
+use macros::implementation;
+use repository::{
+    knows::KnowsRepositoryDependency,
+    participated_in::ParticipatedInRepositoryDependency,
+    technology::TechnologyRepositoryDependency, uses::UsesRepositoryDependency,
+};
+use tracing::instrument;
+use ulid::Ulid;
+
+use super::{TechnologyService, TechnologyServiceResult};
+use crate::common::ServiceError;
+
+implementation! {
+    TechnologyService {
+        technology_repository: TechnologyRepositoryDependency,
+        knows_repository: KnowsRepositoryDependency,
+        uses_repository: UsesRepositoryDependency,
+        participated_in_repository: ParticipatedInRepositoryDependency,
+    } as TechnologyServiceImpl {
+        #[instrument(skip_all, name = "TechnologyService::create")]
+        async fn create(
+            &self,
+            new: CreateTechnology,
+        ) -> Technology {
+            if self.technology_repository.exists_by_name(&new.name).await? {
+                Err(ServiceError::AlreadyExists("Technology with provided name".into()))?
+            }
+
+            self.technology_repository
+                .save(
+                    CreateTechnologyEntity {
+                        name: new.name,
+                    }
+                )
+                .await?
+                .into()
+        }
+
+        #[instrument(skip_all, name = "TechnologyService::find_by_id")]
+        async fn find_by_id(
+            &self,
+            id: Ulid,
+        ) -> Option<Technology> {
+            self.technology_repository
+                .find_by_id(id.into())
+                .await?
+                .map(Technology::from)
+        }
+
+        #[instrument(skip_all, name = "TechnologyService::get_by_id")]
+        async fn get_by_id(
+            &self,
+            id: Ulid,
+        ) -> Technology {
+            self
+                .find_by_id(id)
+                .await?
+                .ok_or(
+                    ServiceError::NotFound("Technology with provided id".into())
+                )?
+        }
+
+        #[instrument(skip_all, name = "TechnologyService::find_all")]
+        async fn find_all(
+            &self,
+            (limit, offset): (u16, u64),
+        ) -> Vec<Technology> {
+            self.technology_repository
+                .find_all(limit, offset)
+                .await?
+                .into_iter()
+                .map(Technology::from)
+                .collect()
+        }
+
+        #[instrument(skip_all, name = "TechnologyService::update_by_id")]
+        async fn update_by_id(
+            &self,
+            id: Ulid,
+            update: TechnologyUpdate,
+        ) -> Technology {
+            self.get_by_id(id).await?;
+
+            let name_taken = match update.name.as_deref() {
+                Some(name) => self.technology_repository
+                    .find_by_name(name)
+                    .await?
+                    .is_some_and(|existing| existing.id != TechnologyId::from(id)),
+                None => false,
+            };
+            if name_taken {
+                Err(ServiceError::AlreadyExists("Technology with provided name".into()))?
+            }
+
+            self.technology_repository
+                .update_by_id(
+                    id.into(),
+                    TechnologyEntityUpdate {
+                        name: update.name,
+                    }
+                )
+                .await?
+                .expect("Got unchecked technology ID")
+                .into()
+        }
+
+        #[instrument(skip_all, name = "TechnologyService::delete_by_id")]
+        async fn delete_by_id(
+            &self,
+            id: Ulid,
+        ) -> () {
+            self.get_by_id(id).await?;
+            if self.knows_repository
+                .exists_by_out(id.into())
+                .await?
+            {
+                Err(ServiceError::Conflict(
+                    "Technology is known by a user".into(),
+                ))?
+            }
+            if self.uses_repository
+                .exists_by_out(id.into())
+                .await?
+            {
+                Err(ServiceError::Conflict(
+                    "Technology is used by a team".into(),
+                ))?
+            }
+            if self.participated_in_repository
+                .exists_by_technology(id.into())
+                .await?
+            {
+                Err(ServiceError::Conflict(
+                    "Technology is referenced by tour results".into(),
+                ))?
+            }
+
+            self.technology_repository
+                .delete_by_id(id.into())
+                .await?;
+        }
+    }
+}
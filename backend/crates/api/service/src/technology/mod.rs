@@ -0,0 +1,25 @@
+use dto::technology::{CreateTechnology, Technology, TechnologyUpdate};
+use macros::service;
+use ulid::Ulid;
+
+use crate::common::ServiceError;
+
+pub mod implementation;
+
+service! {
+    Technology
+        Err: ServiceError
+    {
+        async fn create(&self, new: CreateTechnology) -> Technology;
+
+        async fn find_by_id(&self, id: Ulid) -> Option<Technology>;
+
+        async fn get_by_id(&self, id: Ulid) -> Technology;
+
+        async fn find_all(&self, pagination: (u16, u64)) -> Vec<Technology>;
+
+        async fn update_by_id(&self, id: Ulid, update: TechnologyUpdate) -> Technology;
+
+        async fn delete_by_id(&self, id: Ulid) -> ();
+    }
+}
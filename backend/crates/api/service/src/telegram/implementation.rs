@@ -0,0 +1,189 @@
+use chrono::Utc;
+use dto::{telegram::TelegramLink, user::User};
+use entity::{
+    profile::{ProfileId, UpsertProfile as UpsertProfileEntity},
+    telegram_link::CreateTelegramLink,
+    user::{User as UserEntity, UserUpdate as UserEntityUpdate},
+};
+use macros::implementation;
+use repository::{
+    profile::ProfileRepositoryDependency,
+    telegram_link::TelegramLinkRepositoryDependency,
+    user::UserRepositoryDependency,
+};
+use tracing::instrument;
+use ulid::Ulid;
+use utils::auth::token;
+
+use super::{
+    TELEGRAM_LINK_LIFETIME, TelegramService, TelegramServiceResult,
+    is_telegram_verified,
+};
+use crate::common::ServiceError;
+
+implementation! {
+    TelegramService {
+        telegram_link_repository: TelegramLinkRepositoryDependency,
+        user_repository: UserRepositoryDependency,
+        profile_repository: ProfileRepositoryDependency,
+        bot_username: String,
+    } as TelegramServiceImpl {
+        #[instrument(skip_all, name = "TelegramService::create_link")]
+        async fn create_link(
+            &self,
+            user_id: Ulid,
+        ) -> TelegramLink {
+            self.telegram_link_repository
+                .delete_all_by_user(user_id.into())
+                .await?;
+
+            let link = self.telegram_link_repository
+                .save(
+                    CreateTelegramLink {
+                        user: user_id.into(),
+                        code: token::generate(),
+                        expires_at: Utc::now()
+                            .checked_add_signed(TELEGRAM_LINK_LIFETIME)
+                            .expect("Got out of range Telegram link expiration"),
+                    }
+                )
+                .await?;
+
+            TelegramLink {
+                url: format!("https://t.me/{}?start={}", self.bot_username, link.code),
+                code: link.code,
+                expires_at: link.expires_at,
+            }
+        }
+
+        #[instrument(skip_all, name = "TelegramService::link")]
+        async fn link(
+            &self,
+            code: String,
+            chat_id: i64,
+            username: Option<String>,
+        ) -> User {
+            let invalid_code = || ServiceError::BadRequest(
+                "Invalid or expired Telegram link code".into()
+            );
+
+            let link = self.telegram_link_repository
+                .find_by_code(&code)
+                .await?
+                .ok_or_else(invalid_code)?;
+            if link.expires_at < Utc::now() {
+                Err(invalid_code())?
+            }
+            if self.user_repository
+                .find_by_telegram_chat_id(chat_id)
+                .await?
+                .is_some_and(|linked| linked.id != link.user)
+            {
+                Err(ServiceError::Conflict(
+                    "Telegram account is already linked to another user".into()
+                ))?
+            }
+
+            self.telegram_link_repository
+                .delete_by_id(link.id)
+                .await?
+                .ok_or_else(invalid_code)?;
+
+            let user = self.user_repository
+                .update_by_id(
+                    link.user,
+                    UserEntityUpdate {
+                        telegram_chat_id: Some(Some(chat_id)),
+                        telegram_username: Some(username),
+                        ..Default::default()
+                    }
+                )
+                .await?
+                .ok_or(
+                    ServiceError::NotFound("User with provided id".into())
+                )?;
+            self.sync_profile(&user).await?;
+
+            user.into()
+        }
+
+        #[instrument(skip_all, name = "TelegramService::unlink")]
+        async fn unlink(
+            &self,
+            user_id: Ulid,
+        ) -> () {
+            let user = self.user_repository
+                .find_by_id(user_id.into())
+                .await?
+                .ok_or(
+                    ServiceError::NotFound("User with provided id".into())
+                )?;
+            if user.telegram_chat_id.is_none() {
+                Err(ServiceError::NotFound("Linked Telegram account".into()))?
+            }
+
+            let user = self.user_repository
+                .update_by_id(
+                    user.id,
+                    UserEntityUpdate {
+                        telegram_chat_id: Some(None),
+                        telegram_username: Some(None),
+                        ..Default::default()
+                    }
+                )
+                .await?
+                .expect("Got unchecked user ID");
+            self.sync_profile(&user).await?;
+        }
+
+        #[instrument(skip_all, name = "TelegramService::find_user_by_chat")]
+        async fn find_user_by_chat(
+            &self,
+            chat_id: i64,
+        ) -> Option<User> {
+            self.user_repository
+                .find_by_telegram_chat_id(chat_id)
+                .await?
+                .map(User::from)
+        }
+    }
+}
+
+impl TelegramServiceImpl {
+    /// Puts the linked Telegram username into the user's profile, if there is
+    /// one, and recalculates whether the profile's handle is verified.
+    #[tracing::instrument(skip_all, level = "debug")]
+    async fn sync_profile(
+        &self,
+        user: &UserEntity,
+    ) -> Result<(), ServiceError> {
+        let profile_id: ProfileId = Ulid::from(user.id.clone()).into();
+        let Some(profile) = self
+            .profile_repository
+            .find_by_id(profile_id.clone())
+            .await?
+        else {
+            return Ok(());
+        };
+
+        let telegram =
+            user.telegram_username.clone().unwrap_or(profile.telegram);
+        self.profile_repository
+            .upsert_by_id(
+                profile_id,
+                UpsertProfileEntity {
+                    telegram_verified: is_telegram_verified(user, &telegram),
+                    name: profile.name,
+                    surname: profile.surname,
+                    telegram,
+                    city: profile.city,
+                    bio: profile.bio,
+                    portfolio_urls: profile.portfolio_urls,
+                    has_avatar: profile.has_avatar,
+                },
+            )
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,74 @@
+use chrono::TimeDelta;
+use dto::{telegram::TelegramLink, user::User};
+use entity::user::User as UserEntity;
+use macros::service;
+use ulid::Ulid;
+
+use crate::common::ServiceError;
+
+pub mod implementation;
+
+const TELEGRAM_LINK_LIFETIME: TimeDelta = TimeDelta::minutes(10);
+
+service! {
+    Telegram
+        Err: ServiceError
+    {
+        async fn create_link(&self, user_id: Ulid) -> TelegramLink;
+
+        async fn link(
+            &self,
+            code: String,
+            chat_id: i64,
+            username: Option<String>
+        ) -> User;
+
+        async fn unlink(&self, user_id: Ulid) -> ();
+
+        async fn find_user_by_chat(&self, chat_id: i64) -> Option<User>;
+    }
+}
+
+/// Profile's Telegram handle is trusted only when it matches the username of
+/// the Telegram account linked through the bot.
+#[tracing::instrument(skip_all, level = "trace")]
+pub(crate) fn is_telegram_verified(user: &UserEntity, telegram: &str) -> bool {
+    user.telegram_username
+        .as_deref()
+        .is_some_and(|username| username.eq_ignore_ascii_case(telegram))
+}
+
+#[cfg(test)]
+mod test {
+    use entity::user::{CreateUser, User, UserRole};
+    use rstest::{fixture, rstest};
+
+    use super::is_telegram_verified;
+
+    #[fixture]
+    fn user() -> User {
+        CreateUser {
+            email: "user@example.com".into(),
+            password_hash: String::new(),
+            username: "user".into(),
+            role: UserRole::Participant,
+        }
+        .into()
+    }
+
+    #[rstest]
+    #[case::not_linked(None, "minizord_user", false)]
+    #[case::same(Some("minizord_user"), "minizord_user", true)]
+    #[case::other_case(Some("Minizord_User"), "minizord_user", true)]
+    #[case::other(Some("someone_else"), "minizord_user", false)]
+    fn verification(
+        mut user: User,
+        #[case] linked: Option<&str>,
+        #[case] telegram: &str,
+        #[case] expected: bool,
+    ) {
+        user.telegram_username = linked.map(str::to_string);
+
+        assert_eq!(is_telegram_verified(&user, telegram), expected);
+    }
+}
@@ -0,0 +1,217 @@
+use chrono::{DateTime, Utc};
+use dto::tour::{CreateTour, Tour, TourUpdate};
+use entity::tour::{
+    CreateTour as CreateTourEntity, TourId, TourUpdate as TourEntityUpdate,
+};
+use macros::implementation;
+use repository::{
+    specialization::SpecializationRepositoryDependency,
+    team::TeamRepositoryDependency, tour::TourRepositoryDependency,
+};
+use tracing::instrument;
+use ulid::Ulid;
+
+use super::{TourService, TourServiceResult};
+use crate::{
+    common::ServiceError,
+    permission::{Actor, Permission, PermissionServiceDependency},
+};
+
+implementation! {
+    TourService {
+        tour_repository: TourRepositoryDependency,
+        team_repository: TeamRepositoryDependency,
+        specialization_repository: SpecializationRepositoryDependency,
+        permission_service: PermissionServiceDependency,
+    } as TourServiceImpl {
+        #[instrument(skip_all, name = "TourService::create")]
+        async fn create(
+            &self,
+            new: CreateTour,
+        ) -> Tour {
+            validate_schedule(&new.starts_at, &new.ends_at)?;
+            if self.tour_repository.exists_by_name(&new.name).await? {
+                Err(ServiceError::AlreadyExists("Tour with provided name".into()))?
+            }
+            self.check_specializations(&new.required_specializations).await?;
+
+            self.tour_repository
+                .save(
+                    CreateTourEntity {
+                        name: new.name,
+                        starts_at: new.starts_at,
+                        ends_at: new.ends_at,
+                        max_members: new.max_members,
+                        max_mentors: new.max_mentors,
+                        required_specializations: new.required_specializations
+                            .into_iter()
+                            .map(Ulid::into)
+                            .collect(),
+                    }
+                )
+                .await?
+                .into()
+        }
+
+        #[instrument(skip_all, name = "TourService::find_by_id")]
+        async fn find_by_id(
+            &self,
+            id: Ulid,
+        ) -> Option<Tour> {
+            self.tour_repository
+                .find_by_id(id.into())
+                .await?
+                .map(Tour::from)
+        }
+
+        #[instrument(skip_all, name = "TourService::get_by_id")]
+        async fn get_by_id(
+            &self,
+            id: Ulid,
+        ) -> Tour {
+            self
+                .find_by_id(id)
+                .await?
+                .ok_or(
+                    ServiceError::NotFound("Tour with provided id".into())
+                )?
+        }
+
+        #[instrument(skip_all, name = "TourService::get_current")]
+        async fn get_current(&self) -> Tour {
+            self.tour_repository
+                .find_current()
+                .await?
+                .ok_or(
+                    ServiceError::NotFound("Tour that has not ended yet".into())
+                )?
+                .into()
+        }
+
+        #[instrument(skip_all, name = "TourService::find_all")]
+        async fn find_all(
+            &self,
+            (limit, offset): (u16, u64),
+        ) -> Vec<Tour> {
+            self.tour_repository
+                .find_all(limit, offset)
+                .await?
+                .into_iter()
+                .map(Tour::from)
+                .collect()
+        }
+
+        #[instrument(skip_all, name = "TourService::update_by_id")]
+        async fn update_by_id(
+            &self,
+            id: Ulid,
+            update: TourUpdate,
+            actor: Actor,
+        ) -> Tour {
+            let tour = self.get_by_id(id).await?;
+            self.permission_service
+                .require(actor, Permission::OrganizeTour(id))
+                .await?;
+
+            validate_schedule(
+                update.starts_at.as_ref().unwrap_or(&tour.starts_at),
+                update.ends_at.as_ref().unwrap_or(&tour.ends_at),
+            )?;
+            let name_taken = match update.name.as_deref() {
+                Some(name) => self.tour_repository
+                    .find_by_name(name)
+                    .await?
+                    .is_some_and(|existing| existing.id != TourId::from(id)),
+                None => false,
+            };
+            if name_taken {
+                Err(ServiceError::AlreadyExists("Tour with provided name".into()))?
+            }
+            if update.max_members.is_some_and(|max_members| max_members != tour.max_members)
+                && self.team_repository.exists_by_tour(id.into()).await?
+            {
+                Err(ServiceError::Forbidden(
+                    "Unable to change max members of a tour that already has teams".into(),
+                ))?
+            }
+            if let Some(required_specializations) = update.required_specializations.as_ref() {
+                self.check_specializations(required_specializations).await?;
+            }
+
+            self.tour_repository
+                .update_by_id(
+                    id.into(),
+                    TourEntityUpdate {
+                        name: update.name,
+                        reminder_sent: update.starts_at
+                            .is_some_and(|starts_at| starts_at != tour.starts_at)
+                            .then_some(false),
+                        starts_at: update.starts_at,
+                        ends_at: update.ends_at,
+                        max_members: update.max_members,
+                        max_mentors: update.max_mentors,
+                        required_specializations: update.required_specializations
+                            .map(|specializations| specializations
+                                .into_iter()
+                                .map(Ulid::into)
+                                .collect()
+                            ),
+                    }
+                )
+                .await?
+                .expect("Got unchecked tour ID")
+                .into()
+        }
+
+        #[instrument(skip_all, name = "TourService::delete_by_id")]
+        async fn delete_by_id(
+            &self,
+            id: Ulid,
+        ) -> () {
+            self.get_by_id(id).await?;
+            if self.team_repository.exists_by_tour(id.into()).await? {
+                Err(ServiceError::Forbidden(
+                    "Unable to delete a tour that already has teams".into(),
+                ))?
+            }
+
+            self.tour_repository
+                .delete_by_id(id.into())
+                .await?;
+        }
+    }
+}
+
+impl TourServiceImpl {
+    #[tracing::instrument(skip_all, level = "trace")]
+    async fn check_specializations(
+        &self,
+        specializations: &[Ulid],
+    ) -> TourServiceResult<()> {
+        for id in specializations {
+            if !self
+                .specialization_repository
+                .exists_by_id((*id).into())
+                .await?
+            {
+                Err(ServiceError::NotFound(format!(
+                    "Specialization with id `{id}`"
+                )))?
+            }
+        }
+        Ok(())
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+fn validate_schedule(
+    starts_at: &DateTime<Utc>,
+    ends_at: &DateTime<Utc>,
+) -> TourServiceResult<()> {
+    if starts_at >= ends_at {
+        Err(ServiceError::BadRequest(
+            "Tour must start before it ends".into(),
+        ))?
+    }
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use dto::tour::{CreateTour, Tour, TourUpdate};
+use macros::service;
+use ulid::Ulid;
+
+use crate::{common::ServiceError, permission::Actor};
+
+pub mod implementation;
+
+service! {
+    Tour
+        Err: ServiceError
+    {
+        async fn create(&self, new: CreateTour) -> Tour;
+
+        async fn find_by_id(&self, id: Ulid) -> Option<Tour>;
+
+        async fn get_by_id(&self, id: Ulid) -> Tour;
+
+        /// The current tour is the earliest one that has not ended yet.
+        async fn get_current(&self) -> Tour;
+
+        async fn find_all(&self, pagination: (u16, u64)) -> Vec<Tour>;
+
+        async fn update_by_id(
+            &self,
+            id: Ulid,
+            update: TourUpdate,
+            actor: Actor,
+        ) -> Tour;
+
+        async fn delete_by_id(&self, id: Ulid) -> ();
+    }
+}
@@ -0,0 +1,229 @@
+use dto::tour_result::{TeamResultRequest, TourResult, UpsertTourResult};
+use entity::{
+    participated_in::{
+        CreateParticipatedIn, ParticipatedIn, ParticipatedInUpdate,
+    },
+    specialization::SpecializationId,
+    technology::TechnologyId,
+    tour::TourId,
+    user::UserId,
+};
+use macros::implementation;
+use repository::{
+    member_of::MemberOfRepositoryDependency,
+    participated_in::ParticipatedInRepositoryDependency,
+    specialization::SpecializationRepositoryDependency,
+    team::TeamRepositoryDependency, technology::TechnologyRepositoryDependency,
+    tour::TourRepositoryDependency,
+};
+use tracing::instrument;
+use ulid::Ulid;
+
+use super::{TourResultService, TourResultServiceResult};
+use crate::{common::ServiceError, user::UserServiceDependency};
+
+implementation! {
+    TourResultService {
+        participated_in_repository: ParticipatedInRepositoryDependency,
+        tour_repository: TourRepositoryDependency,
+        team_repository: TeamRepositoryDependency,
+        member_of_repository: MemberOfRepositoryDependency,
+        specialization_repository: SpecializationRepositoryDependency,
+        technology_repository: TechnologyRepositoryDependency,
+        user_service: UserServiceDependency,
+    } as TourResultServiceImpl {
+        #[instrument(skip_all, name = "TourResultService::record_for_user")]
+        async fn record_for_user(
+            &self,
+            tour_id: Ulid,
+            user_id: Ulid,
+            object: UpsertTourResult,
+        ) -> TourResult {
+            self.check_tour(tour_id).await?;
+            self.user_service
+                .get_by_id(user_id)
+                .await?;
+            if !self.specialization_repository
+                .exists_by_id(object.specialization.into())
+                .await?
+            {
+                Err(ServiceError::NotFound(
+                    "Specialization with provided id".into(),
+                ))?
+            }
+            let technologies = self.check_technologies(object.technologies).await?;
+
+            self.upsert(
+                user_id.into(),
+                tour_id.into(),
+                object.score,
+                object.specialization.into(),
+                technologies,
+            )
+            .await?
+            .into()
+        }
+
+        #[instrument(skip_all, name = "TourResultService::record_for_team")]
+        async fn record_for_team(
+            &self,
+            tour_id: Ulid,
+            team_id: Ulid,
+            object: TeamResultRequest,
+        ) -> Vec<TourResult> {
+            self.check_tour(tour_id).await?;
+            let team = self.team_repository
+                .find_by_id(team_id.into())
+                .await?
+                .ok_or(ServiceError::NotFound("Team with provided id".into()))?;
+            if team.tour != TourId::from(tour_id) {
+                Err(ServiceError::BadRequest(
+                    "Team does not participate in provided tour".into(),
+                ))?
+            }
+            let technologies = self.check_technologies(object.technologies).await?;
+
+            let members = self.member_of_repository
+                .find_all_by_out(team.id, u16::MAX, 0)
+                .await?;
+            let mut results = Vec::with_capacity(members.len());
+            for member in members.into_iter().filter(|member| member.accepted) {
+                let result = self.upsert(
+                    member.r#in,
+                    tour_id.into(),
+                    object.score,
+                    member.specialization,
+                    technologies.clone(),
+                )
+                .await?;
+                results.push(result.into());
+            }
+            results
+        }
+
+        #[instrument(skip_all, name = "TourResultService::find_leaderboard")]
+        async fn find_leaderboard(
+            &self,
+            tour_id: Ulid,
+            specialization_id: Option<Ulid>,
+            (limit, offset): (u16, u64),
+        ) -> Vec<TourResult> {
+            self.check_tour(tour_id).await?;
+
+            self.participated_in_repository
+                .find_all_by_out_order_by_score(
+                    tour_id.into(),
+                    specialization_id.map(SpecializationId::from),
+                    limit,
+                    offset,
+                )
+                .await?
+                .into_iter()
+                .map(TourResult::from)
+                .collect()
+        }
+
+        #[instrument(skip_all, name = "TourResultService::find_all_by_user")]
+        async fn find_all_by_user(
+            &self,
+            user_id: Ulid,
+            (limit, offset): (u16, u64),
+            check_user: bool,
+        ) -> Vec<TourResult> {
+            if check_user {
+                self.user_service
+                    .get_by_id(user_id)
+                    .await?;
+            }
+
+            self.participated_in_repository
+                .find_all_by_in(user_id.into(), limit, offset)
+                .await?
+                .into_iter()
+                .map(TourResult::from)
+                .collect()
+        }
+
+        #[instrument(skip_all, name = "TourResultService::delete_by_id")]
+        async fn delete_by_id(
+            &self,
+            tour_id: Ulid,
+            user_id: Ulid,
+        ) -> () {
+            self.participated_in_repository
+                .delete_by_in_and_out(user_id.into(), tour_id.into())
+                .await?
+                .ok_or(ServiceError::NotFound(
+                    "Result for provided user in provided tour".into(),
+                ))?;
+        }
+    }
+}
+
+impl TourResultServiceImpl {
+    #[tracing::instrument(skip_all, level = "trace")]
+    async fn check_tour(&self, tour_id: Ulid) -> TourResultServiceResult<()> {
+        if !self.tour_repository.exists_by_id(tour_id.into()).await? {
+            Err(ServiceError::NotFound("Tour with provided id".into()))?
+        }
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all, level = "trace")]
+    async fn check_technologies(
+        &self,
+        technologies: Vec<Ulid>,
+    ) -> TourResultServiceResult<Vec<TechnologyId>> {
+        let mut ids = Vec::with_capacity(technologies.len());
+        for id in technologies {
+            if !self.technology_repository.exists_by_id(id.into()).await? {
+                Err(ServiceError::NotFound(format!(
+                    "Technology with id `{id}`"
+                )))?
+            }
+            ids.push(id.into());
+        }
+        Ok(ids)
+    }
+
+    #[tracing::instrument(skip_all, level = "trace")]
+    async fn upsert(
+        &self,
+        user: UserId,
+        tour: TourId,
+        score: f64,
+        specialization: SpecializationId,
+        technologies: Vec<TechnologyId>,
+    ) -> TourResultServiceResult<ParticipatedIn> {
+        let exists = self
+            .participated_in_repository
+            .exists_by_in_and_out(user.clone(), tour.clone())
+            .await?;
+
+        let result = if exists {
+            self.participated_in_repository
+                .update_by_in_and_out(
+                    user,
+                    tour,
+                    ParticipatedInUpdate {
+                        score: Some(score),
+                        specialization: Some(specialization),
+                        technologies: Some(technologies),
+                    },
+                )
+                .await?
+                .expect("Got unchecked result ID")
+        } else {
+            self.participated_in_repository
+                .save(CreateParticipatedIn {
+                    r#in: user,
+                    out: tour,
+                    score,
+                    specialization,
+                    technologies,
+                })
+                .await?
+        };
+        Ok(result)
+    }
+}
@@ -0,0 +1,43 @@
+use dto::tour_result::{TeamResultRequest, TourResult, UpsertTourResult};
+use macros::service;
+use ulid::Ulid;
+
+use crate::common::ServiceError;
+
+pub mod implementation;
+
+service! {
+    TourResult
+        Err: ServiceError
+    {
+        async fn record_for_user(
+            &self,
+            tour_id: Ulid,
+            user_id: Ulid,
+            object: UpsertTourResult,
+        ) -> TourResult;
+
+        async fn record_for_team(
+            &self,
+            tour_id: Ulid,
+            team_id: Ulid,
+            object: TeamResultRequest,
+        ) -> Vec<TourResult>;
+
+        async fn find_leaderboard(
+            &self,
+            tour_id: Ulid,
+            specialization_id: Option<Ulid>,
+            pagination: (u16, u64),
+        ) -> Vec<TourResult>;
+
+        async fn find_all_by_user(
+            &self,
+            user_id: Ulid,
+            pagination: (u16, u64),
+            check_user: bool,
+        ) -> Vec<TourResult>;
+
+        async fn delete_by_id(&self, tour_id: Ulid, user_id: Ulid) -> ();
+    }
+}
@@ -0,0 +1,135 @@
+use dto::user::UserRole;
+use entity::{mentors_in::CreateMentorsIn, organizes::CreateOrganizes};
+use macros::implementation;
+use repository::{
+    mentors_in::MentorsInRepositoryDependency,
+    organizes::OrganizesRepositoryDependency, tour::TourRepositoryDependency,
+};
+use tracing::instrument;
+use ulid::Ulid;
+
+use super::{TourStaffService, TourStaffServiceResult};
+use crate::{
+    common::ServiceError,
+    permission::{Actor, Permission, PermissionServiceDependency},
+    user::UserServiceDependency,
+};
+
+implementation! {
+    TourStaffService {
+        organizes_repository: OrganizesRepositoryDependency,
+        mentors_in_repository: MentorsInRepositoryDependency,
+        tour_repository: TourRepositoryDependency,
+        user_service: UserServiceDependency,
+        permission_service: PermissionServiceDependency,
+    } as TourStaffServiceImpl {
+        #[instrument(skip_all, name = "TourStaffService::assign_organizer")]
+        async fn assign_organizer(
+            &self,
+            tour_id: Ulid,
+            user_id: Ulid,
+        ) -> () {
+            self.check_staff(tour_id, user_id, UserRole::Organizer).await?;
+            if self.organizes_repository
+                .exists_by_in_and_out(user_id.into(), tour_id.into())
+                .await?
+            {
+                Err(ServiceError::AlreadyExists(
+                    "Organizer assignment to provided tour".into(),
+                ))?
+            }
+
+            self.organizes_repository
+                .save(
+                    CreateOrganizes {
+                        r#in: user_id.into(),
+                        out: tour_id.into(),
+                    }
+                )
+                .await?;
+        }
+
+        #[instrument(skip_all, name = "TourStaffService::unassign_organizer")]
+        async fn unassign_organizer(
+            &self,
+            tour_id: Ulid,
+            user_id: Ulid,
+        ) -> () {
+            self.organizes_repository
+                .delete_by_in_and_out(user_id.into(), tour_id.into())
+                .await?
+                .ok_or(ServiceError::NotFound(
+                    "Organizer assignment to provided tour".into(),
+                ))?;
+        }
+
+        #[instrument(skip_all, name = "TourStaffService::assign_mentor")]
+        async fn assign_mentor(
+            &self,
+            tour_id: Ulid,
+            user_id: Ulid,
+            actor: Actor,
+        ) -> () {
+            self.check_staff(tour_id, user_id, UserRole::Mentor).await?;
+            self.permission_service
+                .require(actor, Permission::OrganizeTour(tour_id))
+                .await?;
+            if self.mentors_in_repository
+                .exists_by_in_and_out(user_id.into(), tour_id.into())
+                .await?
+            {
+                Err(ServiceError::AlreadyExists(
+                    "Mentor assignment to provided tour".into(),
+                ))?
+            }
+
+            self.mentors_in_repository
+                .save(
+                    CreateMentorsIn {
+                        r#in: user_id.into(),
+                        out: tour_id.into(),
+                    }
+                )
+                .await?;
+        }
+
+        #[instrument(skip_all, name = "TourStaffService::unassign_mentor")]
+        async fn unassign_mentor(
+            &self,
+            tour_id: Ulid,
+            user_id: Ulid,
+            actor: Actor,
+        ) -> () {
+            self.permission_service
+                .require(actor, Permission::OrganizeTour(tour_id))
+                .await?;
+            self.mentors_in_repository
+                .delete_by_in_and_out(user_id.into(), tour_id.into())
+                .await?
+                .ok_or(ServiceError::NotFound(
+                    "Mentor assignment to provided tour".into(),
+                ))?;
+        }
+    }
+}
+
+impl TourStaffServiceImpl {
+    #[tracing::instrument(skip_all, level = "trace")]
+    async fn check_staff(
+        &self,
+        tour_id: Ulid,
+        user_id: Ulid,
+        role: UserRole,
+    ) -> TourStaffServiceResult<()> {
+        if !self.tour_repository.exists_by_id(tour_id.into()).await? {
+            Err(ServiceError::NotFound("Tour with provided id".into()))?
+        }
+        let user = self.user_service.get_by_id(user_id).await?;
+        if user.role != role {
+            Err(ServiceError::BadRequest(format!(
+                "Only users with the {role} role can be assigned as {role}s"
+            )))?
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,30 @@
+use macros::service;
+use ulid::Ulid;
+
+use crate::{common::ServiceError, permission::Actor};
+
+pub mod implementation;
+
+service! {
+    TourStaff
+        Err: ServiceError
+    {
+        async fn assign_organizer(&self, tour_id: Ulid, user_id: Ulid) -> ();
+
+        async fn unassign_organizer(&self, tour_id: Ulid, user_id: Ulid) -> ();
+
+        async fn assign_mentor(
+            &self,
+            tour_id: Ulid,
+            user_id: Ulid,
+            actor: Actor,
+        ) -> ();
+
+        async fn unassign_mentor(
+            &self,
+            tour_id: Ulid,
+            user_id: Ulid,
+            actor: Actor,
+        ) -> ();
+    }
+}
@@ -0,0 +1,308 @@
+use chrono::Utc;
+use dto::{
+    auth::{
+        TwoFactorCodeRequest, TwoFactorLoginRequest, TwoFactorSetupRequest,
+    },
+    session::SessionTokens,
+    two_factor::{
+        TwoFactorChallenge, TwoFactorEnrollment, TwoFactorRecoveryCodes,
+    },
+    user::User,
+};
+use entity::user::{User as UserEntity, UserUpdate as UserEntityUpdate};
+use macros::implementation;
+use repository::user::UserRepositoryDependency;
+use tracing::instrument;
+use ulid::Ulid;
+use utils::auth::{JwtKeys, PasswordHasher, jwt, totp};
+
+use super::{
+    CHALLENGE_TOKEN_KIND, CHALLENGE_TOKEN_LIFETIME, RECOVERY_CODE_COUNT,
+    TwoFactorService, TwoFactorServiceResult,
+};
+use crate::{common::ServiceError, session::SessionServiceDependency};
+
+implementation! {
+    TwoFactorService {
+        user_repository: UserRepositoryDependency,
+        session_service: SessionServiceDependency,
+        jwt_keys: JwtKeys,
+        password_hasher: PasswordHasher<'static>,
+        issuer: String
+    } as TwoFactorServiceImpl {
+        #[instrument(skip_all, name = "TwoFactorService::challenge")]
+        async fn challenge(
+            &self,
+            user_id: Ulid,
+        ) -> TwoFactorChallenge {
+            TwoFactorChallenge {
+                challenge_token: jwt::new_with_lifetime(
+                    CHALLENGE_TOKEN_KIND,
+                    user_id,
+                    &self.jwt_keys,
+                    CHALLENGE_TOKEN_LIFETIME,
+                ),
+            }
+        }
+
+        #[instrument(skip_all, name = "TwoFactorService::challenged_user")]
+        async fn challenged_user(
+            &self,
+            challenge_token: &str,
+        ) -> Ulid {
+            let claims = jwt::parse_for(
+                CHALLENGE_TOKEN_KIND,
+                challenge_token,
+                &self.jwt_keys,
+            ).ok_or_else(Self::invalid_challenge)?;
+            Ulid::from_string(&claims.sub)
+                .map_err(|_| Self::invalid_challenge())?
+        }
+
+        #[instrument(skip_all, name = "TwoFactorService::login")]
+        async fn login(
+            &self,
+            TwoFactorLoginRequest {
+                challenge_token,
+                code
+            }: TwoFactorLoginRequest,
+        ) -> (User, SessionTokens) {
+            let id = self.challenged_user(&challenge_token).await?;
+
+            let user = self.user_repository
+                .find_by_id(id.into())
+                .await?
+                .filter(|user| user.totp_enabled)
+                .ok_or_else(Self::invalid_challenge)?;
+            let user = self.redeem_code(user, &code).await?;
+
+            let tokens = self.session_service
+                .start(user.id.clone().into(), user.role.into())
+                .await?;
+
+            (user.into(), tokens)
+        }
+
+        #[instrument(skip_all, name = "TwoFactorService::enroll")]
+        async fn enroll(
+            &self,
+            user_id: Ulid,
+            TwoFactorSetupRequest {
+                password
+            }: TwoFactorSetupRequest,
+        ) -> TwoFactorEnrollment {
+            let user = self.get_user(user_id).await?;
+            self.password_hasher
+                .verify(&password, &user.password_hash)
+                .map_err(|_| ServiceError::InvalidPassword)?;
+            if user.totp_enabled {
+                Err(ServiceError::Conflict("Two-factor authentication is already enabled".into()))?
+            }
+
+            let secret = totp::generate_secret();
+            self.user_repository
+                .update_by_id(
+                    user.id,
+                    UserEntityUpdate {
+                        totp_secret: Some(Some(secret.clone())),
+                        ..Default::default()
+                    }
+                )
+                .await?;
+
+            TwoFactorEnrollment {
+                provisioning_uri: totp::provisioning_uri(&secret, &self.issuer, &user.email),
+                secret,
+            }
+        }
+
+        #[instrument(skip_all, name = "TwoFactorService::confirm")]
+        async fn confirm(
+            &self,
+            user_id: Ulid,
+            TwoFactorCodeRequest {
+                code
+            }: TwoFactorCodeRequest,
+        ) -> TwoFactorRecoveryCodes {
+            let user = self.get_user(user_id).await?;
+            if user.totp_enabled {
+                Err(ServiceError::Conflict("Two-factor authentication is already enabled".into()))?
+            }
+            let secret = user.totp_secret
+                .as_deref()
+                .ok_or(
+                    ServiceError::Conflict("Two-factor authentication enrollment was not started".into())
+                )?;
+
+            let step = totp::verify(secret, code.trim(), Self::now(), None)
+                .ok_or_else(Self::invalid_code)?;
+            let recovery_codes = totp::generate_recovery_codes(RECOVERY_CODE_COUNT);
+
+            self.user_repository
+                .update_by_id(
+                    user.id,
+                    UserEntityUpdate {
+                        totp_enabled: Some(true),
+                        totp_recovery_codes: Some(self.hash_recovery_codes(&recovery_codes)?),
+                        totp_last_step: Some(Some(step)),
+                        ..Default::default()
+                    }
+                )
+                .await?;
+
+            TwoFactorRecoveryCodes {
+                recovery_codes,
+            }
+        }
+
+        #[instrument(skip_all, name = "TwoFactorService::regenerate_recovery_codes")]
+        async fn regenerate_recovery_codes(
+            &self,
+            user_id: Ulid,
+            TwoFactorCodeRequest {
+                code
+            }: TwoFactorCodeRequest,
+        ) -> TwoFactorRecoveryCodes {
+            let user = self.get_enabled_user(user_id).await?;
+            let user = self.redeem_code(user, &code).await?;
+
+            let recovery_codes = totp::generate_recovery_codes(RECOVERY_CODE_COUNT);
+            self.user_repository
+                .update_by_id(
+                    user.id,
+                    UserEntityUpdate {
+                        totp_recovery_codes: Some(self.hash_recovery_codes(&recovery_codes)?),
+                        ..Default::default()
+                    }
+                )
+                .await?;
+
+            TwoFactorRecoveryCodes {
+                recovery_codes,
+            }
+        }
+
+        #[instrument(skip_all, name = "TwoFactorService::disable")]
+        async fn disable(
+            &self,
+            user_id: Ulid,
+            TwoFactorCodeRequest {
+                code
+            }: TwoFactorCodeRequest,
+        ) -> () {
+            let user = self.get_enabled_user(user_id).await?;
+            let user = self.redeem_code(user, &code).await?;
+
+            self.user_repository
+                .update_by_id(
+                    user.id,
+                    UserEntityUpdate {
+                        totp_secret: Some(None),
+                        totp_enabled: Some(false),
+                        totp_recovery_codes: Some(vec![]),
+                        totp_last_step: Some(None),
+                        ..Default::default()
+                    }
+                )
+                .await?;
+        }
+    }
+}
+
+impl TwoFactorServiceImpl {
+    #[tracing::instrument(skip_all, level = "trace")]
+    async fn get_user(
+        &self,
+        user_id: Ulid,
+    ) -> TwoFactorServiceResult<UserEntity> {
+        self.user_repository
+            .find_by_id(user_id.into())
+            .await?
+            .ok_or(ServiceError::NotFound("User with provided id".into()))
+    }
+
+    #[tracing::instrument(skip_all, level = "trace")]
+    async fn get_enabled_user(
+        &self,
+        user_id: Ulid,
+    ) -> TwoFactorServiceResult<UserEntity> {
+        let user = self.get_user(user_id).await?;
+        if !user.totp_enabled {
+            Err(ServiceError::Conflict(
+                "Two-factor authentication is not enabled".into(),
+            ))?
+        }
+        Ok(user)
+    }
+
+    /// Accepts a current authenticator code or an unused recovery code and
+    /// makes sure neither can be used again.
+    #[tracing::instrument(skip_all, level = "debug")]
+    async fn redeem_code(
+        &self,
+        user: UserEntity,
+        code: &str,
+    ) -> TwoFactorServiceResult<UserEntity> {
+        let step = user.totp_secret.as_deref().and_then(|secret| {
+            totp::verify(secret, code.trim(), Self::now(), user.totp_last_step)
+        });
+        let update = match step {
+            Some(step) => UserEntityUpdate {
+                totp_last_step: Some(Some(step)),
+                ..Default::default()
+            },
+            None => {
+                let code = totp::normalize_recovery_code(code);
+                let mut recovery_codes = user.totp_recovery_codes.clone();
+                let used = recovery_codes
+                    .iter()
+                    .position(|hash| {
+                        self.password_hasher.verify(&code, hash).is_ok()
+                    })
+                    .ok_or_else(Self::invalid_code)?;
+                recovery_codes.swap_remove(used);
+                UserEntityUpdate {
+                    totp_recovery_codes: Some(recovery_codes),
+                    ..Default::default()
+                }
+            },
+        };
+
+        self.user_repository
+            .update_by_id(user.id, update)
+            .await?
+            .ok_or(ServiceError::NotFound("User with provided id".into()))
+    }
+
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn hash_recovery_codes(
+        &self,
+        recovery_codes: &[String],
+    ) -> TwoFactorServiceResult<Vec<String>> {
+        recovery_codes
+            .iter()
+            .map(|code| {
+                self.password_hasher
+                    .hash(&totp::normalize_recovery_code(code))
+                    .map_err(ServiceError::from)
+            })
+            .collect()
+    }
+
+    #[inline]
+    fn invalid_challenge() -> ServiceError {
+        ServiceError::Unauthorized(
+            "Invalid or expired two-factor challenge".into(),
+        )
+    }
+
+    #[inline]
+    fn invalid_code() -> ServiceError {
+        ServiceError::Unauthorized("Invalid two-factor code".into())
+    }
+
+    #[inline]
+    fn now() -> u64 {
+        u64::try_from(Utc::now().timestamp()).unwrap_or_default()
+    }
+}
@@ -0,0 +1,63 @@
+use dto::{
+    auth::{
+        TwoFactorCodeRequest, TwoFactorLoginRequest, TwoFactorSetupRequest,
+    },
+    session::SessionTokens,
+    two_factor::{
+        TwoFactorChallenge, TwoFactorEnrollment, TwoFactorRecoveryCodes,
+    },
+    user::User,
+};
+use macros::service;
+use ulid::Ulid;
+
+use crate::common::ServiceError;
+
+pub mod implementation;
+
+const CHALLENGE_TOKEN_KIND: &str = "two_factor";
+const CHALLENGE_TOKEN_LIFETIME: usize = 60 * 5; // 5 minutes
+const RECOVERY_CODE_COUNT: usize = 10;
+
+service! {
+    TwoFactor
+        Err: ServiceError
+    {
+        /// Issues the token that the second login step is completed with
+        /// once the password was checked.
+        async fn challenge(&self, user_id: Ulid) -> TwoFactorChallenge;
+
+        /// The user the challenge was issued to, whom failed attempts at the
+        /// second step are counted against.
+        async fn challenged_user(&self, challenge_token: &str) -> Ulid;
+
+        /// Accepts either an authenticator code or a recovery code, so losing
+        /// the authenticator doesn't lock the user out.
+        async fn login(
+            &self,
+            req: TwoFactorLoginRequest,
+        ) -> (User, SessionTokens);
+
+        /// Generates a new secret, which only takes effect once confirmed.
+        async fn enroll(
+            &self,
+            user_id: Ulid,
+            req: TwoFactorSetupRequest,
+        ) -> TwoFactorEnrollment;
+
+        async fn confirm(
+            &self,
+            user_id: Ulid,
+            req: TwoFactorCodeRequest,
+        ) -> TwoFactorRecoveryCodes;
+
+        /// Replaces every recovery code the user has left.
+        async fn regenerate_recovery_codes(
+            &self,
+            user_id: Ulid,
+            req: TwoFactorCodeRequest,
+        ) -> TwoFactorRecoveryCodes;
+
+        async fn disable(&self, user_id: Ulid, req: TwoFactorCodeRequest) -> ();
+    }
+}
@@ -1,44 +1,83 @@
+use std::collections::HashMap;
+
+use chrono::Utc;
 use dto::{
-    auth::{LoginRequest, PasswordChangeRequest},
-    user::{CreateUser, User, UserUpdate},
+    auth::{
+        EmailVerificationRequest, LoginRequest, PasswordChangeRequest,
+        PasswordResetConfirmRequest, PasswordResetRequest,
+    },
+    session::SessionTokens,
+    two_factor::LoginOutcome,
+    user::{CreateUser, User, UserRole, UserUpdate},
 };
-use entity::user::{
-    CreateUser as CreateUserEntity, User as UserEntity,
-    UserUpdate as UserEntityUpdate,
+use entity::{
+    email_verification::CreateEmailVerification,
+    mail::Mail,
+    password_reset::CreatePasswordReset,
+    user::{
+        CreateUser as CreateUserEntity, User as UserEntity,
+        UserUpdate as UserEntityUpdate,
+    },
 };
 use macros::implementation;
 use metrics::{describe_gauge, gauge};
-use repository::user::UserRepositoryDependency;
+use repository::{
+    email_verification::EmailVerificationRepositoryDependency,
+    mail::MailRepositoryDependency,
+    password_reset::PasswordResetRepositoryDependency,
+    user::UserRepositoryDependency,
+};
 use tracing::instrument;
 use ulid::Ulid;
 use utils::{
     LGTM,
-    auth::{PasswordHasher, jwt},
+    auth::{JwtKeys, PasswordHasher, jwt, token},
 };
 
 use super::{
-    DEFAULT_ADMIN_ID, USERS_BY_ROLE_COUNT_METRIC_NAME, UserService,
-    UserServiceResult,
+    DEFAULT_ADMIN_ID, EMAIL_VERIFICATION_TEMPLATE,
+    EMAIL_VERIFICATION_TOKEN_KIND, EMAIL_VERIFICATION_TOKEN_LIFETIME,
+    PASSWORD_RESET_TEMPLATE, PASSWORD_RESET_TOKEN_LIFETIME,
+    USERS_BY_ROLE_COUNT_METRIC_NAME, UserService, UserServiceResult,
+};
+use crate::{
+    common::ServiceError,
+    permission::{Actor, Permission, PermissionServiceDependency},
+    session::SessionServiceDependency,
+    two_factor::TwoFactorServiceDependency,
 };
-use crate::common::ServiceError;
 
 implementation! {
     UserService {
         user_repository: UserRepositoryDependency,
-        secret: String,
-        password_hasher: PasswordHasher<'static>
+        email_verification_repository: EmailVerificationRepositoryDependency,
+        password_reset_repository: PasswordResetRepositoryDependency,
+        mail_repository: MailRepositoryDependency,
+        session_service: SessionServiceDependency,
+        two_factor_service: TwoFactorServiceDependency,
+        permission_service: PermissionServiceDependency,
+        jwt_keys: JwtKeys,
+        password_hasher: PasswordHasher<'static>,
+        email_verification_url: String,
+        password_reset_url: String
     } as UserServiceImpl {
         #[instrument(skip_all, name = "UserService::register")]
         async fn register(
             &self,
             new: CreateUser,
-        ) -> (User, String) {
+            actor: Actor,
+        ) -> (User, SessionTokens) {
+            if matches!(new.role, UserRole::Superadmin | UserRole::Organizer) {
+                self.permission_service
+                    .require(actor, Permission::Role(&[UserRole::Superadmin]))
+                    .await?;
+            }
             if self.user_repository.exists_by_username(&new.username).await? {
                 Err(ServiceError::AlreadyExists("User with provided username".into()))?
             }
 
             let user = self.user_repository
-                .save(
+                .save_with_event(
                     CreateUserEntity {
                         email: new.email,
                         password_hash: self.password_hasher.hash(&new.password)?,
@@ -47,9 +86,9 @@ implementation! {
                     }
                 ).await?;
 
-            let token = generate_jwt(&user, &self.secret);
+            let tokens = self.start_session(&user).await?;
 
-            (user.into(), token)
+            (user.into(), tokens)
         }
 
         #[instrument(skip_all, name = "UserService::login")]
@@ -59,7 +98,7 @@ implementation! {
                 email,
                 password
             }: LoginRequest,
-        ) -> (User, String) {
+        ) -> LoginOutcome {
             let user = self.user_repository
                 .find_by_email(&email)
                 .await?
@@ -71,9 +110,183 @@ implementation! {
                 .verify(&password, &user.password_hash)
                 .map_err(|_| ServiceError::InvalidPassword)?;
 
-            let token = generate_jwt(&user, &self.secret);
+            self.complete_login(user).await?
+        }
+
+        #[instrument(skip_all, name = "UserService::send_email_verification")]
+        async fn send_email_verification(
+            &self,
+            id: Ulid,
+        ) -> () {
+            let user = self.user_repository
+                .find_by_id(id.into())
+                .await?
+                .ok_or(
+                    ServiceError::NotFound("User with provided id".into())
+                )?;
+
+            if user.email_verified {
+                Err(ServiceError::Conflict("Email is already verified".into()))?
+            }
+
+            self.send_verification_mail(&user).await?;
+        }
+
+        #[instrument(skip_all, name = "UserService::verify_email")]
+        async fn verify_email(
+            &self,
+            EmailVerificationRequest {
+                token
+            }: EmailVerificationRequest,
+        ) -> User {
+            let invalid_token = || ServiceError::BadRequest(
+                "Invalid or expired email verification token".into()
+            );
+
+            let claims = jwt::parse_for(
+                EMAIL_VERIFICATION_TOKEN_KIND,
+                &token,
+                &self.jwt_keys
+            ).ok_or_else(invalid_token)?;
+            let id = Ulid::from_string(&claims.sub)
+                .map_err(|_| invalid_token())?;
+
+            let verification = self.email_verification_repository
+                .delete_by_id(id.into())
+                .await?
+                .ok_or_else(invalid_token)?;
+
+            let user = self.user_repository
+                .find_by_id(verification.user.clone())
+                .await?
+                .ok_or(
+                    ServiceError::NotFound("User with provided id".into())
+                )?;
+            if user.email != verification.email {
+                Err(invalid_token())?
+            }
+
+            self.user_repository
+                .update_by_id(
+                    user.id,
+                    UserEntityUpdate {
+                        email_verified: Some(true),
+                        ..Default::default()
+                    }
+                )
+                .await?
+                .expect("Got unchecked user ID")
+                .into()
+        }
+
+        #[instrument(skip_all, name = "UserService::request_password_reset")]
+        async fn request_password_reset(
+            &self,
+            PasswordResetRequest {
+                email
+            }: PasswordResetRequest,
+        ) -> () {
+            let Some(user) = self.user_repository
+                .find_by_email(&email)
+                .await?
+            else {
+                return Ok(());
+            };
 
-            (user.into(), token)
+            self.password_reset_repository
+                .delete_all_by_user(user.id.clone())
+                .await?;
+
+            let secret = token::generate();
+            let reset = self.password_reset_repository
+                .save(
+                    CreatePasswordReset {
+                        user: user.id.clone(),
+                        token_hash: self.password_hasher.hash(&secret)?,
+                        expires_at: Utc::now()
+                            .checked_add_signed(PASSWORD_RESET_TOKEN_LIFETIME)
+                            .expect("Got out of range password reset expiration"),
+                    }
+                )
+                .await?;
+
+            let res = self.mail_repository
+                .send(
+                    Mail {
+                        recipient: user.email,
+                        template: PASSWORD_RESET_TEMPLATE.into(),
+                        locale: None,
+                        variables: HashMap::from([
+                            ("username".into(), user.username),
+                            (
+                                "link".into(),
+                                format!("{}?token={}.{secret}", self.password_reset_url, reset.id),
+                            ),
+                        ]),
+                    }
+                )
+                .await;
+            // The response must not reveal whether the email is registered
+            if let Err(err) = res {
+                tracing::warn!("Failed to send password reset to user {}: {err}", user.id);
+            }
+        }
+
+        #[instrument(skip_all, name = "UserService::reset_password")]
+        async fn reset_password(
+            &self,
+            PasswordResetConfirmRequest {
+                token,
+                new_password,
+                ..
+            }: PasswordResetConfirmRequest,
+        ) -> LoginOutcome {
+            let invalid_token = || ServiceError::BadRequest(
+                "Invalid or expired password reset token".into()
+            );
+
+            let (id, secret) = token
+                .split_once('.')
+                .ok_or_else(invalid_token)?;
+            let id = Ulid::from_string(id)
+                .map_err(|_| invalid_token())?;
+
+            let reset = self.password_reset_repository
+                .find_by_id(id.into())
+                .await?
+                .ok_or_else(invalid_token)?;
+            if reset.expires_at < Utc::now() {
+                Err(invalid_token())?
+            }
+            self.password_hasher
+                .verify(secret, &reset.token_hash)
+                .map_err(|_| invalid_token())?;
+
+            self.password_reset_repository
+                .delete_by_id(reset.id)
+                .await?
+                .ok_or_else(invalid_token)?;
+
+            let user = self.user_repository
+                .update_by_id(
+                    reset.user,
+                    UserEntityUpdate {
+                        password_hash: Some(self.password_hasher.hash(&new_password)?),
+                        password_changed_at: Some(Some(Utc::now())),
+                        email_verified: Some(true),
+                        ..Default::default()
+                    }
+                )
+                .await?
+                .ok_or(
+                    ServiceError::NotFound("User with provided id".into())
+                )?;
+
+            self.session_service
+                .revoke_all(user.id.clone().into())
+                .await?;
+
+            self.complete_login(user).await?
         }
 
         #[instrument(skip_all, name = "UserService::find_by_id")]
@@ -87,6 +300,29 @@ implementation! {
                 .map(User::from)
         }
 
+        #[instrument(skip_all, name = "UserService::find_by_session")]
+        async fn find_by_session(
+            &self,
+            id: Ulid,
+            session_id: Ulid,
+            issued_at: usize,
+        ) -> Option<User> {
+            if !self.session_service.is_active(session_id).await? {
+                return Ok(None);
+            }
+
+            self.user_repository
+                .find_by_id(id.into())
+                .await?
+                .filter(|user| {
+                    user.password_changed_at.is_none_or(|changed_at| {
+                        usize::try_from(changed_at.timestamp())
+                            .unwrap_or(usize::MAX) <= issued_at
+                    })
+                })
+                .map(User::from)
+        }
+
         #[instrument(skip_all, name = "UserService::get_by_id")]
         async fn get_by_id(
             &self,
@@ -105,16 +341,15 @@ implementation! {
             &self,
             id: Ulid,
             update: UserUpdate,
-            is_self: bool,
+            actor: Actor,
         ) -> User {
-            if !is_self && id.to_string() == DEFAULT_ADMIN_ID {
-                Err(ServiceError::Forbidden(
-                    "Unable to update specified user".to_string(),
-                ))?
-            }
-            if !is_self {
-                self.get_by_id(id).await?;
-            }
+            self.permission_service
+                .require(actor, Permission::ManageUser(id))
+                .await?;
+            let user = self.get_by_id(id).await?;
+            let email_changed = update.email
+                .as_ref()
+                .is_some_and(|email| email != &user.email);
             if let Some(username) = update.username.as_ref() {
                 if self.user_repository
                     .exists_by_username(username)
@@ -124,20 +359,26 @@ implementation! {
                 }
             }
 
-            self.user_repository
+            let user = self.user_repository
                 .update_by_id(
                     id.into(),
-                        UserEntityUpdate {
+                    UserEntityUpdate {
                         email: update.email,
-                        password_hash: None,
                         username: update.username,
-                        role: None,
-                        profile: None,
+                        email_verified: email_changed.then_some(false),
+                        ..Default::default()
                     }
                 )
                 .await?
-                .expect("Got unchecked self ID")
-                .into()
+                .expect("Got unchecked self ID");
+
+            if email_changed {
+                if let Err(err) = self.send_verification_mail(&user).await {
+                    tracing::warn!("Failed to send email verification to user {}: {err}", user.id);
+                }
+            }
+
+            user.into()
         }
 
         #[instrument(skip_all, name = "UserService::change_password_by_id")]
@@ -149,14 +390,11 @@ implementation! {
                 new_password,
                 ..
             }: PasswordChangeRequest,
-            is_self: bool,
-        ) -> (User, String) {
-
-            if !is_self && id.to_string() == DEFAULT_ADMIN_ID {
-                Err(ServiceError::Forbidden(
-                    "Unable to update specified user's password".to_string(),
-                ))?
-            }
+            actor: Actor,
+        ) -> (User, SessionTokens) {
+            self.permission_service
+                .require(actor, Permission::ManageUser(id))
+                .await?;
             let user = self.user_repository
                 .find_by_id(id.into())
                 .await?
@@ -164,7 +402,7 @@ implementation! {
                     ServiceError::NotFound("User with provided id".into())
                 )?;
 
-            PasswordHasher::new()
+            self.password_hasher
                 .verify(&current_password, &user.password_hash)
                 .map_err(|_| ServiceError::InvalidPassword)?;
 
@@ -172,35 +410,45 @@ implementation! {
                 .update_by_id(
                     id.into(),
                     UserEntityUpdate {
-                        password_hash: Some(new_password),
+                        password_hash: Some(self.password_hasher.hash(&new_password)?),
+                        password_changed_at: Some(Some(Utc::now())),
                         ..Default::default()
                     }
                 )
                 .await?
                 .expect("Got unchecked self ID");
 
-            let token = generate_jwt(&user, &self.secret);
+            self.session_service
+                .revoke_all(id)
+                .await?;
+            let tokens = self.start_session(&user).await?;
 
-            (user.into(), token)
+            (user.into(), tokens)
         }
 
         #[instrument(skip_all, name = "UserService::delete_by_id")]
         async fn delete_by_id(
             &self,
             id: Ulid,
-            check_user: bool,
+            actor: Actor,
         ) -> () {
             if id.to_string() == DEFAULT_ADMIN_ID {
                 Err(ServiceError::Forbidden(
                     "Unable to delete specified user".to_string(),
                 ))?
             }
-            if check_user {
+            self.permission_service
+                .require(actor, Permission::ManageUser(id))
+                .await?;
+            if actor.id != id {
                 self.get_by_id(id).await?;
             }
             self.user_repository
                 .delete_by_id(id.into())
                 .await?;
+            self.session_service
+                .revoke_all(id)
+                .await?;
         }
 
         #[instrument(skip_all, name = "UserService::init_metrics")]
@@ -226,7 +474,76 @@ implementation! {
     }
 }
 
-#[tracing::instrument(skip_all, level = "trace")]
-fn generate_jwt(user: &UserEntity, secret: &str) -> String {
-    jwt::new(&user.role.to_string(), user.id.clone().into(), secret)
+impl UserServiceImpl {
+    /// Starts a session once the password was checked, unless the user
+    /// still has to pass two-factor authentication.
+    #[tracing::instrument(skip_all, level = "trace")]
+    async fn complete_login(
+        &self,
+        user: UserEntity,
+    ) -> UserServiceResult<LoginOutcome> {
+        if user.totp_enabled {
+            let challenge =
+                self.two_factor_service.challenge(user.id.into()).await?;
+            return Ok(LoginOutcome::TwoFactorRequired(challenge));
+        }
+        let tokens = self.start_session(&user).await?;
+        Ok(LoginOutcome::Authenticated(user.into(), tokens))
+    }
+
+    #[tracing::instrument(skip_all, level = "trace")]
+    async fn start_session(
+        &self,
+        user: &UserEntity,
+    ) -> UserServiceResult<SessionTokens> {
+        self.session_service
+            .start(user.id.clone().into(), user.role.into())
+            .await
+    }
+
+    /// Replaces any pending verification of the user with a new one and
+    /// mails its token to the user's current email.
+    #[tracing::instrument(skip_all, level = "debug")]
+    async fn send_verification_mail(
+        &self,
+        user: &UserEntity,
+    ) -> Result<(), ServiceError> {
+        self.email_verification_repository
+            .delete_all_by_user(user.id.clone())
+            .await?;
+        let verification = self
+            .email_verification_repository
+            .save(CreateEmailVerification {
+                user: user.id.clone(),
+                email: user.email.clone(),
+            })
+            .await?;
+
+        let token = jwt::new_with_lifetime(
+            EMAIL_VERIFICATION_TOKEN_KIND,
+            verification.id.into(),
+            &self.jwt_keys,
+            EMAIL_VERIFICATION_TOKEN_LIFETIME,
+        );
+
+        self.mail_repository
+            .send(Mail {
+                recipient: user.email.clone(),
+                template: EMAIL_VERIFICATION_TEMPLATE.into(),
+                locale: None,
+                variables: HashMap::from([
+                    ("username".into(), user.username.clone()),
+                    (
+                        "link".into(),
+                        format!(
+                            "{}?token={token}",
+                            self.email_verification_url
+                        ),
+                    ),
+                ]),
+            })
+            .await?;
+
+        Ok(())
+    }
 }
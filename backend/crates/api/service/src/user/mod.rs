@@ -1,43 +1,81 @@
+use chrono::TimeDelta;
 use dto::{
-    auth::{LoginRequest, PasswordChangeRequest},
+    auth::{
+        EmailVerificationRequest, LoginRequest, PasswordChangeRequest,
+        PasswordResetConfirmRequest, PasswordResetRequest,
+    },
+    session::SessionTokens,
+    two_factor::LoginOutcome,
     user::{CreateUser, User, UserUpdate},
 };
 use macros::{metric_name, service};
 use ulid::Ulid;
 
-use crate::common::ServiceError;
+use crate::{common::ServiceError, permission::Actor};
 
 pub mod implementation;
 
-const DEFAULT_ADMIN_ID: &str = "0000000000000000000000000A";
+pub(crate) const DEFAULT_ADMIN_ID: &str = "0000000000000000000000000A";
+
+const EMAIL_VERIFICATION_TOKEN_KIND: &str = "email_verification";
+const EMAIL_VERIFICATION_TOKEN_LIFETIME: usize = 60 * 60 * 24; // 1 day
+const EMAIL_VERIFICATION_TEMPLATE: &str = "email_verification";
+
+const PASSWORD_RESET_TOKEN_LIFETIME: TimeDelta = TimeDelta::hours(1);
+const PASSWORD_RESET_TEMPLATE: &str = "password_reset";
 
 service! {
     User
         Err: ServiceError
     {
-        async fn register(&self, new: CreateUser) -> (User, String);
+        /// Only superadmins can register organizers and other superadmins.
+        async fn register(
+            &self,
+            new: CreateUser,
+            actor: Actor,
+        ) -> (User, SessionTokens);
+
+        /// Users with two-factor authentication get a challenge to complete
+        /// instead of a session.
+        async fn login(&self, req: LoginRequest) -> LoginOutcome;
+
+        async fn send_email_verification(&self, id: Ulid) -> ();
+
+        async fn verify_email(&self, req: EmailVerificationRequest) -> User;
 
-        async fn login(&self, req: LoginRequest) -> (User, String);
+        async fn request_password_reset(&self, req: PasswordResetRequest) -> ();
+
+        async fn reset_password(
+            &self,
+            req: PasswordResetConfirmRequest
+        ) -> LoginOutcome;
 
         async fn find_by_id(&self, id: Ulid) -> Option<User>;
 
+        async fn find_by_session(
+            &self,
+            id: Ulid,
+            session_id: Ulid,
+            issued_at: usize
+        ) -> Option<User>;
+
         async fn get_by_id(&self, id: Ulid) -> User;
 
         async fn update_by_id(
             &self,
             id: Ulid,
             update: UserUpdate,
-            is_self: bool
+            actor: Actor
         ) -> User;
 
         async fn change_password_by_id(
             &self,
             id: Ulid,
             req: PasswordChangeRequest,
-            is_self: bool
-        ) -> (User, String);
+            actor: Actor
+        ) -> (User, SessionTokens);
 
-        async fn delete_by_id(&self, id: Ulid, check_user: bool) -> ();
+        async fn delete_by_id(&self, id: Ulid, actor: Actor) -> ();
 
         async fn init_metrics(&self);
     }
@@ -1,31 +1,86 @@
-use std::sync::Arc;
+use std::{net::SocketAddr, sync::Arc, time::Duration};
 
 use ::utils::{
     LGTM,
     adapters::{S3, SurrealPool},
-    auth::PasswordHasher,
+    auth::{JwtKeys, PasswordHasher},
     logger::CustomActixLogger,
+    rate_limit::{LockoutPolicy, MemoryRateLimitStore, Quota},
 };
 use actix_web::{
     App, HttpServer,
+    middleware::from_fn,
     web::{Data, FormConfig, JsonConfig, PathConfig, QueryConfig, get},
 };
 use actix_web_lab::middleware::CatchPanic;
 use actix_web_validation::garde::GardeErrorHandlerExt;
 use env_vars_config::env_vars_config;
+use grpc::{telegram::TelegramGrpc, user::UserGrpc};
 use handler::{
-    common::wrapper::{BaseApiUrl, JwtSecret},
+    application::{ApplicationHandler, implementation::ApplicationHandlerImpl},
+    common::{
+        middleware::{RateLimiter, rate_limit_middleware},
+        wrapper::{
+            BaseApiUrl, RequireOrganizerTwoFactor, RequireVerifiedEmail,
+        },
+    },
     info::{InfoHandler, implementation::InfoHandlerImpl},
+    mentor::{MentorHandler, implementation::MentorHandlerImpl},
     profile::{ProfileHandler, implementation::ProfileHandlerImpl},
+    recommendation::{
+        RecommendationHandler, implementation::RecommendationHandlerImpl,
+    },
     review::{ReviewHandler, implementation::ReviewHandlerImpl},
+    skill::{SkillHandler, implementation::SkillHandlerImpl},
+    specialization::{
+        SpecializationHandler, implementation::SpecializationHandlerImpl,
+    },
+    team::{TeamHandler, implementation::TeamHandlerImpl},
+    technology::{TechnologyHandler, implementation::TechnologyHandlerImpl},
+    telegram::{TelegramHandler, implementation::TelegramHandlerImpl},
+    tour::{TourHandler, implementation::TourHandlerImpl},
+    tour_result::{TourResultHandler, implementation::TourResultHandlerImpl},
     user::{UserHandler, implementation::UserHandlerImpl},
 };
+use proto::{auth::TokenInterceptor, propagation};
 use repository::{
-    image::s3::S3ImageRepository, profile::surreal::SurrealProfileRepository,
+    applied_to_join::surreal::SurrealAppliedToJoinRepository,
+    email_verification::surreal::SurrealEmailVerificationRepository,
+    has_experience_as::surreal::SurrealHasExperienceAsRepository,
+    image::s3::S3ImageRepository, knows::surreal::SurrealKnowsRepository,
+    mail::grpc::GrpcMailRepository,
+    member_of::surreal::SurrealMemberOfRepository,
+    mentors::surreal::SurrealMentorsRepository,
+    mentors_in::surreal::SurrealMentorsInRepository,
+    notification::grpc::GrpcNotificationRepository,
+    notification_settings::surreal::SurrealNotificationSettingsRepository,
+    organizes::surreal::SurrealOrganizesRepository,
+    outbox_event::surreal::SurrealOutboxEventRepository,
+    participated_in::surreal::SurrealParticipatedInRepository,
+    password_reset::surreal::SurrealPasswordResetRepository,
+    profile::surreal::SurrealProfileRepository,
     reviewed::surreal::SurrealReviewedRepository,
-    user::surreal::SurrealUserRepository,
+    session::surreal::SurrealSessionRepository,
+    specialization::surreal::SurrealSpecializationRepository,
+    team::surreal::SurrealTeamRepository,
+    technology::surreal::SurrealTechnologyRepository,
+    telegram_link::surreal::SurrealTelegramLinkRepository,
+    tour::surreal::SurrealTourRepository, user::surreal::SurrealUserRepository,
+    uses::surreal::SurrealUsesRepository,
 };
 use service::{
+    application::{
+        ApplicationServiceDependency, implementation::ApplicationServiceImpl,
+    },
+    mentor::{MentorServiceDependency, implementation::MentorServiceImpl},
+    notification::{
+        NotificationServiceDependency, implementation::NotificationServiceImpl,
+    },
+    outbox::{
+        OutboxService, OutboxServiceDependency,
+        implementation::OutboxServiceImpl,
+    },
+    permission::implementation::PermissionServiceImpl,
     profile::{
         ProfileService, ProfileServiceDependency,
         implementation::ProfileServiceImpl,
@@ -33,14 +88,42 @@ use service::{
     profile_image::{
         ProfileImageServiceDependency, implementation::ProfileImageServiceImpl,
     },
+    recommendation::{
+        RecommendationServiceDependency,
+        implementation::RecommendationServiceImpl,
+    },
     review::{
         ReviewService, ReviewServiceDependency,
         implementation::ReviewServiceImpl,
     },
+    session::{SessionServiceDependency, implementation::SessionServiceImpl},
+    skill::{SkillServiceDependency, implementation::SkillServiceImpl},
+    specialization::{
+        SpecializationServiceDependency,
+        implementation::SpecializationServiceImpl,
+    },
+    team::{TeamServiceDependency, implementation::TeamServiceImpl},
+    technology::{
+        TechnologyServiceDependency, implementation::TechnologyServiceImpl,
+    },
+    telegram::{
+        TelegramServiceDependency, implementation::TelegramServiceImpl,
+    },
+    tour::{TourServiceDependency, implementation::TourServiceImpl},
+    tour_result::{
+        TourResultServiceDependency, implementation::TourResultServiceImpl,
+    },
+    tour_staff::{
+        TourStaffServiceDependency, implementation::TourStaffServiceImpl,
+    },
+    two_factor::{
+        TwoFactorServiceDependency, implementation::TwoFactorServiceImpl,
+    },
     user::{
         UserService, UserServiceDependency, implementation::UserServiceImpl,
     },
 };
+use tonic::transport::Server;
 use utils::{OpenApi, cors::default_cors, validation};
 use utoipa::{OpenApi as _, openapi::OpenApi as OpenApiStruct};
 use utoipa_actix_web::{AppExt, service_config::ServiceConfig};
@@ -55,7 +138,8 @@ env_vars_config! {
     DB_USER: String = "root",
     DB_PASSWORD: String = "root",
     DB_MAX_POOL_SIZE: u64 = 16u64,
-    JWT_SECRET: String = "P9mzO6aO64hgkVCBN96CfpUXB1x58XA3zmGuoT4HjSdhHgyRBnqv/EsPDCfs9CRT/oEJYSu6YDcvmdrf/utDNQ==",
+    JWT_SIGNING_KEY: String = "",
+    JWT_VERIFICATION_KEYS: String = "",
     METRICS_ADDRESS: String = "0.0.0.0:8081",
     OTEL_ENDPOINT: String = "http://localhost:4317",
     S3_BASE_URL: String = "http://localhost:9000",
@@ -64,14 +148,53 @@ env_vars_config! {
     S3_REGION: String = "custom",
     DEPLOY_DOMAIN: String = "localhost",
     BASE_API_URL: String = "http://localhost:8080",
+    MAIL_SERVICE_ADDRESS: String = "http://localhost:50051",
+    EMAIL_VERIFICATION_URL: String = "http://localhost:5173/verify-email",
+    PASSWORD_RESET_URL: String = "http://localhost:5173/reset-password",
+    REQUIRE_VERIFIED_EMAIL: bool = false,
+    REQUIRE_ORGANIZER_TWO_FACTOR: bool = false,
+    TOTP_ISSUER: String = "Minizord",
+    GRPC_ADDRESS: String = "0.0.0.0:50052",
+    INTERNAL_GRPC_TOKEN: String = "",
+    TELEGRAM_BOT_USERNAME: String = "minizord_bot",
+    TELEGRAM_SERVICE_ADDRESS: String = "http://localhost:50053",
+    TOUR_REMINDER_INTERVAL_SECONDS: u64 = 600u64,
+    OUTBOX_DISPATCH_INTERVAL_SECONDS: u64 = 5u64,
+    SESSION_PURGE_INTERVAL_SECONDS: u64 = 3600u64,
+    RATE_LIMIT_AUTH_BURST: u32 = 10u32,
+    RATE_LIMIT_AUTH_PERIOD_SECONDS: u64 = 60u64,
+    RATE_LIMIT_API_BURST: u32 = 300u32,
+    RATE_LIMIT_API_PERIOD_SECONDS: u64 = 60u64,
+    RATE_LIMIT_TRUST_PROXY: bool = false,
+    LOGIN_LOCKOUT_ACCOUNT_THRESHOLD: u32 = 5u32,
+    LOGIN_LOCKOUT_IP_THRESHOLD: u32 = 20u32,
+    LOGIN_LOCKOUT_BASE_SECONDS: u64 = 30u64,
+    LOGIN_LOCKOUT_MAX_SECONDS: u64 = 3600u64,
+    LOGIN_LOCKOUT_RESET_SECONDS: u64 = 86400u64,
 }
 
 #[derive(Clone)]
 struct AppConfig {
+    jwt_keys: JwtKeys,
+    rate_limiter: RateLimiter,
     user_service: UserServiceDependency,
+    session_service: SessionServiceDependency,
+    two_factor_service: TwoFactorServiceDependency,
     profile_service: ProfileServiceDependency,
     profile_image_service: ProfileImageServiceDependency,
     review_service: ReviewServiceDependency,
+    team_service: TeamServiceDependency,
+    tour_service: TourServiceDependency,
+    tour_staff_service: TourStaffServiceDependency,
+    application_service: ApplicationServiceDependency,
+    specialization_service: SpecializationServiceDependency,
+    technology_service: TechnologyServiceDependency,
+    skill_service: SkillServiceDependency,
+    mentor_service: MentorServiceDependency,
+    tour_result_service: TourResultServiceDependency,
+    recommendation_service: RecommendationServiceDependency,
+    telegram_service: TelegramServiceDependency,
+    notification_service: NotificationServiceDependency,
 }
 impl AppConfig {
     #[tracing::instrument(skip_all, level = "trace")]
@@ -85,14 +208,46 @@ impl AppConfig {
                 QueryConfig::default().error_handler(handler::input_error),
             )
             .app_data(JsonConfig::default().error_handler(handler::input_error))
-            .app_data(Data::new(JwtSecret(config::JWT_SECRET.to_owned())))
+            .app_data(Data::new(self.jwt_keys))
+            .app_data(Data::new(self.rate_limiter))
             .app_data(Data::new(BaseApiUrl(config::BASE_API_URL.to_owned())))
-            .configure(UserHandlerImpl::routes(self.user_service))
+            .app_data(Data::new(RequireVerifiedEmail(
+                *config::REQUIRE_VERIFIED_EMAIL,
+            )))
+            .app_data(Data::new(RequireOrganizerTwoFactor(
+                *config::REQUIRE_ORGANIZER_TWO_FACTOR,
+            )))
+            .configure(UserHandlerImpl::routes(
+                self.user_service,
+                self.session_service,
+                self.two_factor_service,
+            ))
             .configure(ProfileHandlerImpl::routes(
                 self.profile_service,
                 self.profile_image_service,
             ))
             .configure(ReviewHandlerImpl::routes(self.review_service))
+            .configure(TeamHandlerImpl::routes(self.team_service.clone()))
+            .configure(TourHandlerImpl::routes(
+                self.tour_service,
+                self.tour_staff_service,
+                self.team_service,
+            ))
+            .configure(ApplicationHandlerImpl::routes(self.application_service))
+            .configure(SpecializationHandlerImpl::routes(
+                self.specialization_service,
+            ))
+            .configure(TechnologyHandlerImpl::routes(self.technology_service))
+            .configure(SkillHandlerImpl::routes(self.skill_service))
+            .configure(MentorHandlerImpl::routes(self.mentor_service))
+            .configure(TourResultHandlerImpl::routes(self.tour_result_service))
+            .configure(RecommendationHandlerImpl::routes(
+                self.recommendation_service,
+            ))
+            .configure(TelegramHandlerImpl::routes(
+                self.telegram_service,
+                self.notification_service,
+            ))
             .configure(InfoHandlerImpl::routes())
             .default_service(get().to(handler::not_found));
         }
@@ -101,60 +256,299 @@ impl AppConfig {
 
 pub struct Api {
     config: AppConfig,
+    outbox_service: OutboxServiceDependency,
     openapi: OpenApiStruct,
     lgtm: LGTM,
 }
 impl Api {
     #[tracing::instrument(skip_all, level = "trace")]
     pub async fn setup(lgtm: LGTM, db: SurrealPool, s3: S3) -> Self {
+        assert!(
+            !config::INTERNAL_GRPC_TOKEN.is_empty(),
+            "INTERNAL_GRPC_TOKEN must be set"
+        );
+
         let user_repository = SurrealUserRepository::new(db.clone());
+        let email_verification_repository =
+            SurrealEmailVerificationRepository::new(db.clone());
+        let password_reset_repository =
+            SurrealPasswordResetRepository::new(db.clone());
+        let session_repository = SurrealSessionRepository::new(db.clone());
+        let mail_repository = GrpcMailRepository::connect_lazy(
+            config::MAIL_SERVICE_ADDRESS.clone(),
+            &config::INTERNAL_GRPC_TOKEN,
+        )
+        .expect("Got invalid mail service client configuration");
+        let notification_repository = GrpcNotificationRepository::connect_lazy(
+            config::TELEGRAM_SERVICE_ADDRESS.clone(),
+            &config::INTERNAL_GRPC_TOKEN,
+        )
+        .expect("Got invalid Telegram service client configuration");
+        let notification_settings_repository =
+            SurrealNotificationSettingsRepository::new(db.clone());
         let profile_repository = SurrealProfileRepository::new(db.clone());
         let image_repository = S3ImageRepository::new(s3.clone());
         let reviewed_repository = SurrealReviewedRepository::new(db.clone());
+        let tour_repository = SurrealTourRepository::new(db.clone());
+        let team_repository = SurrealTeamRepository::new(db.clone());
+        let specialization_repository =
+            SurrealSpecializationRepository::new(db.clone());
+        let member_of_repository = SurrealMemberOfRepository::new(db.clone());
+        let applied_to_join_repository =
+            SurrealAppliedToJoinRepository::new(db.clone());
+        let has_experience_as_repository =
+            SurrealHasExperienceAsRepository::new(db.clone());
+        let technology_repository =
+            SurrealTechnologyRepository::new(db.clone());
+        let knows_repository = SurrealKnowsRepository::new(db.clone());
+        let uses_repository = SurrealUsesRepository::new(db.clone());
+        let mentors_repository = SurrealMentorsRepository::new(db.clone());
+        let mentors_in_repository = SurrealMentorsInRepository::new(db.clone());
+        let organizes_repository = SurrealOrganizesRepository::new(db.clone());
+        let participated_in_repository =
+            SurrealParticipatedInRepository::new(db.clone());
+        let telegram_link_repository =
+            SurrealTelegramLinkRepository::new(db.clone());
+        let outbox_event_repository =
+            SurrealOutboxEventRepository::new(db.clone());
 
         let password_hasher = PasswordHasher::new();
+        let jwt_keys = if config::JWT_SIGNING_KEY.is_empty() {
+            tracing::warn!(
+                "JWT_SIGNING_KEY is not set, signing tokens with an ephemeral key"
+            );
+            JwtKeys::generate()
+        } else {
+            JwtKeys::from_pem(
+                &config::JWT_SIGNING_KEY,
+                &config::JWT_VERIFICATION_KEYS,
+            )
+            .expect("Got invalid JWT_SIGNING_KEY or JWT_VERIFICATION_KEYS")
+        };
 
+        let rate_limiter = Self::rate_limiter();
+
+        let permission_service = PermissionServiceImpl::new(
+            team_repository.clone(),
+            mentors_repository.clone(),
+            organizes_repository.clone(),
+        );
+        let session_service = SessionServiceImpl::new(
+            session_repository.clone(),
+            user_repository.clone(),
+            jwt_keys.clone(),
+            password_hasher.clone(),
+        );
+        let two_factor_service = TwoFactorServiceImpl::new(
+            user_repository.clone(),
+            session_service.clone(),
+            jwt_keys.clone(),
+            password_hasher.clone(),
+            config::TOTP_ISSUER.clone(),
+        );
         let user_service = UserServiceImpl::new(
             user_repository.clone(),
-            config::JWT_SECRET.clone(),
+            email_verification_repository.clone(),
+            password_reset_repository.clone(),
+            mail_repository.clone(),
+            session_service.clone(),
+            two_factor_service.clone(),
+            permission_service.clone(),
+            jwt_keys.clone(),
             password_hasher.clone(),
+            config::EMAIL_VERIFICATION_URL.clone(),
+            config::PASSWORD_RESET_URL.clone(),
+        );
+        let notification_service = NotificationServiceImpl::new(
+            notification_repository.clone(),
+            notification_settings_repository.clone(),
+            user_repository.clone(),
+            tour_repository.clone(),
+            team_repository.clone(),
+            member_of_repository.clone(),
         );
         let profile_service = ProfileServiceImpl::new(
             user_repository.clone(),
             profile_repository.clone(),
             user_service.clone(),
+            permission_service.clone(),
         );
         let profile_image_service = ProfileImageServiceImpl::new(
             image_repository.clone(),
             profile_service.clone(),
+            permission_service.clone(),
         );
         let review_service = ReviewServiceImpl::new(
             reviewed_repository.clone(),
             user_service.clone(),
+            permission_service.clone(),
+        );
+        let team_service = TeamServiceImpl::new(
+            team_repository.clone(),
+            tour_repository.clone(),
+            member_of_repository.clone(),
+            specialization_repository.clone(),
+            uses_repository.clone(),
+            technology_repository.clone(),
+            permission_service.clone(),
+        );
+        let tour_service = TourServiceImpl::new(
+            tour_repository.clone(),
+            team_repository.clone(),
+            specialization_repository.clone(),
+            permission_service.clone(),
+        );
+        let tour_staff_service = TourStaffServiceImpl::new(
+            organizes_repository.clone(),
+            mentors_in_repository.clone(),
+            tour_repository.clone(),
+            user_service.clone(),
+            permission_service.clone(),
+        );
+        let application_service = ApplicationServiceImpl::new(
+            applied_to_join_repository.clone(),
+            member_of_repository.clone(),
+            team_repository.clone(),
+            tour_repository.clone(),
+            specialization_repository.clone(),
+            permission_service.clone(),
+        );
+        let specialization_service = SpecializationServiceImpl::new(
+            specialization_repository.clone(),
+            tour_repository.clone(),
+            member_of_repository.clone(),
+            applied_to_join_repository.clone(),
+            has_experience_as_repository.clone(),
+            participated_in_repository.clone(),
+        );
+        let technology_service = TechnologyServiceImpl::new(
+            technology_repository.clone(),
+            knows_repository.clone(),
+            uses_repository.clone(),
+            participated_in_repository.clone(),
+        );
+        let skill_service = SkillServiceImpl::new(
+            knows_repository.clone(),
+            has_experience_as_repository.clone(),
+            technology_repository.clone(),
+            specialization_repository.clone(),
+            user_service.clone(),
+        );
+        let mentor_service = MentorServiceImpl::new(
+            mentors_repository.clone(),
+            team_repository.clone(),
+            tour_repository.clone(),
+            user_service.clone(),
+            mentors_in_repository.clone(),
+            permission_service.clone(),
+        );
+        let tour_result_service = TourResultServiceImpl::new(
+            participated_in_repository.clone(),
+            tour_repository.clone(),
+            team_repository.clone(),
+            member_of_repository.clone(),
+            specialization_repository.clone(),
+            technology_repository.clone(),
+            user_service.clone(),
+        );
+        let recommendation_service = RecommendationServiceImpl::new(
+            team_repository.clone(),
+            tour_repository.clone(),
+            member_of_repository.clone(),
+            uses_repository.clone(),
+            user_repository.clone(),
+            permission_service.clone(),
+        );
+        let telegram_service = TelegramServiceImpl::new(
+            telegram_link_repository.clone(),
+            user_repository.clone(),
+            profile_repository.clone(),
+            config::TELEGRAM_BOT_USERNAME.clone(),
+        );
+        let outbox_service = OutboxServiceImpl::new(
+            outbox_event_repository.clone(),
+            team_repository.clone(),
+            user_service.clone(),
+            notification_service.clone(),
         );
 
         user_service.init_metrics().await;
         profile_service.init_metrics().await;
         review_service.init_metrics().await;
+        outbox_service.init_metrics().await;
 
         Self {
             config: AppConfig {
+                jwt_keys,
+                rate_limiter,
                 user_service,
+                session_service,
+                two_factor_service,
                 profile_service,
                 profile_image_service,
                 review_service,
+                team_service,
+                tour_service,
+                tour_staff_service,
+                application_service,
+                specialization_service,
+                technology_service,
+                skill_service,
+                mentor_service,
+                tour_result_service,
+                recommendation_service,
+                telegram_service,
+                notification_service,
             },
+            outbox_service,
             openapi: OpenApi::openapi(),
             lgtm,
         }
     }
 
     pub async fn run(self) -> std::io::Result<()> {
+        tracing::info!("Starting the gRPC server");
+
+        let grpc_address: SocketAddr = config::GRPC_ADDRESS
+            .parse()
+            .expect("Got invalid GRPC_ADDRESS");
+        let interceptor = TokenInterceptor::new(&config::INTERNAL_GRPC_TOKEN);
+        let grpc = Server::builder()
+            .trace_fn(propagation::server_span)
+            .add_service(UserGrpc::server(
+                self.config.user_service.clone(),
+                self.config.telegram_service.clone(),
+                interceptor.clone(),
+            ))
+            .add_service(TelegramGrpc::server(
+                self.config.telegram_service.clone(),
+                self.config.user_service.clone(),
+                self.config.tour_service.clone(),
+                self.config.team_service.clone(),
+                self.config.specialization_service.clone(),
+                self.config.application_service.clone(),
+                interceptor,
+            ))
+            .serve_with_shutdown(grpc_address, async {
+                tokio::signal::ctrl_c()
+                    .await
+                    .expect("Failed to listen for the shutdown signal");
+            });
+
+        let reminders = tokio::spawn(Self::send_tour_reminders(
+            self.config.notification_service.clone(),
+        ));
+        let outbox = tokio::spawn(Self::dispatch_outbox(self.outbox_service));
+        let sessions = tokio::spawn(Self::purge_sessions(
+            self.config.session_service.clone(),
+        ));
+
         tracing::info!("Starting the web server");
 
-        HttpServer::new(move || {
+        let http = HttpServer::new(move || {
             App::new()
                 .garde_error_handler(Arc::new(validation::error_handler))
+                .wrap(from_fn(rate_limit_middleware))
                 .wrap(self.lgtm.metrics_middleware())
                 .wrap(CatchPanic::default())
                 .wrap(default_cors())
@@ -167,10 +561,87 @@ impl Api {
                 .into_app()
         })
         .bind(config::SERVER_ADDRESS.clone())?
-        .run()
-        .await?;
+        .run();
+
+        let (http, grpc) = tokio::join!(http, grpc);
+        reminders.abort();
+        outbox.abort();
+        sessions.abort();
+        http?;
+        grpc.map_err(std::io::Error::other)?;
 
-        tracing::info!("Shutting down the web server");
+        tracing::info!("Shutting down the web and gRPC servers");
         Ok(())
     }
+
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn rate_limiter() -> RateLimiter {
+        let lockout = |max_attempts| LockoutPolicy {
+            max_attempts,
+            base: Duration::from_secs(*config::LOGIN_LOCKOUT_BASE_SECONDS),
+            max: Duration::from_secs(*config::LOGIN_LOCKOUT_MAX_SECONDS),
+            reset_after: Duration::from_secs(
+                *config::LOGIN_LOCKOUT_RESET_SECONDS,
+            ),
+        };
+        RateLimiter::new(
+            MemoryRateLimitStore::new(),
+            Quota {
+                burst: *config::RATE_LIMIT_AUTH_BURST,
+                period: Duration::from_secs(
+                    *config::RATE_LIMIT_AUTH_PERIOD_SECONDS,
+                ),
+            },
+            Quota {
+                burst: *config::RATE_LIMIT_API_BURST,
+                period: Duration::from_secs(
+                    *config::RATE_LIMIT_API_PERIOD_SECONDS,
+                ),
+            },
+            lockout(*config::LOGIN_LOCKOUT_ACCOUNT_THRESHOLD),
+            lockout(*config::LOGIN_LOCKOUT_IP_THRESHOLD),
+            *config::RATE_LIMIT_TRUST_PROXY,
+        )
+    }
+
+    #[tracing::instrument(skip_all, level = "debug")]
+    async fn send_tour_reminders(
+        notification_service: NotificationServiceDependency,
+    ) {
+        let mut interval = tokio::time::interval(Duration::from_secs(
+            *config::TOUR_REMINDER_INTERVAL_SECONDS,
+        ));
+        loop {
+            interval.tick().await;
+            if let Err(err) = notification_service.send_tour_reminders().await {
+                tracing::warn!("Failed to send tour reminders: {err}");
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, level = "debug")]
+    async fn dispatch_outbox(outbox_service: OutboxServiceDependency) {
+        let mut interval = tokio::time::interval(Duration::from_secs(
+            *config::OUTBOX_DISPATCH_INTERVAL_SECONDS,
+        ));
+        loop {
+            interval.tick().await;
+            if let Err(err) = outbox_service.dispatch().await {
+                tracing::warn!("Failed to dispatch outbox events: {err}");
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, level = "debug")]
+    async fn purge_sessions(session_service: SessionServiceDependency) {
+        let mut interval = tokio::time::interval(Duration::from_secs(
+            *config::SESSION_PURGE_INTERVAL_SECONDS,
+        ));
+        loop {
+            interval.tick().await;
+            if let Err(err) = session_service.purge().await {
+                tracing::warn!("Failed to purge stale sessions: {err}");
+            }
+        }
+    }
 }
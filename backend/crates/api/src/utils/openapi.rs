@@ -37,6 +37,46 @@ use crate::config;
             name = "Reviews",
             description = "Review-related endpoints"
         ),
+        (
+            name = "Teams",
+            description = "Team-related endpoints"
+        ),
+        (
+            name = "Tours",
+            description = "Tour-related endpoints"
+        ),
+        (
+            name = "Applications",
+            description = "Team application-related endpoints"
+        ),
+        (
+            name = "Specializations",
+            description = "Specialization catalog endpoints"
+        ),
+        (
+            name = "Technologies",
+            description = "Technology catalog endpoints"
+        ),
+        (
+            name = "Skills",
+            description = "User skill-related endpoints"
+        ),
+        (
+            name = "Mentors",
+            description = "Mentor assignment endpoints"
+        ),
+        (
+            name = "Results",
+            description = "Tour result and leaderboard endpoints"
+        ),
+        (
+            name = "Recommendations",
+            description = "Teammate recommendation endpoints"
+        ),
+        (
+            name = "Telegram",
+            description = "Telegram account linking and notification endpoints"
+        ),
     ),
     nest(
         (
@@ -59,6 +99,56 @@ use crate::config;
             api = handler::review::implementation::OpenApi,
             tags = ["Reviews"]
         ),
+        (
+            path = "/teams",
+            api = handler::team::implementation::OpenApi,
+            tags = ["Teams"]
+        ),
+        (
+            path = "/tours",
+            api = handler::tour::implementation::OpenApi,
+            tags = ["Tours"]
+        ),
+        (
+            path = "/applications",
+            api = handler::application::implementation::OpenApi,
+            tags = ["Applications"]
+        ),
+        (
+            path = "/specializations",
+            api = handler::specialization::implementation::OpenApi,
+            tags = ["Specializations"]
+        ),
+        (
+            path = "/technologies",
+            api = handler::technology::implementation::OpenApi,
+            tags = ["Technologies"]
+        ),
+        (
+            path = "/skills",
+            api = handler::skill::implementation::OpenApi,
+            tags = ["Skills"]
+        ),
+        (
+            path = "/mentors",
+            api = handler::mentor::implementation::OpenApi,
+            tags = ["Mentors"]
+        ),
+        (
+            path = "/results",
+            api = handler::tour_result::implementation::OpenApi,
+            tags = ["Results"]
+        ),
+        (
+            path = "/recommendations",
+            api = handler::recommendation::implementation::OpenApi,
+            tags = ["Recommendations"]
+        ),
+        (
+            path = "/telegram",
+            api = handler::telegram::implementation::OpenApi,
+            tags = ["Telegram"]
+        ),
     ),
     modifiers(
         &ServerModifier,
@@ -111,6 +201,7 @@ impl Modify for SecurityModifier {
                 .build(),
         );
 
+        components.add_security_scheme("superadmin", scheme.clone());
         components.add_security_scheme("organizer", scheme.clone());
         components.add_security_scheme("mentor", scheme.clone());
         components.add_security_scheme("participant", scheme.clone());
@@ -136,7 +136,7 @@ macro_rules! crud_repository {
                 $(
                     $(
                         $(#[$fn_meta])*
-                        async fn $method $sig -> [<$name RepositoryResult>]<$res>;
+                        async fn $method $sig -> [<$name:camel RepositoryResult>]<$res>;
                     )*
                 )?
             }
@@ -189,7 +189,7 @@ macro_rules! urd_repository {
                 $(
                     $(
                         $(#[$fn_meta])*
-                        async fn $method $sig -> [<$name RepositoryResult>]<$res>;
+                        async fn $method $sig -> [<$name:camel RepositoryResult>]<$res>;
                     )*
                 )?
             }
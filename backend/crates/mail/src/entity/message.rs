@@ -0,0 +1,63 @@
+use chrono::{DateTime, Utc};
+use macros::entity;
+use serde::{Deserialize, Serialize};
+use ulid::Ulid;
+
+#[derive(Deserialize, Serialize, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum DeliveryStatus {
+    Pending,
+    Sent,
+    Failed,
+}
+
+entity! {
+    Message {
+        id: Ulid,
+        fields {
+            recipient: String,
+            template: String,
+            locale: String,
+            subject: String,
+            text_body: String,
+            html_body: String,
+            status: DeliveryStatus,
+            error: Option<String>,
+            created_at: DateTime<Utc>,
+            updated_at: DateTime<Utc>,
+        },
+        create {
+            recipient: String,
+            template: String,
+            locale: String,
+            subject: String,
+            text_body: String,
+            html_body: String,
+        },
+        update {
+            status: DeliveryStatus,
+            error: String,
+            updated_at: DateTime<Utc>,
+        }
+    }
+}
+
+impl From<CreateMessage> for Message {
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn from(create_entity: CreateMessage) -> Self {
+        let now = Utc::now();
+        Self {
+            id: MessageId::from(Ulid::new()),
+            recipient: create_entity.recipient,
+            template: create_entity.template,
+            locale: create_entity.locale,
+            subject: create_entity.subject,
+            text_body: create_entity.text_body,
+            html_body: create_entity.html_body,
+            status: DeliveryStatus::Pending,
+            error: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use std::str::FromStr;
+
+use proto::{
+    auth::TokenInterceptor,
+    mail::{
+        DeliveryStatus as ProtoDeliveryStatus, GetDeliveryStatusRequest,
+        GetDeliveryStatusResponse, SendTemplatedRequest, SendTemplatedResponse,
+        mail_service_server::{self, MailServiceServer},
+    },
+};
+use tonic::{Request, Response, Status, codegen::InterceptedService};
+use ulid::Ulid;
+
+use crate::{
+    entity::message::{DeliveryStatus, Message},
+    service::{
+        ServiceError,
+        mail::{MailService as _, MailServiceDependency},
+    },
+};
+
+pub struct MailGrpc {
+    mail_service: MailServiceDependency,
+}
+impl MailGrpc {
+    #[tracing::instrument(skip_all, level = "trace")]
+    pub fn server(
+        mail_service: MailServiceDependency,
+        interceptor: TokenInterceptor,
+    ) -> InterceptedService<MailServiceServer<Self>, TokenInterceptor> {
+        MailServiceServer::with_interceptor(
+            Self {
+                mail_service,
+            },
+            interceptor,
+        )
+    }
+}
+
+#[tonic::async_trait]
+impl mail_service_server::MailService for MailGrpc {
+    #[tracing::instrument(skip_all, name = "MailGrpc::send_templated")]
+    async fn send_templated(
+        &self,
+        request: Request<SendTemplatedRequest>,
+    ) -> Result<Response<SendTemplatedResponse>, Status> {
+        let request = request.into_inner();
+        let message = self
+            .mail_service
+            .send_templated(
+                request.recipient,
+                request.template,
+                request.locale,
+                request.variables,
+            )
+            .await?;
+
+        Ok(Response::new(SendTemplatedResponse {
+            message_id: message.id.to_string(),
+            status: ProtoDeliveryStatus::from(message.status).into(),
+        }))
+    }
+
+    #[tracing::instrument(skip_all, name = "MailGrpc::get_delivery_status")]
+    async fn get_delivery_status(
+        &self,
+        request: Request<GetDeliveryStatusRequest>,
+    ) -> Result<Response<GetDeliveryStatusResponse>, Status> {
+        let id = Ulid::from_str(&request.into_inner().message_id)
+            .map_err(|err| Status::invalid_argument(err.to_string()))?;
+        let message = self.mail_service.get_by_id(id).await?;
+
+        Ok(Response::new(message.into()))
+    }
+}
+
+impl From<DeliveryStatus> for ProtoDeliveryStatus {
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn from(status: DeliveryStatus) -> Self {
+        match status {
+            DeliveryStatus::Pending => Self::Pending,
+            DeliveryStatus::Sent => Self::Sent,
+            DeliveryStatus::Failed => Self::Failed,
+        }
+    }
+}
+
+impl From<Message> for GetDeliveryStatusResponse {
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn from(message: Message) -> Self {
+        Self {
+            message_id: message.id.to_string(),
+            status: ProtoDeliveryStatus::from(message.status).into(),
+            error: message.error,
+        }
+    }
+}
+
+impl From<ServiceError> for Status {
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn from(err: ServiceError) -> Self {
+        use ServiceError as SE;
+        match err {
+            SE::BadRequest(..) => Self::invalid_argument(err.to_string()),
+            SE::NotFound(..) => Self::not_found(err.to_string()),
+            SE::Database(..) | SE::Internal(..) => {
+                Self::internal(err.to_string())
+            },
+        }
+    }
+}
@@ -1 +1,96 @@
+use std::{net::SocketAddr, sync::Arc};
 
+use env_vars_config::env_vars_config;
+use grpc::MailGrpc;
+use proto::{auth::TokenInterceptor, propagation};
+use repository::message::surreal::SurrealMessageRepository;
+use service::mail::{MailServiceDependency, implementation::MailServiceImpl};
+use template::{TEMPLATES, TemplateRegistry};
+use tonic::transport::Server;
+use transport::smtp::SmtpTransport;
+use utils::adapters::SurrealPool;
+
+pub mod entity;
+pub mod grpc;
+pub mod repository;
+pub mod service;
+pub mod template;
+pub mod transport;
+
+env_vars_config! {
+    SERVER_ADDRESS: String = "0.0.0.0:50051",
+    DB_ADDRESS: String = "localhost:8001",
+    DB_NAMESPACE: String = "minizord",
+    DB_NAME: String = "mail",
+    DB_USER: String = "root",
+    DB_PASSWORD: String = "root",
+    DB_MAX_POOL_SIZE: u64 = 8u64,
+    METRICS_ADDRESS: String = "0.0.0.0:8082",
+    OTEL_ENDPOINT: String = "http://localhost:4317",
+    SMTP_HOST: String = "localhost",
+    SMTP_PORT: u16 = 1025u16,
+    SMTP_USERNAME: String = "",
+    SMTP_PASSWORD: String = "",
+    SMTP_TLS: bool = false,
+    MAIL_FROM: String = "Minizord <noreply@localhost>",
+    DEFAULT_LOCALE: String = "ru",
+    INTERNAL_GRPC_TOKEN: String = "",
+}
+
+pub struct Mail {
+    mail_service: MailServiceDependency,
+}
+impl Mail {
+    #[tracing::instrument(skip_all, level = "trace")]
+    pub async fn setup(db: SurrealPool) -> Self {
+        assert!(
+            !config::INTERNAL_GRPC_TOKEN.is_empty(),
+            "INTERNAL_GRPC_TOKEN must be set"
+        );
+
+        let message_repository = SurrealMessageRepository::new(db.clone());
+
+        let transport = SmtpTransport::new(
+            &config::SMTP_HOST,
+            *config::SMTP_PORT,
+            &config::SMTP_USERNAME,
+            &config::SMTP_PASSWORD,
+            *config::SMTP_TLS,
+            &config::MAIL_FROM,
+        );
+        let templates = Arc::new(
+            TemplateRegistry::load(&TEMPLATES, &config::DEFAULT_LOCALE)
+                .expect("Failed to load mail templates"),
+        );
+
+        let mail_service =
+            MailServiceImpl::new(message_repository, transport, templates);
+
+        Self {
+            mail_service,
+        }
+    }
+
+    pub async fn run(self) -> Result<(), tonic::transport::Error> {
+        tracing::info!("Starting the gRPC server");
+
+        let address: SocketAddr = config::SERVER_ADDRESS
+            .parse()
+            .expect("Got invalid server address");
+        Server::builder()
+            .trace_fn(propagation::server_span)
+            .add_service(MailGrpc::server(
+                self.mail_service,
+                TokenInterceptor::new(&config::INTERNAL_GRPC_TOKEN),
+            ))
+            .serve_with_shutdown(address, async {
+                tokio::signal::ctrl_c()
+                    .await
+                    .expect("Failed to listen for the shutdown signal");
+            })
+            .await?;
+
+        tracing::info!("Shutting down the gRPC server");
+        Ok(())
+    }
+}
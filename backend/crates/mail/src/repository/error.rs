@@ -0,0 +1,15 @@
+#[cfg(feature = "surrealdb")]
+type DBError = surrealdb::Error;
+
+#[derive(thiserror::Error, Debug)]
+pub enum RepositoryError {
+    #[error("Database error: {0}")]
+    Pool(#[from] mobc::Error<DBError>),
+
+    #[error("Database error: {0}")]
+    Database(#[from] DBError),
+
+    #[cfg(feature = "surrealdb")]
+    #[error("Failed to save object to the database")]
+    FailedToSaveObject,
+}
@@ -0,0 +1,11 @@
+use macros::crud_repository;
+
+use crate::{entity::message, repository::RepositoryError};
+
+#[cfg(feature = "surrealdb")]
+pub mod surreal;
+
+crud_repository! {
+    Message
+        Err: RepositoryError
+}
@@ -0,0 +1,60 @@
+use macros::{EntityId, implementation};
+use tracing::instrument;
+use utils::adapters::{MobcPool, SurrealPool};
+
+use super::{MessageRepository, MessageRepositoryResult};
+use crate::{
+    entity::message::{CreateMessage, Message, MessageId, MessageUpdate},
+    repository::RepositoryError,
+};
+
+implementation! {
+    MessageRepository {
+        pool: SurrealPool
+    } as SurrealMessageRepository {
+        #[instrument(skip_all, name = "MessageRepository::save")]
+        async fn save(&self, new: CreateMessage) -> Message {
+            let entity: Message = new.into();
+            self.pool
+                .get()
+                .await?
+                .create(entity.id.record_id())
+                .content(entity)
+                .await?
+                .ok_or(RepositoryError::FailedToSaveObject)?
+        }
+
+        #[instrument(skip_all, name = "MessageRepository::find_by_id")]
+        async fn find_by_id(&self, id: MessageId) -> Option<Message> {
+            self.pool
+                .get()
+                .await?
+                .select(id.record_id())
+                .await?
+        }
+
+        #[instrument(skip_all, name = "MessageRepository::exists_by_id")]
+        async fn exists_by_id(&self, id: MessageId) -> bool {
+            self.find_by_id(id).await?.is_some()
+        }
+
+        #[instrument(skip_all, name = "MessageRepository::update_by_id")]
+        async fn update_by_id(&self, id: MessageId, update: MessageUpdate) -> Option<Message> {
+            self.pool
+                .get()
+                .await?
+                .update(id.record_id())
+                .merge(update)
+                .await?
+        }
+
+        #[instrument(skip_all, name = "MessageRepository::delete_by_id")]
+        async fn delete_by_id(&self, id: MessageId) -> Option<Message> {
+            self.pool
+                .get()
+                .await?
+                .delete(id.record_id())
+                .await?
+        }
+    }
+}
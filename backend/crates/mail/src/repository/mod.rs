@@ -0,0 +1,4 @@
+mod error;
+pub mod message;
+
+pub use error::RepositoryError;
@@ -0,0 +1,35 @@
+use crate::{repository::RepositoryError, template::TemplateError};
+
+#[derive(thiserror::Error, Debug)]
+pub enum ServiceError {
+    #[error("{0}")]
+    BadRequest(String),
+
+    #[error("{0}")]
+    NotFound(String),
+
+    #[error("{0}")]
+    Database(String),
+
+    #[error("{0}")]
+    Internal(String),
+}
+
+impl From<RepositoryError> for ServiceError {
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn from(err: RepositoryError) -> Self {
+        Self::Database(err.to_string())
+    }
+}
+
+impl From<TemplateError> for ServiceError {
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn from(err: TemplateError) -> Self {
+        use TemplateError as TE;
+        match err {
+            TE::NotFound(..) => Self::NotFound(err.to_string()),
+            TE::MissingVariable(..) => Self::BadRequest(err.to_string()),
+            TE::Invalid(..) | TE::Render(..) => Self::Internal(err.to_string()),
+        }
+    }
+}
@@ -0,0 +1,310 @@
+use std::{collections::HashMap, sync::Arc};
+
+use chrono::Utc;
+use lettre::Address;
+use macros::implementation;
+use tracing::instrument;
+use ulid::Ulid;
+
+use super::{MailService, MailServiceResult};
+use crate::{
+    entity::message::{CreateMessage, DeliveryStatus, Message, MessageUpdate},
+    repository::message::MessageRepositoryDependency,
+    service::ServiceError,
+    template::TemplateRegistry,
+    transport::{MailTransportDependency, OutgoingMail},
+};
+
+implementation! {
+    MailService {
+        message_repository: MessageRepositoryDependency,
+        transport: MailTransportDependency,
+        templates: Arc<TemplateRegistry>,
+    } as MailServiceImpl {
+        #[instrument(skip_all, name = "MailService::send_templated")]
+        async fn send_templated(
+            &self,
+            recipient: String,
+            template: String,
+            locale: String,
+            variables: HashMap<String, String>,
+        ) -> Message {
+            if recipient.parse::<Address>().is_err() {
+                Err(ServiceError::BadRequest(
+                    "Invalid recipient address".into(),
+                ))?
+            }
+            let rendered =
+                self.templates.render(&template, &locale, &variables)?;
+
+            let message = self.message_repository
+                .save(
+                    CreateMessage {
+                        recipient: recipient.clone(),
+                        template,
+                        locale: rendered.locale,
+                        subject: rendered.subject.clone(),
+                        text_body: rendered.text.clone(),
+                        html_body: rendered.html.clone(),
+                    }
+                )
+                .await?;
+
+            let update = match self.transport
+                .send(
+                    OutgoingMail {
+                        recipient,
+                        subject: rendered.subject,
+                        text: rendered.text,
+                        html: rendered.html,
+                    }
+                )
+                .await
+            {
+                Ok(()) => MessageUpdate {
+                    status: Some(DeliveryStatus::Sent),
+                    error: None,
+                    updated_at: Some(Utc::now()),
+                },
+                Err(err) => {
+                    tracing::warn!("Failed to deliver message {}: {err}", message.id);
+                    MessageUpdate {
+                        status: Some(DeliveryStatus::Failed),
+                        error: Some(err.to_string()),
+                        updated_at: Some(Utc::now()),
+                    }
+                },
+            };
+
+            self.message_repository
+                .update_by_id(message.id, update)
+                .await?
+                .expect("Got unchecked message ID")
+        }
+
+        #[instrument(skip_all, name = "MailService::get_by_id")]
+        async fn get_by_id(
+            &self,
+            id: Ulid,
+        ) -> Message {
+            self.message_repository
+                .find_by_id(id.into())
+                .await?
+                .ok_or(
+                    ServiceError::NotFound("Message with provided id".into())
+                )?
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        collections::HashMap,
+        sync::{Arc, Mutex},
+    };
+
+    use macros::async_trait::async_trait;
+    use rstest::{fixture, rstest};
+    use ulid::Ulid;
+
+    use super::MailServiceImpl;
+    use crate::{
+        entity::message::{
+            CreateMessage, DeliveryStatus, Message, MessageId, MessageUpdate,
+        },
+        repository::{RepositoryError, message::MessageRepository},
+        service::{ServiceError, mail::MailService},
+        template::{TEMPLATES, TemplateRegistry},
+        transport::memory::MemoryTransport,
+    };
+
+    #[derive(Default)]
+    struct MemoryMessageRepository(Mutex<HashMap<String, Message>>);
+
+    #[async_trait]
+    impl MessageRepository for MemoryMessageRepository {
+        async fn save(
+            &self,
+            new: CreateMessage,
+        ) -> Result<Message, RepositoryError> {
+            let message = Message::from(new);
+            self.0
+                .lock()
+                .expect("Poisoned lock")
+                .insert(message.id.to_string(), message.clone());
+            Ok(message)
+        }
+
+        async fn find_by_id(
+            &self,
+            id: MessageId,
+        ) -> Result<Option<Message>, RepositoryError> {
+            Ok(self
+                .0
+                .lock()
+                .expect("Poisoned lock")
+                .get(&id.to_string())
+                .cloned())
+        }
+
+        async fn exists_by_id(
+            &self,
+            id: MessageId,
+        ) -> Result<bool, RepositoryError> {
+            Ok(self.find_by_id(id).await?.is_some())
+        }
+
+        async fn update_by_id(
+            &self,
+            id: MessageId,
+            update: MessageUpdate,
+        ) -> Result<Option<Message>, RepositoryError> {
+            let mut messages = self.0.lock().expect("Poisoned lock");
+            Ok(messages.get_mut(&id.to_string()).map(|message| {
+                if let Some(status) = update.status {
+                    message.status = status;
+                }
+                if let Some(error) = update.error {
+                    message.error = Some(error);
+                }
+                if let Some(updated_at) = update.updated_at {
+                    message.updated_at = updated_at;
+                }
+                message.clone()
+            }))
+        }
+
+        async fn delete_by_id(
+            &self,
+            id: MessageId,
+        ) -> Result<Option<Message>, RepositoryError> {
+            Ok(self
+                .0
+                .lock()
+                .expect("Poisoned lock")
+                .remove(&id.to_string()))
+        }
+    }
+
+    #[fixture]
+    fn templates() -> Arc<TemplateRegistry> {
+        Arc::new(
+            TemplateRegistry::load(&TEMPLATES, "ru")
+                .expect("Shipped templates should be valid"),
+        )
+    }
+
+    #[fixture]
+    fn variables() -> HashMap<String, String> {
+        HashMap::from([("username".to_string(), "admin".to_string())])
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn delivered(variables: HashMap<String, String>) {
+        let transport = MemoryTransport::new();
+        let service = MailServiceImpl::new(
+            Arc::new(MemoryMessageRepository::default()),
+            transport.clone(),
+            templates(),
+        );
+
+        let message = service
+            .send_templated(
+                "admin@example.com".into(),
+                "welcome".into(),
+                "en".into(),
+                variables,
+            )
+            .await
+            .expect("Message should be sent");
+
+        assert_eq!(message.status, DeliveryStatus::Sent);
+        assert_eq!(
+            transport
+                .sent()
+                .iter()
+                .map(|mail| mail.subject.as_str())
+                .collect::<Vec<_>>(),
+            vec!["Welcome to Minizord, admin!"]
+        );
+        assert_eq!(
+            service
+                .get_by_id(message.id.into())
+                .await
+                .expect("Message should be stored")
+                .status,
+            DeliveryStatus::Sent
+        );
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn rejected(variables: HashMap<String, String>) {
+        let service = MailServiceImpl::new(
+            Arc::new(MemoryMessageRepository::default()),
+            MemoryTransport::rejecting(),
+            templates(),
+        );
+
+        let message = service
+            .send_templated(
+                "admin@example.com".into(),
+                "welcome".into(),
+                "en".into(),
+                variables,
+            )
+            .await
+            .expect("Message should be stored");
+
+        assert_eq!(message.status, DeliveryStatus::Failed);
+        assert!(message.error.is_some());
+    }
+
+    #[rstest]
+    #[case::invalid_recipient("admin", "welcome")]
+    #[case::unknown_template("admin@example.com", "unknown")]
+    #[tokio::test]
+    async fn not_sent(
+        variables: HashMap<String, String>,
+        #[case] recipient: &str,
+        #[case] template: &str,
+    ) {
+        let transport = MemoryTransport::new();
+        let service = MailServiceImpl::new(
+            Arc::new(MemoryMessageRepository::default()),
+            transport.clone(),
+            templates(),
+        );
+
+        let res = service
+            .send_templated(
+                recipient.into(),
+                template.into(),
+                "en".into(),
+                variables,
+            )
+            .await;
+
+        assert!(matches!(
+            res,
+            Err(ServiceError::BadRequest(..) | ServiceError::NotFound(..))
+        ));
+        assert!(transport.sent().is_empty());
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn unknown_message() {
+        let service = MailServiceImpl::new(
+            Arc::new(MemoryMessageRepository::default()),
+            MemoryTransport::new(),
+            templates(),
+        );
+
+        let res = service.get_by_id(Ulid::new()).await;
+
+        assert!(matches!(res, Err(ServiceError::NotFound(..))));
+    }
+}
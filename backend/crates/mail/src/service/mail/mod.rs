@@ -0,0 +1,24 @@
+use std::collections::HashMap;
+
+use macros::service;
+use ulid::Ulid;
+
+use crate::{entity::message::Message, service::ServiceError};
+
+pub mod implementation;
+
+service! {
+    Mail
+        Err: ServiceError
+    {
+        async fn send_templated(
+            &self,
+            recipient: String,
+            template: String,
+            locale: String,
+            variables: HashMap<String, String>,
+        ) -> Message;
+
+        async fn get_by_id(&self, id: Ulid) -> Message;
+    }
+}
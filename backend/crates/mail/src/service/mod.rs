@@ -0,0 +1,4 @@
+mod error;
+pub mod mail;
+
+pub use error::ServiceError;
@@ -0,0 +1,339 @@
+use std::collections::{BTreeMap, HashMap};
+
+use include_dir::{Dir, include_dir};
+use minijinja::{Environment, UndefinedBehavior};
+use serde::Deserialize;
+
+pub static TEMPLATES: Dir<'_> = include_dir!("crates/mail/templates");
+
+const MANIFEST: &str = "template.json";
+const PARTS: [&str; 3] = ["subject.txt", "body.txt", "body.html"];
+
+#[derive(thiserror::Error, Debug)]
+pub enum TemplateError {
+    #[error("Template `{0}` was not found")]
+    NotFound(String),
+
+    #[error("Missing variable `{0}`")]
+    MissingVariable(String),
+
+    #[error("Template `{0}` is invalid: {1}")]
+    Invalid(String, String),
+
+    #[error("Failed to render template `{0}`: {1}")]
+    Render(String, String),
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct RenderedTemplate {
+    pub locale: String,
+    pub subject: String,
+    pub text: String,
+    pub html: String,
+}
+
+#[derive(Deserialize)]
+struct Manifest {
+    variables: Vec<String>,
+}
+
+struct Template {
+    variables: Vec<String>,
+    locales: Vec<String>,
+}
+
+/// Localized mail templates embedded at compile time.
+///
+/// Every template lives in its own directory with a `template.json` manifest
+/// declaring its variables and one subdirectory per locale holding the
+/// subject, the plain text body and the HTML body.
+pub struct TemplateRegistry {
+    environment: Environment<'static>,
+    templates: HashMap<String, Template>,
+    default_locale: String,
+}
+impl TemplateRegistry {
+    /// Loads every template from `dir` and renders each of its locales once,
+    /// so a template using an undeclared variable or lacking the default
+    /// locale is rejected before the service starts.
+    #[tracing::instrument(
+        name = "TemplateRegistry::load",
+        skip_all,
+        level = "debug"
+    )]
+    pub fn load(
+        dir: &'static Dir<'static>,
+        default_locale: &str,
+    ) -> Result<Self, TemplateError> {
+        let mut environment = Environment::new();
+        environment.set_undefined_behavior(UndefinedBehavior::Strict);
+
+        let mut templates = HashMap::new();
+        for template_dir in dir.dirs() {
+            let name = file_name(template_dir.path());
+            let manifest: Manifest = template_dir
+                .get_file(template_dir.path().join(MANIFEST))
+                .and_then(|file| file.contents_utf8())
+                .ok_or_else(|| {
+                    TemplateError::Invalid(
+                        name.clone(),
+                        format!("missing `{MANIFEST}`"),
+                    )
+                })
+                .and_then(|contents| {
+                    serde_json::from_str(contents).map_err(|err| {
+                        TemplateError::Invalid(name.clone(), err.to_string())
+                    })
+                })?;
+
+            let mut locales = Vec::new();
+            for locale_dir in template_dir.dirs() {
+                for part in PARTS {
+                    let path = locale_dir.path().join(part);
+                    let (path, source) = locale_dir
+                        .get_file(&path)
+                        .and_then(|file| {
+                            Some((file.path().to_str()?, file.contents_utf8()?))
+                        })
+                        .ok_or_else(|| {
+                            TemplateError::Invalid(
+                                name.clone(),
+                                format!("missing `{}`", path.display()),
+                            )
+                        })?;
+                    environment.add_template(path, source).map_err(|err| {
+                        TemplateError::Invalid(name.clone(), err.to_string())
+                    })?;
+                }
+                locales.push(file_name(locale_dir.path()));
+            }
+            if !locales.iter().any(|locale| locale == default_locale) {
+                return Err(TemplateError::Invalid(
+                    name,
+                    format!("missing default locale `{default_locale}`"),
+                ));
+            }
+
+            templates.insert(
+                name,
+                Template {
+                    variables: manifest.variables,
+                    locales,
+                },
+            );
+        }
+
+        let registry = Self {
+            environment,
+            templates,
+            default_locale: default_locale.to_string(),
+        };
+        registry.validate()?;
+        Ok(registry)
+    }
+
+    #[tracing::instrument(
+        name = "TemplateRegistry::render",
+        skip_all,
+        level = "debug"
+    )]
+    pub fn render(
+        &self,
+        name: &str,
+        locale: &str,
+        variables: &HashMap<String, String>,
+    ) -> Result<RenderedTemplate, TemplateError> {
+        let template = self
+            .templates
+            .get(name)
+            .ok_or_else(|| TemplateError::NotFound(name.to_string()))?;
+        if let Some(missing) = template
+            .variables
+            .iter()
+            .find(|variable| !variables.contains_key(*variable))
+        {
+            return Err(TemplateError::MissingVariable(missing.clone()));
+        }
+
+        let locale = self.resolve_locale(template, locale);
+        let [subject, text, html] = PARTS.map(|part| {
+            self.render_part(&format!("{name}/{locale}/{part}"), variables)
+        });
+
+        Ok(RenderedTemplate {
+            locale: locale.to_string(),
+            subject: subject?.trim().to_string(),
+            text: text?,
+            html: html?,
+        })
+    }
+
+    /// Picks the requested locale, then its language without the region,
+    /// then the default locale.
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn resolve_locale<'a>(
+        &'a self,
+        template: &'a Template,
+        locale: &'a str,
+    ) -> &'a str {
+        let language = locale.split(['-', '_']).next().unwrap_or(locale);
+        [locale, language]
+            .into_iter()
+            .find(|candidate| {
+                template.locales.iter().any(|locale| locale == candidate)
+            })
+            .unwrap_or(self.default_locale.as_str())
+    }
+
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn render_part<T: serde::Serialize>(
+        &self,
+        path: &str,
+        variables: &T,
+    ) -> Result<String, TemplateError> {
+        self.environment
+            .get_template(path)
+            .and_then(|template| template.render(variables))
+            .map_err(|err| {
+                TemplateError::Render(path.to_string(), err.to_string())
+            })
+    }
+
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn validate(&self) -> Result<(), TemplateError> {
+        for (name, template) in &self.templates {
+            let variables: BTreeMap<&str, &str> = template
+                .variables
+                .iter()
+                .map(|variable| (variable.as_str(), variable.as_str()))
+                .collect();
+            for locale in &template.locales {
+                for part in PARTS {
+                    self.render_part(
+                        &format!("{name}/{locale}/{part}"),
+                        &variables,
+                    )
+                    .map_err(|err| {
+                        TemplateError::Invalid(name.clone(), err.to_string())
+                    })?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+fn file_name(path: &std::path::Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use include_dir::{Dir, DirEntry, File};
+    use rstest::{fixture, rstest};
+
+    use super::{TEMPLATES, TemplateError, TemplateRegistry};
+
+    static UNDECLARED_VARIABLE: Dir<'_> = Dir::new(
+        "",
+        &[DirEntry::Dir(Dir::new(
+            "broken",
+            &[
+                DirEntry::File(File::new(
+                    "broken/template.json",
+                    br#"{ "variables": [] }"#,
+                )),
+                DirEntry::Dir(Dir::new(
+                    "broken/ru",
+                    &[
+                        DirEntry::File(File::new(
+                            "broken/ru/subject.txt",
+                            b"Hi",
+                        )),
+                        DirEntry::File(File::new(
+                            "broken/ru/body.txt",
+                            b"Hi, {{ username }}",
+                        )),
+                        DirEntry::File(File::new("broken/ru/body.html", b"Hi")),
+                    ],
+                )),
+            ],
+        ))],
+    );
+
+    #[fixture]
+    fn registry() -> TemplateRegistry {
+        TemplateRegistry::load(&TEMPLATES, "ru")
+            .expect("Shipped templates should be valid")
+    }
+
+    #[fixture]
+    fn variables() -> HashMap<String, String> {
+        HashMap::from([("username".to_string(), "<admin>".to_string())])
+    }
+
+    #[rstest]
+    #[case::exact("en", "en")]
+    #[case::region("en-US", "en")]
+    #[case::fallback("de", "ru")]
+    fn locale(
+        registry: TemplateRegistry,
+        variables: HashMap<String, String>,
+        #[case] requested: &str,
+        #[case] expected: &str,
+    ) {
+        let rendered = registry
+            .render("welcome", requested, &variables)
+            .expect("Template should render");
+
+        assert_eq!(rendered.locale, expected);
+    }
+
+    #[rstest]
+    fn html_is_escaped(
+        registry: TemplateRegistry,
+        variables: HashMap<String, String>,
+    ) {
+        let rendered = registry
+            .render("welcome", "en", &variables)
+            .expect("Template should render");
+
+        assert!(rendered.text.contains("<admin>"));
+        assert!(rendered.html.contains("&lt;admin&gt;"));
+        assert!(!rendered.subject.ends_with('\n'));
+    }
+
+    #[rstest]
+    fn missing_variable(registry: TemplateRegistry) {
+        let res = registry.render("welcome", "en", &HashMap::new());
+
+        assert!(matches!(
+            res,
+            Err(TemplateError::MissingVariable(name)) if name == "username"
+        ));
+    }
+
+    #[rstest]
+    fn unknown_template(
+        registry: TemplateRegistry,
+        variables: HashMap<String, String>,
+    ) {
+        let res = registry.render("unknown", "en", &variables);
+
+        assert!(matches!(res, Err(TemplateError::NotFound(..))));
+    }
+
+    #[rstest]
+    #[case::undeclared_variable("ru")]
+    #[case::missing_default_locale("en")]
+    fn invalid(#[case] default_locale: &str) {
+        let res = TemplateRegistry::load(&UNDECLARED_VARIABLE, default_locale);
+
+        assert!(matches!(res, Err(TemplateError::Invalid(..))));
+    }
+}
@@ -0,0 +1,52 @@
+use std::sync::{Arc, Mutex};
+
+use macros::async_trait::async_trait;
+
+use super::{MailTransport, OutgoingMail, TransportError};
+
+/// An in-process transport that keeps every mail it is given instead of
+/// delivering it, for tests and local runs without an SMTP sink.
+#[derive(Default)]
+pub struct MemoryTransport {
+    sent: Mutex<Vec<OutgoingMail>>,
+    reject: bool,
+}
+impl MemoryTransport {
+    #[tracing::instrument(skip_all, level = "trace")]
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    #[tracing::instrument(skip_all, level = "trace")]
+    pub fn rejecting() -> Arc<Self> {
+        Arc::new(Self {
+            reject: true,
+            ..Self::default()
+        })
+    }
+
+    #[tracing::instrument(skip_all, level = "trace")]
+    pub fn sent(&self) -> Vec<OutgoingMail> {
+        self.sent
+            .lock()
+            .map(|sent| sent.clone())
+            .unwrap_or_default()
+    }
+}
+
+#[async_trait]
+impl MailTransport for MemoryTransport {
+    #[tracing::instrument(name = "MemoryTransport::send", skip_all)]
+    async fn send(&self, mail: OutgoingMail) -> Result<(), TransportError> {
+        if self.reject {
+            return Err(TransportError::Rejected(format!(
+                "Mail to {} was rejected",
+                mail.recipient
+            )));
+        }
+        if let Ok(mut sent) = self.sent.lock() {
+            sent.push(mail);
+        }
+        Ok(())
+    }
+}
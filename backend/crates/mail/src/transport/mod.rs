@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use macros::async_trait::async_trait;
+
+pub mod memory;
+pub mod smtp;
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct OutgoingMail {
+    pub recipient: String,
+    pub subject: String,
+    pub text: String,
+    pub html: String,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum TransportError {
+    #[error("Invalid address: {0}")]
+    Address(#[from] lettre::address::AddressError),
+
+    #[error("Failed to build message: {0}")]
+    Message(#[from] lettre::error::Error),
+
+    #[error("SMTP error: {0}")]
+    Smtp(#[from] lettre::transport::smtp::Error),
+
+    #[error("{0}")]
+    Rejected(String),
+}
+
+#[async_trait]
+pub trait MailTransport {
+    async fn send(&self, mail: OutgoingMail) -> Result<(), TransportError>;
+}
+
+pub type MailTransportDependency = Arc<dyn MailTransport + Send + Sync>;
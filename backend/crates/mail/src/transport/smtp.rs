@@ -0,0 +1,66 @@
+use std::sync::Arc;
+
+use lettre::{
+    AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
+    message::{Mailbox, MultiPart},
+    transport::smtp::authentication::Credentials,
+};
+use macros::async_trait::async_trait;
+
+use super::{MailTransport, OutgoingMail, TransportError};
+
+pub struct SmtpTransport {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+}
+impl SmtpTransport {
+    #[tracing::instrument(
+        name = "SmtpTransport::new",
+        skip_all,
+        level = "debug"
+    )]
+    pub fn new(
+        host: &str,
+        port: u16,
+        username: &str,
+        password: &str,
+        tls: bool,
+        from: &str,
+    ) -> Arc<Self> {
+        let mut builder = if tls {
+            AsyncSmtpTransport::<Tokio1Executor>::relay(host)
+                .expect("Failed to set up SMTP relay")
+        } else {
+            AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(host)
+        }
+        .port(port);
+        if !username.is_empty() {
+            builder = builder.credentials(Credentials::new(
+                username.to_string(),
+                password.to_string(),
+            ));
+        }
+
+        Arc::new(Self {
+            transport: builder.build(),
+            from: from.parse().expect("Got invalid sender mailbox"),
+        })
+    }
+}
+
+#[async_trait]
+impl MailTransport for SmtpTransport {
+    #[tracing::instrument(name = "SmtpTransport::send", skip_all)]
+    async fn send(&self, mail: OutgoingMail) -> Result<(), TransportError> {
+        let message = Message::builder()
+            .from(self.from.clone())
+            .to(Mailbox::new(None, mail.recipient.parse()?))
+            .subject(mail.subject)
+            .multipart(MultiPart::alternative_plain_html(
+                mail.text, mail.html,
+            ))?;
+
+        self.transport.send(message).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,11 @@
+fn main() -> std::io::Result<()> {
+    tonic_build::configure().compile_protos(
+        &[
+            "proto/minizord/api/v1/telegram.proto",
+            "proto/minizord/api/v1/user.proto",
+            "proto/minizord/mail/v1/mail.proto",
+            "proto/minizord/telegram/v1/notification.proto",
+        ],
+        &["proto"],
+    )
+}
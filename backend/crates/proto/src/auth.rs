@@ -0,0 +1,101 @@
+use std::sync::Arc;
+
+use subtle::ConstantTimeEq as _;
+use tonic::{
+    Request, Status,
+    metadata::{Ascii, MetadataValue, errors::InvalidMetadataValue},
+    service::Interceptor,
+};
+
+use crate::propagation;
+
+/// Attaches the internal token shared between the services and the trace
+/// context to every outgoing call.
+#[derive(Clone)]
+pub struct BearerToken(MetadataValue<Ascii>);
+impl BearerToken {
+    #[tracing::instrument(skip_all, level = "trace")]
+    pub fn new(token: &str) -> Result<Self, InvalidMetadataValue> {
+        Ok(Self(format!("Bearer {token}").parse()?))
+    }
+}
+impl Interceptor for BearerToken {
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn call(
+        &mut self,
+        mut request: Request<()>,
+    ) -> Result<Request<()>, Status> {
+        request
+            .metadata_mut()
+            .insert("authorization", self.0.clone());
+        propagation::inject(request.metadata_mut());
+        Ok(request)
+    }
+}
+
+/// Rejects calls lacking the `authorization: Bearer <token>` metadata shared
+/// between the internal services, since the gRPC ports are not meant to be
+/// reachable by users.
+#[derive(Clone)]
+pub struct TokenInterceptor {
+    token: Arc<str>,
+}
+impl TokenInterceptor {
+    #[tracing::instrument(skip_all, level = "trace")]
+    pub fn new(token: &str) -> Self {
+        Self {
+            token: token.into(),
+        }
+    }
+}
+impl Interceptor for TokenInterceptor {
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn call(&mut self, request: Request<()>) -> Result<Request<()>, Status> {
+        let authorized = request
+            .metadata()
+            .get("authorization")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .is_some_and(|token| {
+                token.as_bytes().ct_eq(self.token.as_bytes()).into()
+            });
+        if !authorized {
+            return Err(Status::unauthenticated(
+                "Invalid or missing internal token",
+            ));
+        }
+        Ok(request)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rstest::rstest;
+    use tonic::{Code, Request, service::Interceptor as _};
+
+    use super::{BearerToken, TokenInterceptor};
+
+    #[rstest]
+    #[case("secret", true)]
+    #[case("other", false)]
+    fn bearer_token_is_checked(#[case] token: &str, #[case] accepted: bool) {
+        let request = BearerToken::new(token)
+            .expect("Token should be valid metadata")
+            .call(Request::new(()))
+            .expect("Client interceptor should not fail");
+
+        let result = TokenInterceptor::new("secret").call(request);
+
+        assert_eq!(result.is_ok(), accepted);
+    }
+
+    #[rstest]
+    fn missing_token_is_rejected() {
+        let result = TokenInterceptor::new("secret").call(Request::new(()));
+
+        assert_eq!(
+            result.err().map(|status| status.code()),
+            Some(Code::Unauthenticated)
+        );
+    }
+}
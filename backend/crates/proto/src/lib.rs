@@ -0,0 +1,14 @@
+pub mod auth;
+pub mod propagation;
+
+pub mod api {
+    tonic::include_proto!("minizord.api.v1");
+}
+
+pub mod mail {
+    tonic::include_proto!("minizord.mail.v1");
+}
+
+pub mod telegram {
+    tonic::include_proto!("minizord.telegram.v1");
+}
@@ -0,0 +1,126 @@
+use opentelemetry::{
+    global,
+    propagation::{Extractor, Injector},
+};
+use tonic::{
+    Request, Status,
+    codegen::http::{self, HeaderMap},
+    metadata::{MetadataKey, MetadataMap, MetadataValue},
+    service::Interceptor,
+};
+use tracing::Span;
+use tracing_opentelemetry::OpenTelemetrySpanExt as _;
+
+struct MetadataInjector<'a>(&'a mut MetadataMap);
+impl Injector for MetadataInjector<'_> {
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn set(&mut self, key: &str, value: String) {
+        let (Ok(key), Ok(value)) = (
+            MetadataKey::from_bytes(key.as_bytes()),
+            MetadataValue::try_from(value),
+        ) else {
+            return;
+        };
+        self.0.insert(key, value);
+    }
+}
+
+struct HeaderExtractor<'a>(&'a HeaderMap);
+impl Extractor for HeaderExtractor<'_> {
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(|value| value.to_str().ok())
+    }
+
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(http::HeaderName::as_str).collect()
+    }
+}
+
+/// Writes the context of the current span into the metadata of an outgoing
+/// call, for clients that already have an interceptor of their own.
+///
+/// Not instrumented, as its own span would take the caller's place.
+pub fn inject(metadata: &mut MetadataMap) {
+    let context = Span::current().context();
+    global::get_text_map_propagator(|propagator| {
+        propagator.inject_context(&context, &mut MetadataInjector(metadata));
+    });
+}
+
+/// Makes the callee's spans children of the span the client is called from.
+#[derive(Clone, Copy, Default, Debug)]
+pub struct TraceContextInterceptor;
+impl Interceptor for TraceContextInterceptor {
+    fn call(
+        &mut self,
+        mut request: Request<()>,
+    ) -> Result<Request<()>, Status> {
+        inject(request.metadata_mut());
+        Ok(request)
+    }
+}
+
+/// Root span of an incoming call, meant for `Server::trace_fn`. It continues
+/// the caller's trace when the call carries one.
+pub fn server_span(request: &http::Request<()>) -> Span {
+    let span = tracing::info_span!(
+        "gRPC request",
+        otel.kind = "server",
+        rpc.system = "grpc",
+        rpc.method = request.uri().path(),
+    );
+    let parent = global::get_text_map_propagator(|propagator| {
+        propagator.extract(&HeaderExtractor(request.headers()))
+    });
+    span.set_parent(parent);
+    span
+}
+
+#[cfg(test)]
+mod test {
+    use opentelemetry::{
+        Context,
+        propagation::TextMapPropagator as _,
+        trace::{
+            SpanContext, SpanId, TraceContextExt as _, TraceFlags, TraceId,
+            TraceState,
+        },
+    };
+    use opentelemetry_sdk::propagation::TraceContextPropagator;
+    use rstest::rstest;
+    use tonic::metadata::MetadataMap;
+
+    use super::{HeaderExtractor, MetadataInjector};
+
+    #[rstest]
+    fn round_trip() {
+        let propagator = TraceContextPropagator::new();
+        let span_context = SpanContext::new(
+            TraceId::from_hex("4bf92f3577b34da6a3ce929d0e0e4736")
+                .expect("Trace ID should be valid"),
+            SpanId::from_hex("00f067aa0ba902b7")
+                .expect("Span ID should be valid"),
+            TraceFlags::SAMPLED,
+            true,
+            TraceState::default(),
+        );
+        let mut metadata = MetadataMap::new();
+
+        propagator.inject_context(
+            &Context::new().with_remote_span_context(span_context.clone()),
+            &mut MetadataInjector(&mut metadata),
+        );
+        let headers = metadata.into_headers();
+        let extracted = propagator.extract(&HeaderExtractor(&headers));
+
+        assert_eq!(
+            headers
+                .get("traceparent")
+                .and_then(|value| value.to_str().ok()),
+            Some("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01")
+        );
+        assert_eq!(extracted.span().span_context(), &span_context);
+    }
+}
@@ -0,0 +1,253 @@
+use std::sync::Arc;
+
+use macros::async_trait::async_trait;
+use proto::{
+    api::{
+        Application, ApplicationDecisionRequest, ApplyRequest,
+        GetTeamNeedsRequest, LinkAccountRequest, LinkAccountResponse,
+        ListApplicationsRequest, ListOpenTeamsRequest, SpecializationNeed,
+        Team, telegram_service_client::TelegramServiceClient,
+    },
+    auth::BearerToken,
+};
+use tonic::{
+    codegen::{InterceptedService, http::uri::InvalidUri},
+    metadata::errors::InvalidMetadataValue,
+    transport::Channel,
+};
+
+use super::{
+    ApiClient, ApiClientResult, ApiError, Applicant, LinkedAccount, OpenTeams,
+    SpecializationNeed as Need, TeamApplications, TeamNeeds, TeamSummary,
+};
+
+#[derive(thiserror::Error, Debug)]
+pub enum GrpcApiClientError {
+    #[error("Invalid API address: {0}")]
+    Address(#[from] InvalidUri),
+
+    #[error("Invalid internal token: {0}")]
+    Token(#[from] InvalidMetadataValue),
+}
+
+pub struct GrpcApiClient {
+    client: TelegramServiceClient<InterceptedService<Channel, BearerToken>>,
+}
+impl GrpcApiClient {
+    /// The connection is established on the first request, so the bot
+    /// starts even when the API is not reachable yet.
+    #[tracing::instrument(skip_all, level = "trace")]
+    pub fn connect_lazy(
+        address: String,
+        token: &str,
+    ) -> Result<Arc<Self>, GrpcApiClientError> {
+        let channel = Channel::from_shared(address)?.connect_lazy();
+        let token = BearerToken::new(token)?;
+        Ok(Arc::new(Self {
+            client: TelegramServiceClient::with_interceptor(channel, token),
+        }))
+    }
+}
+
+#[async_trait]
+impl ApiClient for GrpcApiClient {
+    #[tracing::instrument(skip_all, name = "ApiClient::link_account")]
+    async fn link_account(
+        &self,
+        code: String,
+        chat_id: i64,
+        username: Option<String>,
+    ) -> ApiClientResult<LinkedAccount> {
+        let response = self
+            .client
+            .clone()
+            .link_account(LinkAccountRequest {
+                code,
+                chat_id,
+                username,
+            })
+            .await?
+            .into_inner();
+
+        Ok(response.into())
+    }
+
+    #[tracing::instrument(skip_all, name = "ApiClient::list_open_teams")]
+    async fn list_open_teams(
+        &self,
+        limit: u32,
+        offset: u64,
+    ) -> ApiClientResult<OpenTeams> {
+        let response = self
+            .client
+            .clone()
+            .list_open_teams(ListOpenTeamsRequest {
+                limit,
+                offset,
+            })
+            .await?
+            .into_inner();
+
+        Ok(OpenTeams {
+            tour: response.tour.map(|tour| tour.name).unwrap_or_default(),
+            teams: response.teams.into_iter().map(TeamSummary::from).collect(),
+            has_more: response.has_more,
+        })
+    }
+
+    #[tracing::instrument(skip_all, name = "ApiClient::get_team_needs")]
+    async fn get_team_needs(
+        &self,
+        team_id: String,
+    ) -> ApiClientResult<TeamNeeds> {
+        let response = self
+            .client
+            .clone()
+            .get_team_needs(GetTeamNeedsRequest {
+                team_id,
+            })
+            .await?
+            .into_inner();
+
+        Ok(TeamNeeds {
+            team: team(response.team)?,
+            missing: response.missing.into_iter().map(Need::from).collect(),
+        })
+    }
+
+    #[tracing::instrument(skip_all, name = "ApiClient::apply")]
+    async fn apply(
+        &self,
+        chat_id: i64,
+        team_id: String,
+        specialization_id: String,
+        application: String,
+    ) -> ApiClientResult<()> {
+        self.client
+            .clone()
+            .apply(ApplyRequest {
+                chat_id,
+                team_id,
+                specialization_id,
+                application,
+            })
+            .await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all, name = "ApiClient::list_applications")]
+    async fn list_applications(
+        &self,
+        chat_id: i64,
+        limit: u32,
+        offset: u64,
+    ) -> ApiClientResult<TeamApplications> {
+        let response = self
+            .client
+            .clone()
+            .list_applications(ListApplicationsRequest {
+                chat_id,
+                limit,
+                offset,
+            })
+            .await?
+            .into_inner();
+
+        Ok(TeamApplications {
+            team: team(response.team)?,
+            applications: response
+                .applications
+                .into_iter()
+                .map(Applicant::from)
+                .collect(),
+        })
+    }
+
+    #[tracing::instrument(skip_all, name = "ApiClient::accept_application")]
+    async fn accept_application(
+        &self,
+        chat_id: i64,
+        team_id: String,
+        user_id: String,
+    ) -> ApiClientResult<()> {
+        self.client
+            .clone()
+            .accept_application(ApplicationDecisionRequest {
+                chat_id,
+                team_id,
+                user_id,
+            })
+            .await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all, name = "ApiClient::reject_application")]
+    async fn reject_application(
+        &self,
+        chat_id: i64,
+        team_id: String,
+        user_id: String,
+    ) -> ApiClientResult<()> {
+        self.client
+            .clone()
+            .reject_application(ApplicationDecisionRequest {
+                chat_id,
+                team_id,
+                user_id,
+            })
+            .await?;
+        Ok(())
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+fn team(team: Option<Team>) -> ApiClientResult<TeamSummary> {
+    team.map(TeamSummary::from).ok_or_else(|| {
+        ApiError::Unavailable("API response lacks the team".into())
+    })
+}
+
+impl From<LinkAccountResponse> for LinkedAccount {
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn from(response: LinkAccountResponse) -> Self {
+        Self {
+            user_id: response.user_id,
+            username: response.username,
+        }
+    }
+}
+
+impl From<Team> for TeamSummary {
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn from(team: Team) -> Self {
+        Self {
+            id: team.id,
+            name: team.name,
+            members: team.members,
+            max_members: team.max_members,
+        }
+    }
+}
+
+impl From<SpecializationNeed> for Need {
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn from(need: SpecializationNeed) -> Self {
+        Self {
+            id: need.specialization_id,
+            name: need.name,
+            count: need.count,
+        }
+    }
+}
+
+impl From<Application> for Applicant {
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn from(application: Application) -> Self {
+        Self {
+            user_id: application.user_id,
+            username: application.username,
+            specialization: application.specialization,
+            application: application.application,
+        }
+    }
+}
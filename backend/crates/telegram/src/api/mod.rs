@@ -0,0 +1,150 @@
+use std::sync::Arc;
+
+use macros::async_trait::async_trait;
+use tonic::{Code, Status};
+
+pub mod grpc;
+
+#[derive(thiserror::Error, Debug)]
+pub enum ApiError {
+    #[error("{0}")]
+    Invalid(String),
+
+    #[error("{0}")]
+    NotFound(String),
+
+    #[error("{0}")]
+    AlreadyExists(String),
+
+    #[error("{0}")]
+    Conflict(String),
+
+    #[error("{0}")]
+    Forbidden(String),
+
+    #[error("Telegram chat is not linked to any account")]
+    NotLinked,
+
+    #[error("API request failed: {0}")]
+    Unavailable(String),
+}
+
+impl From<Status> for ApiError {
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn from(status: Status) -> Self {
+        let message = status.message().to_string();
+        match status.code() {
+            Code::InvalidArgument => Self::Invalid(message),
+            Code::NotFound => Self::NotFound(message),
+            Code::AlreadyExists => Self::AlreadyExists(message),
+            Code::FailedPrecondition => Self::Conflict(message),
+            Code::PermissionDenied => Self::Forbidden(message),
+            Code::Unauthenticated => Self::NotLinked,
+            _ => Self::Unavailable(message),
+        }
+    }
+}
+
+pub type ApiClientResult<T> = Result<T, ApiError>;
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct LinkedAccount {
+    pub user_id: String,
+    pub username: String,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct TeamSummary {
+    pub id: String,
+    pub name: String,
+    pub members: u32,
+    pub max_members: u32,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct OpenTeams {
+    pub tour: String,
+    pub teams: Vec<TeamSummary>,
+    pub has_more: bool,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct SpecializationNeed {
+    pub id: String,
+    pub name: String,
+    pub count: u32,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct TeamNeeds {
+    pub team: TeamSummary,
+    pub missing: Vec<SpecializationNeed>,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Applicant {
+    pub user_id: String,
+    pub username: String,
+    pub specialization: String,
+    pub application: String,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct TeamApplications {
+    pub team: TeamSummary,
+    pub applications: Vec<Applicant>,
+}
+
+/// Calls the API on behalf of the bot, so the bot never touches the API
+/// database itself.
+#[async_trait]
+pub trait ApiClient {
+    async fn link_account(
+        &self,
+        code: String,
+        chat_id: i64,
+        username: Option<String>,
+    ) -> ApiClientResult<LinkedAccount>;
+
+    async fn list_open_teams(
+        &self,
+        limit: u32,
+        offset: u64,
+    ) -> ApiClientResult<OpenTeams>;
+
+    async fn get_team_needs(
+        &self,
+        team_id: String,
+    ) -> ApiClientResult<TeamNeeds>;
+
+    async fn apply(
+        &self,
+        chat_id: i64,
+        team_id: String,
+        specialization_id: String,
+        application: String,
+    ) -> ApiClientResult<()>;
+
+    async fn list_applications(
+        &self,
+        chat_id: i64,
+        limit: u32,
+        offset: u64,
+    ) -> ApiClientResult<TeamApplications>;
+
+    async fn accept_application(
+        &self,
+        chat_id: i64,
+        team_id: String,
+        user_id: String,
+    ) -> ApiClientResult<()>;
+
+    async fn reject_application(
+        &self,
+        chat_id: i64,
+        team_id: String,
+        user_id: String,
+    ) -> ApiClientResult<()>;
+}
+
+pub type ApiClientDependency = Arc<dyn ApiClient + Send + Sync>;
@@ -0,0 +1,149 @@
+use teloxide::{
+    prelude::*,
+    types::{InlineKeyboardButton, InlineKeyboardMarkup},
+};
+
+use super::{BotResult, NOT_LINKED, TRY_LATER, callback::Callback};
+use crate::api::{ApiClientDependency, ApiError};
+
+const APPLICATIONS_PAGE_SIZE: u32 = 10;
+
+const NOT_A_LEAD: &str = "В текущем туре вы не руководите командой.";
+const ALREADY_DECIDED: &str = "Эта заявка уже обработана или отозвана.";
+const CANNOT_DECIDE: &str = "Не получилось обработать заявку: в команде нет \
+                             места, участник уже состоит в другой команде \
+                             или вы не лидер этой команды.";
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Decision {
+    Accept,
+    Reject,
+}
+
+/// Sends every pending application to the team the user leads as a
+/// separate message with buttons to accept or reject it.
+#[tracing::instrument(skip_all, name = "Bot::applications")]
+pub async fn applications(
+    bot: Bot,
+    chat_id: ChatId,
+    api_client: ApiClientDependency,
+) -> BotResult {
+    let team_applications = match api_client
+        .list_applications(chat_id.0, APPLICATIONS_PAGE_SIZE, 0)
+        .await
+    {
+        Ok(team_applications) => team_applications,
+        Err(err) => {
+            let text = match err {
+                ApiError::NotLinked => NOT_LINKED,
+                ApiError::NotFound(..) => NOT_A_LEAD,
+                err => {
+                    tracing::warn!("Failed to list applications: {err}");
+                    TRY_LATER
+                },
+            };
+            bot.send_message(chat_id, text).await?;
+            return Ok(());
+        },
+    };
+
+    let team = &team_applications.team;
+    if team_applications.applications.is_empty() {
+        bot.send_message(
+            chat_id,
+            format!("Новых заявок в команду «{}» нет.", team.name),
+        )
+        .await?;
+        return Ok(());
+    }
+
+    bot.send_message(
+        chat_id,
+        format!(
+            "Заявки в команду «{}» ({}/{}):",
+            team.name, team.members, team.max_members
+        ),
+    )
+    .await?;
+    for applicant in &team_applications.applications {
+        let keyboard = InlineKeyboardMarkup::new([[
+            InlineKeyboardButton::callback(
+                "Принять",
+                Callback::Accept {
+                    team_id: team.id.clone(),
+                    user_id: applicant.user_id.clone(),
+                }
+                .to_string(),
+            ),
+            InlineKeyboardButton::callback(
+                "Отклонить",
+                Callback::Reject {
+                    team_id: team.id.clone(),
+                    user_id: applicant.user_id.clone(),
+                }
+                .to_string(),
+            ),
+        ]]);
+        bot.send_message(
+            chat_id,
+            format!(
+                "{} — {}\n\n{}",
+                applicant.username,
+                applicant.specialization,
+                applicant.application
+            ),
+        )
+        .reply_markup(keyboard)
+        .await?;
+    }
+    Ok(())
+}
+
+#[tracing::instrument(skip_all, name = "Bot::decide")]
+pub async fn decide(
+    bot: Bot,
+    chat_id: ChatId,
+    team_id: String,
+    user_id: String,
+    decision: Decision,
+    api_client: ApiClientDependency,
+) -> BotResult {
+    let res = match decision {
+        Decision::Accept => {
+            api_client
+                .accept_application(chat_id.0, team_id, user_id)
+                .await
+        },
+        Decision::Reject => {
+            api_client
+                .reject_application(chat_id.0, team_id, user_id)
+                .await
+        },
+    };
+
+    let text = match (res, decision) {
+        (Ok(()), Decision::Accept) => {
+            "Заявка принята. Участник получит уведомление."
+        },
+        (Ok(()), Decision::Reject) => "Заявка отклонена.",
+        (Err(ApiError::NotLinked), _) => NOT_LINKED,
+        (Err(ApiError::NotFound(..) | ApiError::Invalid(..)), _) => {
+            ALREADY_DECIDED
+        },
+        (
+            Err(
+                ApiError::Forbidden(..)
+                | ApiError::Conflict(..)
+                | ApiError::AlreadyExists(..),
+            ),
+            _,
+        ) => CANNOT_DECIDE,
+        (Err(err), _) => {
+            tracing::warn!("Failed to decide on application: {err}");
+            TRY_LATER
+        },
+    };
+
+    bot.send_message(chat_id, text).await?;
+    Ok(())
+}
@@ -0,0 +1,128 @@
+use std::{fmt, str::FromStr};
+
+/// Payload of an inline keyboard button.
+///
+/// Telegram limits callback data to 64 bytes, which fits a prefix and two
+/// ULIDs.
+#[derive(Clone, PartialEq, Debug)]
+pub enum Callback {
+    Teams {
+        offset: u64,
+    },
+    Team {
+        team_id: String,
+    },
+    Apply {
+        team_id: String,
+        specialization_id: String,
+    },
+    Accept {
+        team_id: String,
+        user_id: String,
+    },
+    Reject {
+        team_id: String,
+        user_id: String,
+    },
+}
+
+#[derive(thiserror::Error, Debug)]
+#[error("Unknown callback data `{0}`")]
+pub struct UnknownCallback(String);
+
+impl fmt::Display for Callback {
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Teams {
+                offset,
+            } => write!(formatter, "teams:{offset}"),
+            Self::Team {
+                team_id,
+            } => write!(formatter, "team:{team_id}"),
+            Self::Apply {
+                team_id,
+                specialization_id,
+            } => write!(formatter, "apply:{team_id}:{specialization_id}"),
+            Self::Accept {
+                team_id,
+                user_id,
+            } => write!(formatter, "accept:{team_id}:{user_id}"),
+            Self::Reject {
+                team_id,
+                user_id,
+            } => write!(formatter, "reject:{team_id}:{user_id}"),
+        }
+    }
+}
+
+impl FromStr for Callback {
+    type Err = UnknownCallback;
+
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn from_str(data: &str) -> Result<Self, Self::Err> {
+        let unknown = || UnknownCallback(data.to_string());
+        let mut parts = data.split(':');
+        let (Some(kind), first, second, None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(unknown());
+        };
+
+        match (kind, first, second) {
+            ("teams", Some(offset), None) => Ok(Self::Teams {
+                offset: offset.parse().map_err(|_| unknown())?,
+            }),
+            ("team", Some(team_id), None) => Ok(Self::Team {
+                team_id: team_id.into(),
+            }),
+            ("apply", Some(team_id), Some(specialization_id)) => {
+                Ok(Self::Apply {
+                    team_id: team_id.into(),
+                    specialization_id: specialization_id.into(),
+                })
+            },
+            ("accept", Some(team_id), Some(user_id)) => Ok(Self::Accept {
+                team_id: team_id.into(),
+                user_id: user_id.into(),
+            }),
+            ("reject", Some(team_id), Some(user_id)) => Ok(Self::Reject {
+                team_id: team_id.into(),
+                user_id: user_id.into(),
+            }),
+            _ => Err(unknown()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rstest::rstest;
+
+    use super::Callback;
+
+    const ULID: &str = "01JZ8V3W1N3Q3Y5T7K9M2P4R6S";
+
+    #[rstest]
+    #[case::teams(Callback::Teams { offset: 20 })]
+    #[case::team(Callback::Team { team_id: ULID.into() })]
+    #[case::apply(Callback::Apply { team_id: ULID.into(), specialization_id: ULID.into() })]
+    #[case::accept(Callback::Accept { team_id: ULID.into(), user_id: ULID.into() })]
+    #[case::reject(Callback::Reject { team_id: ULID.into(), user_id: ULID.into() })]
+    fn round_trip(#[case] callback: Callback) {
+        let data = callback.to_string();
+
+        assert!(data.len() <= 64);
+        assert_eq!(data.parse::<Callback>().ok(), Some(callback));
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::unknown_kind("join:1")]
+    #[case::invalid_offset("teams:next")]
+    #[case::missing_part("apply:1")]
+    #[case::extra_part("team:1:2")]
+    fn unknown(#[case] data: &str) {
+        assert!(data.parse::<Callback>().is_err());
+    }
+}
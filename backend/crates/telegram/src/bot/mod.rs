@@ -0,0 +1,686 @@
+use teloxide::{
+    RequestError,
+    dispatching::{
+        HandlerExt, UpdateFilterExt, UpdateHandler,
+        dialogue::{Dialogue, GetChatId as _, InMemStorage, InMemStorageError},
+    },
+    prelude::*,
+    utils::command::BotCommands,
+};
+
+use crate::api::{ApiClientDependency, ApiError};
+
+pub mod applications;
+pub mod callback;
+pub mod teams;
+
+use applications::Decision;
+use callback::Callback;
+
+const GREETING: &str = "Привет! Я бот Minizord. Чтобы получать уведомления, \
+                        привяжите аккаунт в настройках профиля на сайте.";
+const PRIVATE_CHAT_ONLY: &str =
+    "Привязать аккаунт можно только в личном чате с ботом.";
+const INVALID_CODE: &str = "Ссылка для привязки недействительна или устарела. \
+                            Получите новую в настройках профиля.";
+const ALREADY_LINKED: &str =
+    "Этот Telegram-аккаунт уже привязан к другому пользователю Minizord.";
+const UNAVAILABLE: &str = "Не удалось привязать аккаунт, попробуйте позже.";
+const NOT_LINKED: &str = "Сначала привяжите аккаунт Minizord в настройках \
+                          профиля на сайте.";
+const TRY_LATER: &str = "Сервис временно недоступен, попробуйте позже.";
+const CANCELLED: &str = "Действие отменено.";
+
+#[derive(thiserror::Error, Debug)]
+pub enum BotError {
+    #[error(transparent)]
+    Request(#[from] RequestError),
+
+    #[error("Dialogue storage error: {0}")]
+    Dialogue(#[from] InMemStorageError),
+}
+
+pub type BotResult = Result<(), BotError>;
+
+/// What the bot expects from the chat next.
+#[derive(Clone, Default, PartialEq, Debug)]
+pub enum State {
+    #[default]
+    Idle,
+    Application {
+        team_id: String,
+        specialization_id: String,
+    },
+}
+
+pub type BotDialogue = Dialogue<State, InMemStorage<State>>;
+
+#[derive(BotCommands, Clone, PartialEq, Debug)]
+#[command(rename_rule = "lowercase", description = "Доступные команды:")]
+pub enum Command {
+    #[command(description = "привязать аккаунт Minizord")]
+    Start(String),
+
+    #[command(description = "открытые команды текущего тура")]
+    Teams,
+
+    #[command(description = "заявки в вашу команду")]
+    Applications,
+
+    #[command(description = "отменить текущее действие")]
+    Cancel,
+
+    #[command(description = "показать это сообщение")]
+    Help,
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub fn schema() -> UpdateHandler<BotError> {
+    let messages = Update::filter_message()
+        .enter_dialogue::<Message, InMemStorage<State>, State>()
+        .branch(dptree::entry().filter_command::<Command>().endpoint(answer))
+        .branch(
+            dptree::case![State::Application {
+                team_id,
+                specialization_id
+            }]
+            .endpoint(teams::receive_application),
+        );
+    let callbacks = Update::filter_callback_query()
+        .enter_dialogue::<CallbackQuery, InMemStorage<State>, State>()
+        .endpoint(callback);
+
+    dptree::entry().branch(messages).branch(callbacks)
+}
+
+#[tracing::instrument(skip_all, name = "Bot::answer")]
+async fn answer(
+    bot: Bot,
+    message: Message,
+    command: Command,
+    dialogue: BotDialogue,
+    api_client: ApiClientDependency,
+) -> BotResult {
+    let chat_id = message.chat.id;
+    match command {
+        Command::Start(code) => start(bot, message, code, api_client).await,
+        Command::Teams => teams::teams(bot, chat_id, 0, api_client).await,
+        Command::Applications => {
+            applications::applications(bot, chat_id, api_client).await
+        },
+        Command::Cancel => {
+            dialogue.exit().await?;
+            bot.send_message(chat_id, CANCELLED).await?;
+            Ok(())
+        },
+        Command::Help => {
+            bot.send_message(chat_id, Command::descriptions().to_string())
+                .await?;
+            Ok(())
+        },
+    }
+}
+
+#[tracing::instrument(skip_all, name = "Bot::callback")]
+async fn callback(
+    bot: Bot,
+    query: CallbackQuery,
+    dialogue: BotDialogue,
+    api_client: ApiClientDependency,
+) -> BotResult {
+    bot.answer_callback_query(query.id.clone()).await?;
+    let (Some(chat_id), Some(data)) = (query.chat_id(), query.data.as_deref())
+    else {
+        return Ok(());
+    };
+    let callback = match data.parse::<Callback>() {
+        Ok(callback) => callback,
+        Err(err) => {
+            tracing::warn!("Ignoring callback query: {err}");
+            return Ok(());
+        },
+    };
+
+    match callback {
+        Callback::Teams {
+            offset,
+        } => teams::teams(bot, chat_id, offset, api_client).await,
+        Callback::Team {
+            team_id,
+        } => teams::team(bot, chat_id, team_id, api_client).await,
+        Callback::Apply {
+            team_id,
+            specialization_id,
+        } => {
+            teams::request_application(
+                bot,
+                chat_id,
+                dialogue,
+                team_id,
+                specialization_id,
+            )
+            .await
+        },
+        Callback::Accept {
+            team_id,
+            user_id,
+        } => {
+            applications::decide(
+                bot,
+                chat_id,
+                team_id,
+                user_id,
+                Decision::Accept,
+                api_client,
+            )
+            .await
+        },
+        Callback::Reject {
+            team_id,
+            user_id,
+        } => {
+            applications::decide(
+                bot,
+                chat_id,
+                team_id,
+                user_id,
+                Decision::Reject,
+                api_client,
+            )
+            .await
+        },
+    }
+}
+
+/// Handles `/start`, which carries the one-time link code when the user
+/// follows the deep link issued by the API.
+#[tracing::instrument(skip_all, name = "Bot::start")]
+pub async fn start(
+    bot: Bot,
+    message: Message,
+    code: String,
+    api_client: ApiClientDependency,
+) -> BotResult {
+    let code = code.trim();
+    let text = if code.is_empty() {
+        GREETING.to_string()
+    } else if !message.chat.is_private() {
+        PRIVATE_CHAT_ONLY.to_string()
+    } else {
+        let username =
+            message.from.as_ref().and_then(|user| user.username.clone());
+        match api_client
+            .link_account(code.to_string(), message.chat.id.0, username)
+            .await
+        {
+            Ok(account) => format!(
+                "Готово! Telegram привязан к аккаунту {} в Minizord.",
+                account.username
+            ),
+            Err(ApiError::Invalid(..) | ApiError::NotFound(..)) => {
+                INVALID_CODE.to_string()
+            },
+            Err(ApiError::AlreadyExists(..) | ApiError::Conflict(..)) => {
+                ALREADY_LINKED.to_string()
+            },
+            Err(err) => {
+                tracing::warn!("Failed to link Telegram account: {err}");
+                UNAVAILABLE.to_string()
+            },
+        }
+    };
+
+    bot.send_message(message.chat.id, text).await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::{Arc, Mutex};
+
+    use macros::async_trait::async_trait;
+    use rstest::{fixture, rstest};
+    use serde_json::{Value, json};
+    use teloxide::{
+        Bot,
+        dispatching::dialogue::{Dialogue, InMemStorage},
+        types::{ChatId, Message},
+    };
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{method, path},
+    };
+
+    use super::{
+        ALREADY_LINKED, BotDialogue, GREETING, INVALID_CODE, NOT_LINKED,
+        PRIVATE_CHAT_ONLY, State,
+        applications::{Decision, decide},
+        start,
+        teams::{receive_application, teams},
+    };
+    use crate::api::{
+        ApiClient, ApiClientResult, ApiError, LinkedAccount, OpenTeams,
+        TeamApplications, TeamNeeds, TeamSummary,
+    };
+
+    const TOKEN: &str = "123456:test";
+    const CHAT_ID: i64 = 42;
+    const ULID: &str = "01JZ8V3W1N3Q3Y5T7K9M2P4R6S";
+
+    /// Answers every call with the configured response and records the
+    /// arguments.
+    struct FakeApiClient {
+        link: fn() -> ApiClientResult<LinkedAccount>,
+        open_teams: fn() -> ApiClientResult<OpenTeams>,
+        apply: fn() -> ApiClientResult<()>,
+        decision: fn() -> ApiClientResult<()>,
+        calls: Mutex<Vec<String>>,
+    }
+
+    impl Default for FakeApiClient {
+        fn default() -> Self {
+            Self {
+                link: linked,
+                open_teams: || Err(unavailable()),
+                apply: || Err(unavailable()),
+                decision: || Err(unavailable()),
+                calls: Mutex::default(),
+            }
+        }
+    }
+
+    impl FakeApiClient {
+        fn record(&self, call: String) {
+            self.calls.lock().expect("Poisoned lock").push(call);
+        }
+
+        fn calls(&self) -> Vec<String> {
+            self.calls.lock().expect("Poisoned lock").clone()
+        }
+    }
+
+    #[async_trait]
+    impl ApiClient for FakeApiClient {
+        async fn link_account(
+            &self,
+            code: String,
+            chat_id: i64,
+            username: Option<String>,
+        ) -> ApiClientResult<LinkedAccount> {
+            self.record(format!("link:{code}:{chat_id}:{username:?}"));
+            (self.link)()
+        }
+
+        async fn list_open_teams(
+            &self,
+            limit: u32,
+            offset: u64,
+        ) -> ApiClientResult<OpenTeams> {
+            self.record(format!("teams:{limit}:{offset}"));
+            (self.open_teams)()
+        }
+
+        async fn get_team_needs(
+            &self,
+            team_id: String,
+        ) -> ApiClientResult<TeamNeeds> {
+            self.record(format!("needs:{team_id}"));
+            Err(unavailable())
+        }
+
+        async fn apply(
+            &self,
+            chat_id: i64,
+            team_id: String,
+            specialization_id: String,
+            application: String,
+        ) -> ApiClientResult<()> {
+            self.record(format!(
+                "apply:{chat_id}:{team_id}:{specialization_id}:{application}"
+            ));
+            (self.apply)()
+        }
+
+        async fn list_applications(
+            &self,
+            chat_id: i64,
+            limit: u32,
+            offset: u64,
+        ) -> ApiClientResult<TeamApplications> {
+            self.record(format!("applications:{chat_id}:{limit}:{offset}"));
+            Err(unavailable())
+        }
+
+        async fn accept_application(
+            &self,
+            chat_id: i64,
+            team_id: String,
+            user_id: String,
+        ) -> ApiClientResult<()> {
+            self.record(format!("accept:{chat_id}:{team_id}:{user_id}"));
+            (self.decision)()
+        }
+
+        async fn reject_application(
+            &self,
+            chat_id: i64,
+            team_id: String,
+            user_id: String,
+        ) -> ApiClientResult<()> {
+            self.record(format!("reject:{chat_id}:{team_id}:{user_id}"));
+            (self.decision)()
+        }
+    }
+
+    fn unavailable() -> ApiError {
+        ApiError::Unavailable("Connection refused".into())
+    }
+
+    fn linked() -> ApiClientResult<LinkedAccount> {
+        Ok(LinkedAccount {
+            user_id: ULID.into(),
+            username: "minizord_user".into(),
+        })
+    }
+
+    fn invalid_code() -> ApiClientResult<LinkedAccount> {
+        Err(ApiError::Invalid("Invalid link code".into()))
+    }
+
+    fn already_linked() -> ApiClientResult<LinkedAccount> {
+        Err(ApiError::AlreadyExists("User with provided chat".into()))
+    }
+
+    fn open_teams(has_more: bool) -> ApiClientResult<OpenTeams> {
+        Ok(OpenTeams {
+            tour: "Весенний тур".into(),
+            teams: vec![TeamSummary {
+                id: ULID.into(),
+                name: "Зорды".into(),
+                members: 2,
+                max_members: 5,
+            }],
+            has_more,
+        })
+    }
+
+    #[fixture]
+    async fn telegram() -> MockServer {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path(format!("/bot{TOKEN}/SendMessage")))
+            .respond_with(|request: &wiremock::Request| {
+                let body: Value =
+                    request.body_json().expect("Bot should send JSON payloads");
+                ResponseTemplate::new(200).set_body_json(json!({
+                    "ok": true,
+                    "result": message(CHAT_ID, "private", body.get("text").cloned().unwrap_or_default()),
+                }))
+            })
+            .mount(&server)
+            .await;
+        server
+    }
+
+    fn bot(server: &MockServer) -> Bot {
+        Bot::new(TOKEN).set_api_url(
+            server
+                .uri()
+                .parse()
+                .expect("Mock server URI should be valid"),
+        )
+    }
+
+    fn message(chat_id: i64, chat_type: &str, text: Value) -> Value {
+        json!({
+            "message_id": 1,
+            "date": 1_700_000_000,
+            "chat": { "id": chat_id, "type": chat_type, "first_name": "Test" },
+            "from": {
+                "id": chat_id,
+                "is_bot": false,
+                "first_name": "Test",
+                "username": "minizord_user",
+            },
+            "text": text,
+        })
+    }
+
+    async fn sent(server: &MockServer) -> Vec<Value> {
+        server
+            .received_requests()
+            .await
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|request| request.body_json::<Value>().ok())
+            .collect()
+    }
+
+    async fn sent_texts(server: &MockServer) -> Vec<String> {
+        sent(server)
+            .await
+            .iter()
+            .filter_map(|body| Some(body.get("text")?.as_str()?.to_string()))
+            .collect()
+    }
+
+    #[rstest]
+    #[case::linked("private", "code", linked, "minizord_user", true)]
+    #[case::invalid_code("private", "code", invalid_code, INVALID_CODE, true)]
+    #[case::already_linked(
+        "private",
+        "code",
+        already_linked,
+        ALREADY_LINKED,
+        true
+    )]
+    #[case::no_code("private", " ", linked, GREETING, false)]
+    #[case::group("group", "code", linked, PRIVATE_CHAT_ONLY, false)]
+    #[tokio::test]
+    async fn start_command(
+        #[future] telegram: MockServer,
+        #[case] chat_type: &str,
+        #[case] code: &str,
+        #[case] link: fn() -> ApiClientResult<LinkedAccount>,
+        #[case] expected: &str,
+        #[case] called: bool,
+    ) {
+        let telegram = telegram.await;
+        let api_client = Arc::new(FakeApiClient {
+            link,
+            ..Default::default()
+        });
+        let message: Message = serde_json::from_value(message(
+            CHAT_ID,
+            chat_type,
+            format!("/start {code}").into(),
+        ))
+        .expect("Message fixture should be valid");
+
+        start(bot(&telegram), message, code.into(), api_client.clone())
+            .await
+            .expect("Reply should be sent");
+
+        let texts = sent_texts(&telegram).await;
+        assert_eq!(texts.len(), 1);
+        assert!(texts.iter().all(|text| text.contains(expected)));
+        assert_eq!(
+            api_client.calls(),
+            if called {
+                vec![format!("link:code:{CHAT_ID}:Some(\"minizord_user\")")]
+            } else {
+                vec![]
+            }
+        );
+    }
+
+    #[rstest]
+    #[case::listed(|| open_teams(false), "Весенний тур", false)]
+    #[case::paginated(|| open_teams(true), "Весенний тур", true)]
+    #[case::no_tour(
+        || Err(ApiError::NotFound("Tour that has not ended yet".into())),
+        "нет активного тура",
+        false
+    )]
+    #[case::none_open(
+        || Ok(OpenTeams { tour: "Весенний тур".into(), teams: vec![], has_more: false }),
+        "нет открытых команд",
+        false
+    )]
+    #[tokio::test]
+    async fn teams_command(
+        #[future] telegram: MockServer,
+        #[case] open_teams: fn() -> ApiClientResult<OpenTeams>,
+        #[case] expected: &str,
+        #[case] next_page: bool,
+    ) {
+        let telegram = telegram.await;
+        let api_client = Arc::new(FakeApiClient {
+            open_teams,
+            ..Default::default()
+        });
+
+        teams(bot(&telegram), ChatId(CHAT_ID), 10, api_client.clone())
+            .await
+            .expect("Reply should be sent");
+
+        let sent = sent(&telegram).await;
+        assert_eq!(sent.len(), 1);
+        assert!(sent.iter().all(|body| {
+            body.get("text")
+                .and_then(Value::as_str)
+                .is_some_and(|text| text.contains(expected))
+        }));
+        assert_eq!(
+            sent.iter()
+                .any(|body| body.to_string().contains("teams:20")),
+            next_page
+        );
+        assert_eq!(api_client.calls(), vec!["teams:10:10".to_string()]);
+    }
+
+    #[rstest]
+    #[case::applied(|| Ok(()), "Заявка отправлена", State::Idle)]
+    #[case::not_linked(|| Err(ApiError::NotLinked), NOT_LINKED, State::Idle)]
+    #[case::already_applied(
+        || Err(ApiError::AlreadyExists("Application".into())),
+        "уже подали заявку",
+        State::Idle
+    )]
+    #[case::too_long(
+        || Err(ApiError::Invalid("application: length is too long".into())),
+        "слишком длинный",
+        application_state()
+    )]
+    #[case::unavailable(
+        || Err(unavailable()),
+        "временно недоступен",
+        application_state()
+    )]
+    #[tokio::test]
+    async fn application_message(
+        #[future] telegram: MockServer,
+        #[case] apply: fn() -> ApiClientResult<()>,
+        #[case] expected: &str,
+        #[case] state: State,
+    ) {
+        let telegram = telegram.await;
+        let api_client = Arc::new(FakeApiClient {
+            apply,
+            ..Default::default()
+        });
+        let dialogue: BotDialogue =
+            Dialogue::new(InMemStorage::new(), ChatId(CHAT_ID));
+        dialogue
+            .update(application_state())
+            .await
+            .expect("Dialogue should be updated");
+        let message: Message = serde_json::from_value(message(
+            CHAT_ID,
+            "private",
+            "Хочу к вам".into(),
+        ))
+        .expect("Message fixture should be valid");
+
+        receive_application(
+            bot(&telegram),
+            message,
+            dialogue.clone(),
+            (ULID.into(), ULID.into()),
+            api_client.clone(),
+        )
+        .await
+        .expect("Reply should be sent");
+
+        let texts = sent_texts(&telegram).await;
+        assert_eq!(texts.len(), 1);
+        assert!(texts.iter().all(|text| text.contains(expected)));
+        assert_eq!(
+            dialogue
+                .get_or_default()
+                .await
+                .expect("Dialogue should be readable"),
+            state
+        );
+        assert_eq!(
+            api_client.calls(),
+            vec![format!("apply:{CHAT_ID}:{ULID}:{ULID}:Хочу к вам")]
+        );
+    }
+
+    fn application_state() -> State {
+        State::Application {
+            team_id: ULID.into(),
+            specialization_id: ULID.into(),
+        }
+    }
+
+    #[rstest]
+    #[case::accepted(Decision::Accept, || Ok(()), "accept", "принята")]
+    #[case::rejected(Decision::Reject, || Ok(()), "reject", "отклонена")]
+    #[case::decided(
+        Decision::Accept,
+        || Err(ApiError::NotFound("Application".into())),
+        "accept",
+        "уже обработана"
+    )]
+    #[case::full(
+        Decision::Accept,
+        || Err(ApiError::Forbidden("Team is already full".into())),
+        "accept",
+        "нет места"
+    )]
+    #[tokio::test]
+    async fn application_decision(
+        #[future] telegram: MockServer,
+        #[case] decision: Decision,
+        #[case] response: fn() -> ApiClientResult<()>,
+        #[case] call: &str,
+        #[case] expected: &str,
+    ) {
+        let telegram = telegram.await;
+        let api_client = Arc::new(FakeApiClient {
+            decision: response,
+            ..Default::default()
+        });
+
+        decide(
+            bot(&telegram),
+            ChatId(CHAT_ID),
+            ULID.into(),
+            ULID.into(),
+            decision,
+            api_client.clone(),
+        )
+        .await
+        .expect("Reply should be sent");
+
+        let texts = sent_texts(&telegram).await;
+        assert_eq!(texts.len(), 1);
+        assert!(texts.iter().all(|text| text.contains(expected)));
+        assert_eq!(
+            api_client.calls(),
+            vec![format!("{call}:{CHAT_ID}:{ULID}:{ULID}")]
+        );
+    }
+}
@@ -0,0 +1,221 @@
+use teloxide::{
+    prelude::*,
+    types::{InlineKeyboardButton, InlineKeyboardMarkup},
+};
+
+use super::{
+    BotDialogue, BotResult, NOT_LINKED, State, TRY_LATER, callback::Callback,
+};
+use crate::api::{ApiClientDependency, ApiError};
+
+const TEAMS_PAGE_SIZE: u32 = 10;
+
+const NO_CURRENT_TOUR: &str = "Сейчас нет активного тура.";
+const NO_OPEN_TEAMS: &str = "В текущем туре больше нет открытых команд.";
+const TEAM_NOT_FOUND: &str = "Команда не найдена.";
+const APPLICATION_PROMPT: &str = "Напишите одним сообщением, почему вы хотите \
+                                  в команду (до 4096 символов). /cancel — \
+                                  отменить.";
+const APPLICATION_TEXT_ONLY: &str =
+    "Отправьте текст заявки обычным сообщением или /cancel для отмены.";
+const APPLIED: &str = "Заявка отправлена! Лидер команды получит уведомление.";
+const ALREADY_APPLIED: &str = "Вы уже подали заявку в эту команду или \
+                               состоите в команде этого тура.";
+const INVALID_APPLICATION: &str =
+    "Текст заявки слишком длинный, сократите его и отправьте ещё раз.";
+const CANNOT_APPLY: &str = "Подать заявку не получилось: команда уже \
+                            заполнена или больше не существует.";
+
+/// Lists a page of the current tour's teams that still have free places,
+/// with a button per team to see what it is missing.
+#[tracing::instrument(skip_all, name = "Bot::teams")]
+pub async fn teams(
+    bot: Bot,
+    chat_id: ChatId,
+    offset: u64,
+    api_client: ApiClientDependency,
+) -> BotResult {
+    let open_teams =
+        match api_client.list_open_teams(TEAMS_PAGE_SIZE, offset).await {
+            Ok(open_teams) => open_teams,
+            Err(ApiError::NotFound(..)) => {
+                bot.send_message(chat_id, NO_CURRENT_TOUR).await?;
+                return Ok(());
+            },
+            Err(err) => {
+                tracing::warn!("Failed to list open teams: {err}");
+                bot.send_message(chat_id, TRY_LATER).await?;
+                return Ok(());
+            },
+        };
+    if open_teams.teams.is_empty() && !open_teams.has_more {
+        bot.send_message(chat_id, NO_OPEN_TEAMS).await?;
+        return Ok(());
+    }
+
+    let mut keyboard: Vec<_> = open_teams
+        .teams
+        .iter()
+        .map(|team| {
+            vec![InlineKeyboardButton::callback(
+                format!(
+                    "{} ({}/{})",
+                    team.name, team.members, team.max_members
+                ),
+                Callback::Team {
+                    team_id: team.id.clone(),
+                }
+                .to_string(),
+            )]
+        })
+        .collect();
+    if open_teams.has_more {
+        keyboard.push(vec![InlineKeyboardButton::callback(
+            "Дальше →",
+            Callback::Teams {
+                offset: offset.saturating_add(TEAMS_PAGE_SIZE.into()),
+            }
+            .to_string(),
+        )]);
+    }
+
+    bot.send_message(
+        chat_id,
+        format!(
+            "Открытые команды тура «{}». Выберите команду, чтобы узнать, \
+             кого в ней не хватает:",
+            open_teams.tour
+        ),
+    )
+    .reply_markup(InlineKeyboardMarkup::new(keyboard))
+    .await?;
+    Ok(())
+}
+
+/// Shows the specializations the team is missing, with a button to apply
+/// for each of them.
+#[tracing::instrument(skip_all, name = "Bot::team")]
+pub async fn team(
+    bot: Bot,
+    chat_id: ChatId,
+    team_id: String,
+    api_client: ApiClientDependency,
+) -> BotResult {
+    let needs = match api_client.get_team_needs(team_id).await {
+        Ok(needs) => needs,
+        Err(ApiError::NotFound(..) | ApiError::Invalid(..)) => {
+            bot.send_message(chat_id, TEAM_NOT_FOUND).await?;
+            return Ok(());
+        },
+        Err(err) => {
+            tracing::warn!("Failed to get team needs: {err}");
+            bot.send_message(chat_id, TRY_LATER).await?;
+            return Ok(());
+        },
+    };
+
+    let team = &needs.team;
+    if needs.missing.is_empty() {
+        bot.send_message(
+            chat_id,
+            format!(
+                "Команда «{}» ({}/{}) укомплектована по всем нужным \
+                 специализациям.",
+                team.name, team.members, team.max_members
+            ),
+        )
+        .await?;
+        return Ok(());
+    }
+
+    let missing = needs
+        .missing
+        .iter()
+        .map(|need| format!("• {} — {}", need.name, need.count))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let keyboard = needs.missing.iter().map(|need| {
+        vec![InlineKeyboardButton::callback(
+            format!("Подать заявку: {}", need.name),
+            Callback::Apply {
+                team_id: team.id.clone(),
+                specialization_id: need.id.clone(),
+            }
+            .to_string(),
+        )]
+    });
+
+    bot.send_message(
+        chat_id,
+        format!(
+            "Команда «{}» ({}/{}). Не хватает:\n{missing}",
+            team.name, team.members, team.max_members
+        ),
+    )
+    .reply_markup(InlineKeyboardMarkup::new(keyboard))
+    .await?;
+    Ok(())
+}
+
+/// Remembers the chosen team and specialization and asks for the
+/// application text, which arrives as the next message.
+#[tracing::instrument(skip_all, name = "Bot::request_application")]
+pub async fn request_application(
+    bot: Bot,
+    chat_id: ChatId,
+    dialogue: BotDialogue,
+    team_id: String,
+    specialization_id: String,
+) -> BotResult {
+    dialogue
+        .update(State::Application {
+            team_id,
+            specialization_id,
+        })
+        .await?;
+    bot.send_message(chat_id, APPLICATION_PROMPT).await?;
+    Ok(())
+}
+
+#[tracing::instrument(skip_all, name = "Bot::receive_application")]
+pub async fn receive_application(
+    bot: Bot,
+    message: Message,
+    dialogue: BotDialogue,
+    (team_id, specialization_id): (String, String),
+    api_client: ApiClientDependency,
+) -> BotResult {
+    let chat_id = message.chat.id;
+    let Some(application) = message.text() else {
+        bot.send_message(chat_id, APPLICATION_TEXT_ONLY).await?;
+        return Ok(());
+    };
+
+    let text = match api_client
+        .apply(chat_id.0, team_id, specialization_id, application.into())
+        .await
+    {
+        Ok(()) => APPLIED,
+        Err(ApiError::Invalid(..)) => {
+            // Keep waiting for a shorter text.
+            bot.send_message(chat_id, INVALID_APPLICATION).await?;
+            return Ok(());
+        },
+        Err(ApiError::NotLinked) => NOT_LINKED,
+        Err(ApiError::AlreadyExists(..)) => ALREADY_APPLIED,
+        Err(
+            ApiError::NotFound(..)
+            | ApiError::Conflict(..)
+            | ApiError::Forbidden(..),
+        ) => CANNOT_APPLY,
+        Err(err) => {
+            tracing::warn!("Failed to apply to team: {err}");
+            bot.send_message(chat_id, TRY_LATER).await?;
+            return Ok(());
+        },
+    };
+
+    dialogue.exit().await?;
+    bot.send_message(chat_id, text).await?;
+    Ok(())
+}
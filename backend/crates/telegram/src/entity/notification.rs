@@ -0,0 +1,70 @@
+use chrono::{DateTime, Utc};
+use macros::entity;
+use serde::{Deserialize, Serialize};
+use ulid::Ulid;
+
+#[derive(Deserialize, Serialize, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationCategory {
+    ApplicationAccepted,
+    NewApplicant,
+    ReviewReceived,
+    TourStarting,
+}
+
+#[derive(Deserialize, Serialize, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum DeliveryStatus {
+    Pending,
+    Sent,
+    Failed,
+}
+
+entity! {
+    Notification {
+        id: Ulid,
+        fields {
+            chat_id: i64,
+            category: NotificationCategory,
+            text: String,
+            status: DeliveryStatus,
+            attempts: u32,
+            error: Option<String>,
+            next_attempt_at: DateTime<Utc>,
+            created_at: DateTime<Utc>,
+            updated_at: DateTime<Utc>,
+        },
+        create {
+            chat_id: i64,
+            category: NotificationCategory,
+            text: String,
+            next_attempt_at: DateTime<Utc>,
+        },
+        update {
+            status: DeliveryStatus,
+            attempts: u32,
+            error: String,
+            next_attempt_at: DateTime<Utc>,
+            updated_at: DateTime<Utc>,
+        }
+    }
+}
+
+impl From<CreateNotification> for Notification {
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn from(create_entity: CreateNotification) -> Self {
+        let now = Utc::now();
+        Self {
+            id: NotificationId::from(Ulid::new()),
+            chat_id: create_entity.chat_id,
+            category: create_entity.category,
+            text: create_entity.text,
+            status: DeliveryStatus::Pending,
+            attempts: 0,
+            error: None,
+            next_attempt_at: create_entity.next_attempt_at,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
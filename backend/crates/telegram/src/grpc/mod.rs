@@ -0,0 +1,133 @@
+use std::str::FromStr;
+
+use proto::{
+    auth::TokenInterceptor,
+    telegram::{
+        DeliveryStatus as ProtoDeliveryStatus, GetDeliveryStatusRequest,
+        GetDeliveryStatusResponse,
+        NotificationCategory as ProtoNotificationCategory, NotifyRequest,
+        NotifyResponse,
+        notification_service_server::{self, NotificationServiceServer},
+    },
+};
+use tonic::{Request, Response, Status, codegen::InterceptedService};
+use ulid::Ulid;
+
+use crate::{
+    entity::notification::{
+        DeliveryStatus, Notification, NotificationCategory,
+    },
+    service::{ServiceError, notification::NotificationServiceDependency},
+};
+
+pub struct NotificationGrpc {
+    notification_service: NotificationServiceDependency,
+}
+impl NotificationGrpc {
+    #[tracing::instrument(skip_all, level = "trace")]
+    pub fn server(
+        notification_service: NotificationServiceDependency,
+        interceptor: TokenInterceptor,
+    ) -> InterceptedService<NotificationServiceServer<Self>, TokenInterceptor>
+    {
+        NotificationServiceServer::with_interceptor(
+            Self {
+                notification_service,
+            },
+            interceptor,
+        )
+    }
+}
+
+#[tonic::async_trait]
+impl notification_service_server::NotificationService for NotificationGrpc {
+    #[tracing::instrument(skip_all, name = "NotificationGrpc::notify")]
+    async fn notify(
+        &self,
+        request: Request<NotifyRequest>,
+    ) -> Result<Response<NotifyResponse>, Status> {
+        let request = request.into_inner();
+        let category = ProtoNotificationCategory::try_from(request.category)
+            .map_err(|err| Status::invalid_argument(err.to_string()))
+            .and_then(NotificationCategory::try_from)?;
+        let notification = self
+            .notification_service
+            .notify(request.chat_id, category, request.variables)
+            .await?;
+
+        Ok(Response::new(NotifyResponse {
+            notification_id: notification.id.to_string(),
+            status: ProtoDeliveryStatus::from(notification.status).into(),
+        }))
+    }
+
+    #[tracing::instrument(
+        skip_all,
+        name = "NotificationGrpc::get_delivery_status"
+    )]
+    async fn get_delivery_status(
+        &self,
+        request: Request<GetDeliveryStatusRequest>,
+    ) -> Result<Response<GetDeliveryStatusResponse>, Status> {
+        let id = Ulid::from_str(&request.into_inner().notification_id)
+            .map_err(|err| Status::invalid_argument(err.to_string()))?;
+        let notification = self.notification_service.get_by_id(id).await?;
+
+        Ok(Response::new(notification.into()))
+    }
+}
+
+impl TryFrom<ProtoNotificationCategory> for NotificationCategory {
+    type Error = Status;
+
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn try_from(category: ProtoNotificationCategory) -> Result<Self, Status> {
+        use ProtoNotificationCategory as PNC;
+        match category {
+            PNC::Unspecified => Err(Status::invalid_argument(
+                "Notification category is not specified",
+            )),
+            PNC::ApplicationAccepted => Ok(Self::ApplicationAccepted),
+            PNC::NewApplicant => Ok(Self::NewApplicant),
+            PNC::ReviewReceived => Ok(Self::ReviewReceived),
+            PNC::TourStarting => Ok(Self::TourStarting),
+        }
+    }
+}
+
+impl From<DeliveryStatus> for ProtoDeliveryStatus {
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn from(status: DeliveryStatus) -> Self {
+        match status {
+            DeliveryStatus::Pending => Self::Pending,
+            DeliveryStatus::Sent => Self::Sent,
+            DeliveryStatus::Failed => Self::Failed,
+        }
+    }
+}
+
+impl From<Notification> for GetDeliveryStatusResponse {
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn from(notification: Notification) -> Self {
+        Self {
+            notification_id: notification.id.to_string(),
+            status: ProtoDeliveryStatus::from(notification.status).into(),
+            attempts: notification.attempts,
+            error: notification.error,
+        }
+    }
+}
+
+impl From<ServiceError> for Status {
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn from(err: ServiceError) -> Self {
+        use ServiceError as SE;
+        match err {
+            SE::BadRequest(..) => Self::invalid_argument(err.to_string()),
+            SE::NotFound(..) => Self::not_found(err.to_string()),
+            SE::Database(..) | SE::Internal(..) => {
+                Self::internal(err.to_string())
+            },
+        }
+    }
+}
@@ -1 +1,151 @@
+use std::{net::SocketAddr, time::Duration};
 
+use api::{ApiClientDependency, grpc::GrpcApiClient};
+use bot::State;
+use env_vars_config::env_vars_config;
+use grpc::NotificationGrpc;
+use proto::{auth::TokenInterceptor, propagation};
+use repository::notification::surreal::SurrealNotificationRepository;
+use service::notification::{
+    NotificationServiceDependency, RetryPolicy,
+    implementation::NotificationServiceImpl,
+};
+use teloxide::{
+    Bot,
+    dispatching::{Dispatcher, dialogue::InMemStorage},
+    dptree,
+};
+use tonic::transport::Server;
+use transport::bot::BotTransport;
+use utils::adapters::SurrealPool;
+
+pub mod api;
+pub mod bot;
+pub mod entity;
+pub mod grpc;
+pub mod repository;
+pub mod service;
+pub mod transport;
+
+env_vars_config! {
+    SERVER_ADDRESS: String = "0.0.0.0:50053",
+    DB_ADDRESS: String = "localhost:8001",
+    DB_NAMESPACE: String = "minizord",
+    DB_NAME: String = "telegram",
+    DB_USER: String = "root",
+    DB_PASSWORD: String = "root",
+    DB_MAX_POOL_SIZE: u64 = 8u64,
+    TELEGRAM_BOT_TOKEN: String = "",
+    TELEGRAM_API_URL: String = "https://api.telegram.org",
+    API_GRPC_ADDRESS: String = "http://localhost:50052",
+    INTERNAL_GRPC_TOKEN: String = "",
+    DELIVERY_MAX_ATTEMPTS: u32 = 5u32,
+    DELIVERY_BASE_DELAY_MS: u64 = 1000u64,
+    DELIVERY_MAX_DELAY_MS: u64 = 60000u64,
+    DELIVERY_DISPATCH_INTERVAL_SECONDS: u64 = 5u64,
+    METRICS_ADDRESS: String = "0.0.0.0:8083",
+    OTEL_ENDPOINT: String = "http://localhost:4317",
+}
+
+pub struct Telegram {
+    bot: Bot,
+    api_client: ApiClientDependency,
+    notification_service: NotificationServiceDependency,
+}
+impl Telegram {
+    #[tracing::instrument(skip_all, level = "trace")]
+    pub fn setup(db: SurrealPool) -> Self {
+        assert!(
+            !config::INTERNAL_GRPC_TOKEN.is_empty(),
+            "INTERNAL_GRPC_TOKEN must be set"
+        );
+
+        let bot = Bot::new(config::TELEGRAM_BOT_TOKEN.as_str()).set_api_url(
+            config::TELEGRAM_API_URL
+                .parse()
+                .expect("Got invalid TELEGRAM_API_URL"),
+        );
+        let api_client = GrpcApiClient::connect_lazy(
+            config::API_GRPC_ADDRESS.clone(),
+            &config::INTERNAL_GRPC_TOKEN,
+        )
+        .expect("Got invalid API gRPC client configuration");
+
+        let notification_repository =
+            SurrealNotificationRepository::new(db.clone());
+
+        let notification_service = NotificationServiceImpl::new(
+            notification_repository,
+            BotTransport::new(bot.clone()),
+            RetryPolicy {
+                max_attempts: *config::DELIVERY_MAX_ATTEMPTS,
+                base_delay: Duration::from_millis(
+                    *config::DELIVERY_BASE_DELAY_MS,
+                ),
+                max_delay: Duration::from_millis(
+                    *config::DELIVERY_MAX_DELAY_MS,
+                ),
+            },
+        );
+
+        Self {
+            bot,
+            api_client,
+            notification_service,
+        }
+    }
+
+    pub async fn run(self) -> Result<(), tonic::transport::Error> {
+        tracing::info!("Starting the Telegram bot and the gRPC server");
+
+        let address: SocketAddr = config::SERVER_ADDRESS
+            .parse()
+            .expect("Got invalid server address");
+        let dispatch = tokio::spawn(Self::dispatch_notifications(
+            self.notification_service.clone(),
+        ));
+        let server = Server::builder()
+            .trace_fn(propagation::server_span)
+            .add_service(NotificationGrpc::server(
+                self.notification_service,
+                TokenInterceptor::new(&config::INTERNAL_GRPC_TOKEN),
+            ))
+            .serve_with_shutdown(address, async {
+                tokio::signal::ctrl_c()
+                    .await
+                    .expect("Failed to listen for the shutdown signal");
+            });
+
+        let mut dispatcher = Dispatcher::builder(self.bot, bot::schema())
+            .dependencies(dptree::deps![
+                self.api_client,
+                InMemStorage::<State>::new()
+            ])
+            .enable_ctrlc_handler()
+            .build();
+
+        let ((), server) = tokio::join!(dispatcher.dispatch(), server);
+        dispatch.abort();
+        server?;
+
+        tracing::info!("Shutting down the Telegram bot and the gRPC server");
+        Ok(())
+    }
+
+    /// Also picks up the notifications left pending by a previous run, as the
+    /// first tick completes immediately.
+    #[tracing::instrument(skip_all, level = "debug")]
+    async fn dispatch_notifications(
+        notification_service: NotificationServiceDependency,
+    ) {
+        let mut interval = tokio::time::interval(Duration::from_secs(
+            *config::DELIVERY_DISPATCH_INTERVAL_SECONDS,
+        ));
+        loop {
+            interval.tick().await;
+            if let Err(err) = notification_service.dispatch().await {
+                tracing::warn!("Failed to dispatch notifications: {err}");
+            }
+        }
+    }
+}
@@ -1,3 +1,36 @@
-fn main() {
-    println!("Hello, world!");
+use include_dir::include_dir;
+use telegram::{Telegram, config};
+use utils::{LGTM, adapters::SurrealPool};
+
+#[tokio::main]
+async fn main() -> Result<(), tonic::transport::Error> {
+    config::init();
+
+    let lgtm = LGTM::init(
+        &config::OTEL_ENDPOINT,
+        &config::METRICS_ADDRESS,
+        "minizord",
+        "telegram",
+    );
+
+    config::test_values();
+
+    let pool = SurrealPool::init(
+        &config::DB_ADDRESS,
+        &config::DB_NAMESPACE,
+        &config::DB_NAME,
+        &config::DB_USER,
+        &config::DB_PASSWORD,
+        false,
+        *config::DB_MAX_POOL_SIZE,
+    )
+    .await
+    .migrate(&include_dir!("crates/telegram/db/surreal"))
+    .await
+    .expect("Failed to run migrations");
+
+    Telegram::setup(pool).run().await?;
+
+    lgtm.shutdown().expect("Failed to shut down LGTM stuff");
+    Ok(())
 }
@@ -0,0 +1,4 @@
+mod error;
+pub mod notification;
+
+pub use error::RepositoryError;
@@ -0,0 +1,18 @@
+use chrono::{DateTime, Utc};
+use macros::crud_repository;
+
+use crate::{
+    entity::notification::{self, Notification},
+    repository::RepositoryError,
+};
+
+#[cfg(feature = "surrealdb")]
+pub mod surreal;
+
+crud_repository! {
+    Notification
+        Err: RepositoryError
+    {
+        async fn find_all_due(&self, now: DateTime<Utc>, limit: u16) -> Vec<Notification>;
+    }
+}
@@ -0,0 +1,76 @@
+use chrono::{DateTime, Utc};
+use macros::{EntityId, implementation};
+use tracing::instrument;
+use utils::adapters::{MobcPool, SurrealPool};
+
+use super::{NotificationRepository, NotificationRepositoryResult};
+use crate::{
+    entity::notification::{
+        CreateNotification, Notification, NotificationId, NotificationUpdate,
+    },
+    repository::RepositoryError,
+};
+
+implementation! {
+    NotificationRepository {
+        pool: SurrealPool
+    } as SurrealNotificationRepository {
+        #[instrument(skip_all, name = "NotificationRepository::save")]
+        async fn save(&self, new: CreateNotification) -> Notification {
+            let entity: Notification = new.into();
+            self.pool
+                .get()
+                .await?
+                .create(entity.id.record_id())
+                .content(entity)
+                .await?
+                .ok_or(RepositoryError::FailedToSaveObject)?
+        }
+
+        #[instrument(skip_all, name = "NotificationRepository::find_by_id")]
+        async fn find_by_id(&self, id: NotificationId) -> Option<Notification> {
+            self.pool
+                .get()
+                .await?
+                .select(id.record_id())
+                .await?
+        }
+
+        #[instrument(skip_all, name = "NotificationRepository::exists_by_id")]
+        async fn exists_by_id(&self, id: NotificationId) -> bool {
+            self.find_by_id(id).await?.is_some()
+        }
+
+        #[instrument(skip_all, name = "NotificationRepository::update_by_id")]
+        async fn update_by_id(&self, id: NotificationId, update: NotificationUpdate) -> Option<Notification> {
+            self.pool
+                .get()
+                .await?
+                .update(id.record_id())
+                .merge(update)
+                .await?
+        }
+
+        #[instrument(skip_all, name = "NotificationRepository::delete_by_id")]
+        async fn delete_by_id(&self, id: NotificationId) -> Option<Notification> {
+            self.pool
+                .get()
+                .await?
+                .delete(id.record_id())
+                .await?
+        }
+
+        #[instrument(skip_all, name = "NotificationRepository::find_all_due")]
+        async fn find_all_due(&self, now: DateTime<Utc>, limit: u16) -> Vec<Notification> {
+            self.pool
+                .get()
+                .await?
+                .query(include_str!("../../../db/surreal/queries/notification/find_all_due.surql"))
+                .bind(("table", NotificationId::TABLE))
+                .bind(("now", now))
+                .bind(("limit", limit))
+                .await?
+                .take(0)?
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use crate::repository::RepositoryError;
+
+#[derive(thiserror::Error, Debug)]
+pub enum ServiceError {
+    #[error("{0}")]
+    BadRequest(String),
+
+    #[error("{0}")]
+    NotFound(String),
+
+    #[error("{0}")]
+    Database(String),
+
+    #[error("{0}")]
+    Internal(String),
+}
+
+impl From<RepositoryError> for ServiceError {
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn from(err: RepositoryError) -> Self {
+        Self::Database(err.to_string())
+    }
+}
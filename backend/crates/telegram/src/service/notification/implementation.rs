@@ -0,0 +1,451 @@
+use std::{collections::HashMap, time::Duration};
+
+use chrono::{DateTime, TimeDelta, Utc};
+use macros::implementation;
+use tracing::instrument;
+use ulid::Ulid;
+
+use super::{
+    DELIVERY_BATCH_SIZE, NotificationService, NotificationServiceResult,
+    RetryPolicy, render,
+};
+use crate::{
+    entity::notification::{
+        CreateNotification, DeliveryStatus, Notification, NotificationCategory,
+        NotificationUpdate,
+    },
+    repository::notification::NotificationRepositoryDependency,
+    service::ServiceError,
+    transport::{TelegramTransportDependency, TransportError},
+};
+
+implementation! {
+    NotificationService {
+        notification_repository: NotificationRepositoryDependency,
+        transport: TelegramTransportDependency,
+        retry_policy: RetryPolicy,
+    } as NotificationServiceImpl {
+        #[instrument(skip_all, name = "NotificationService::notify")]
+        async fn notify(
+            &self,
+            chat_id: i64,
+            category: NotificationCategory,
+            variables: HashMap<String, String>,
+        ) -> Notification {
+            let text = render(category, &variables)?;
+
+            // Left for `dispatch` to pick up if the first attempt below never
+            // gets recorded
+            let notification = self.notification_repository
+                .save(
+                    CreateNotification {
+                        chat_id,
+                        category,
+                        text,
+                        next_attempt_at: after(self.retry_policy.delay(1)),
+                    }
+                )
+                .await?;
+
+            let notification_repository = self.notification_repository.clone();
+            let transport = self.transport.clone();
+            let retry_policy = self.retry_policy;
+            let pending = notification.clone();
+            tokio::spawn(async move {
+                let id = pending.id.clone();
+                if let Err(err) = deliver(
+                    &notification_repository,
+                    &transport,
+                    retry_policy,
+                    pending,
+                )
+                .await
+                {
+                    tracing::error!("Failed to record delivery of notification {id}: {err}");
+                }
+            });
+
+            notification
+        }
+
+        #[instrument(skip_all, name = "NotificationService::get_by_id")]
+        async fn get_by_id(
+            &self,
+            id: Ulid,
+        ) -> Notification {
+            self.notification_repository
+                .find_by_id(id.into())
+                .await?
+                .ok_or(
+                    ServiceError::NotFound("Notification with provided id".into())
+                )?
+        }
+
+        #[instrument(skip_all, name = "NotificationService::dispatch")]
+        async fn dispatch(&self) -> u32 {
+            let mut sent: u32 = 0;
+            for notification in self.notification_repository
+                .find_all_due(Utc::now(), DELIVERY_BATCH_SIZE)
+                .await?
+            {
+                let notification = deliver(
+                    &self.notification_repository,
+                    &self.transport,
+                    self.retry_policy,
+                    notification,
+                )
+                .await?;
+                if notification.status == DeliveryStatus::Sent {
+                    sent = sent.saturating_add(1);
+                }
+            }
+            sent
+        }
+    }
+}
+
+/// Makes a single attempt to send the notification and records its outcome,
+/// scheduling the next attempt for retryable failures until the policy runs
+/// out of attempts.
+#[instrument(skip_all, name = "NotificationService::deliver")]
+async fn deliver(
+    notification_repository: &NotificationRepositoryDependency,
+    transport: &TelegramTransportDependency,
+    retry_policy: RetryPolicy,
+    notification: Notification,
+) -> NotificationServiceResult<Notification> {
+    let attempts = notification.attempts.saturating_add(1);
+    let (status, error, next_attempt_at) = match transport
+        .send(notification.chat_id, notification.text.clone())
+        .await
+    {
+        Ok(()) => (DeliveryStatus::Sent, None, None),
+        Err(TransportError::Retryable {
+            message,
+            retry_after,
+        }) if attempts < retry_policy.max_attempts => (
+            DeliveryStatus::Pending,
+            Some(message),
+            Some(after(retry_after.unwrap_or(retry_policy.delay(attempts)))),
+        ),
+        Err(err) => {
+            tracing::warn!(
+                "Failed to deliver notification {}: {err}",
+                notification.id
+            );
+            (DeliveryStatus::Failed, Some(err.to_string()), None)
+        },
+    };
+
+    notification_repository
+        .update_by_id(
+            notification.id,
+            NotificationUpdate {
+                status: Some(status),
+                attempts: Some(attempts),
+                error,
+                next_attempt_at,
+                updated_at: Some(Utc::now()),
+            },
+        )
+        .await?
+        .ok_or(ServiceError::NotFound(
+            "Notification with provided id".into(),
+        ))
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+fn after(delay: Duration) -> DateTime<Utc> {
+    TimeDelta::from_std(delay)
+        .ok()
+        .and_then(|delay| Utc::now().checked_add_signed(delay))
+        .unwrap_or(DateTime::<Utc>::MAX_UTC)
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        collections::HashMap,
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    use chrono::{DateTime, TimeDelta, Utc};
+    use macros::async_trait::async_trait;
+    use rstest::{fixture, rstest};
+    use ulid::Ulid;
+
+    use super::NotificationServiceImpl;
+    use crate::{
+        entity::notification::{
+            CreateNotification, DeliveryStatus, Notification,
+            NotificationCategory, NotificationId, NotificationUpdate,
+        },
+        repository::{RepositoryError, notification::NotificationRepository},
+        service::{
+            ServiceError,
+            notification::{NotificationService, RetryPolicy},
+        },
+        transport::memory::MemoryTransport,
+    };
+
+    #[derive(Default)]
+    struct MemoryNotificationRepository(Mutex<HashMap<String, Notification>>);
+
+    #[async_trait]
+    impl NotificationRepository for MemoryNotificationRepository {
+        async fn save(
+            &self,
+            new: CreateNotification,
+        ) -> Result<Notification, RepositoryError> {
+            let notification = Notification::from(new);
+            self.0
+                .lock()
+                .expect("Poisoned lock")
+                .insert(notification.id.to_string(), notification.clone());
+            Ok(notification)
+        }
+
+        async fn find_by_id(
+            &self,
+            id: NotificationId,
+        ) -> Result<Option<Notification>, RepositoryError> {
+            Ok(self
+                .0
+                .lock()
+                .expect("Poisoned lock")
+                .get(&id.to_string())
+                .cloned())
+        }
+
+        async fn exists_by_id(
+            &self,
+            id: NotificationId,
+        ) -> Result<bool, RepositoryError> {
+            Ok(self.find_by_id(id).await?.is_some())
+        }
+
+        async fn update_by_id(
+            &self,
+            id: NotificationId,
+            update: NotificationUpdate,
+        ) -> Result<Option<Notification>, RepositoryError> {
+            let mut notifications = self.0.lock().expect("Poisoned lock");
+            Ok(notifications.get_mut(&id.to_string()).map(|notification| {
+                if let Some(status) = update.status {
+                    notification.status = status;
+                }
+                if let Some(attempts) = update.attempts {
+                    notification.attempts = attempts;
+                }
+                if let Some(error) = update.error {
+                    notification.error = Some(error);
+                }
+                if let Some(next_attempt_at) = update.next_attempt_at {
+                    notification.next_attempt_at = next_attempt_at;
+                }
+                if let Some(updated_at) = update.updated_at {
+                    notification.updated_at = updated_at;
+                }
+                notification.clone()
+            }))
+        }
+
+        async fn delete_by_id(
+            &self,
+            id: NotificationId,
+        ) -> Result<Option<Notification>, RepositoryError> {
+            Ok(self
+                .0
+                .lock()
+                .expect("Poisoned lock")
+                .remove(&id.to_string()))
+        }
+
+        async fn find_all_due(
+            &self,
+            now: DateTime<Utc>,
+            limit: u16,
+        ) -> Result<Vec<Notification>, RepositoryError> {
+            let mut due: Vec<Notification> = self
+                .0
+                .lock()
+                .expect("Poisoned lock")
+                .values()
+                .filter(|notification| {
+                    notification.status == DeliveryStatus::Pending
+                        && notification.next_attempt_at <= now
+                })
+                .cloned()
+                .collect();
+            due.sort_by_key(|notification| notification.next_attempt_at);
+            due.truncate(usize::from(limit));
+            Ok(due)
+        }
+    }
+
+    async fn pending(
+        repository: &MemoryNotificationRepository,
+        next_attempt_at: DateTime<Utc>,
+    ) -> Notification {
+        repository
+            .save(CreateNotification {
+                chat_id: 1,
+                category: NotificationCategory::NewApplicant,
+                text: "Hi".into(),
+                next_attempt_at,
+            })
+            .await
+            .expect("Notification should be stored")
+    }
+
+    #[fixture]
+    fn retry_policy() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::ZERO,
+            max_delay: Duration::ZERO,
+        }
+    }
+
+    #[fixture]
+    fn variables() -> HashMap<String, String> {
+        HashMap::from([("team".to_string(), "Minizord".to_string())])
+    }
+
+    #[rstest]
+    #[case::first_attempt(0, DeliveryStatus::Sent, 1)]
+    #[case::retried(2, DeliveryStatus::Sent, 3)]
+    #[case::exhausted(3, DeliveryStatus::Failed, 3)]
+    #[tokio::test]
+    async fn delivery(
+        retry_policy: RetryPolicy,
+        #[case] failures: u32,
+        #[case] status: DeliveryStatus,
+        #[case] attempts: u32,
+    ) {
+        let repository = Arc::new(MemoryNotificationRepository::default());
+        let transport = MemoryTransport::failing(failures);
+        let notification = pending(&repository, Utc::now()).await;
+        let service = NotificationServiceImpl::new(
+            repository.clone(),
+            transport.clone(),
+            retry_policy,
+        );
+
+        for _ in 0..retry_policy.max_attempts {
+            service.dispatch().await.expect("Dispatch should succeed");
+        }
+
+        let notification = service
+            .get_by_id(notification.id.into())
+            .await
+            .expect("Notification should exist");
+        assert_eq!(notification.status, status);
+        assert_eq!(notification.attempts, attempts);
+        assert_eq!(
+            transport.sent().len(),
+            usize::from(status == DeliveryStatus::Sent)
+        );
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn rejected(retry_policy: RetryPolicy) {
+        let repository = Arc::new(MemoryNotificationRepository::default());
+        let notification = pending(&repository, Utc::now()).await;
+        let service = NotificationServiceImpl::new(
+            repository,
+            MemoryTransport::rejecting(),
+            retry_policy,
+        );
+
+        let sent = service.dispatch().await.expect("Dispatch should succeed");
+
+        let notification = service
+            .get_by_id(notification.id.into())
+            .await
+            .expect("Notification should exist");
+        assert_eq!(sent, 0);
+        assert_eq!(notification.status, DeliveryStatus::Failed);
+        assert_eq!(notification.attempts, 1);
+        assert!(notification.error.is_some());
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn not_due(retry_policy: RetryPolicy) {
+        let repository = Arc::new(MemoryNotificationRepository::default());
+        let next_attempt_at = Utc::now()
+            .checked_add_signed(TimeDelta::minutes(1))
+            .expect("Next attempt should be representable");
+        pending(&repository, next_attempt_at).await;
+        let transport = MemoryTransport::new();
+        let service = NotificationServiceImpl::new(
+            repository,
+            transport.clone(),
+            retry_policy,
+        );
+
+        let sent = service.dispatch().await.expect("Dispatch should succeed");
+
+        assert_eq!(sent, 0);
+        assert!(transport.sent().is_empty());
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn queued(
+        retry_policy: RetryPolicy,
+        variables: HashMap<String, String>,
+    ) {
+        let service = NotificationServiceImpl::new(
+            Arc::new(MemoryNotificationRepository::default()),
+            MemoryTransport::new(),
+            retry_policy,
+        );
+
+        let notification = service
+            .notify(1, NotificationCategory::NewApplicant, variables)
+            .await
+            .expect("Notification should be queued");
+
+        assert_eq!(notification.status, DeliveryStatus::Pending);
+        assert_eq!(
+            notification.text,
+            "В вашу команду «Minizord» поступила новая заявка."
+        );
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn not_queued(retry_policy: RetryPolicy) {
+        let transport = MemoryTransport::new();
+        let service = NotificationServiceImpl::new(
+            Arc::new(MemoryNotificationRepository::default()),
+            transport.clone(),
+            retry_policy,
+        );
+
+        let res = service
+            .notify(1, NotificationCategory::NewApplicant, HashMap::new())
+            .await;
+
+        assert!(matches!(res, Err(ServiceError::BadRequest(..))));
+        assert!(transport.sent().is_empty());
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn unknown_notification(retry_policy: RetryPolicy) {
+        let service = NotificationServiceImpl::new(
+            Arc::new(MemoryNotificationRepository::default()),
+            MemoryTransport::new(),
+            retry_policy,
+        );
+
+        let res = service.get_by_id(Ulid::new()).await;
+
+        assert!(matches!(res, Err(ServiceError::NotFound(..))));
+    }
+}
@@ -0,0 +1,130 @@
+use std::{collections::HashMap, time::Duration};
+
+use macros::service;
+use ulid::Ulid;
+
+use crate::{
+    entity::notification::{Notification, NotificationCategory},
+    service::ServiceError,
+};
+
+pub mod implementation;
+
+const DELIVERY_BATCH_SIZE: u16 = 100;
+
+service! {
+    Notification
+        Err: ServiceError
+    {
+        async fn notify(
+            &self,
+            chat_id: i64,
+            category: NotificationCategory,
+            variables: HashMap<String, String>,
+        ) -> Notification;
+
+        async fn get_by_id(&self, id: Ulid) -> Notification;
+
+        /// Retries the pending notifications whose next attempt is due,
+        /// returning how many of them got sent.
+        async fn dispatch(&self) -> u32;
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+}
+impl RetryPolicy {
+    /// Delay after the failed `attempt`, doubling with every attempt and
+    /// capped at `max_delay`.
+    #[tracing::instrument(skip_all, level = "trace")]
+    pub fn delay(&self, attempt: u32) -> Duration {
+        2u32.checked_pow(attempt.saturating_sub(1))
+            .and_then(|factor| self.base_delay.checked_mul(factor))
+            .map_or(self.max_delay, |delay| delay.min(self.max_delay))
+    }
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+pub(crate) fn render(
+    category: NotificationCategory,
+    variables: &HashMap<String, String>,
+) -> Result<String, ServiceError> {
+    let variable = |name: &str| {
+        variables.get(name).ok_or_else(|| {
+            ServiceError::BadRequest(format!("Missing variable `{name}`"))
+        })
+    };
+
+    use NotificationCategory as NC;
+    Ok(match category {
+        NC::ApplicationAccepted => {
+            format!("Вашу заявку в команду «{}» приняли! 🎉", variable("team")?)
+        },
+        NC::NewApplicant => format!(
+            "В вашу команду «{}» поступила новая заявка.",
+            variable("team")?
+        ),
+        NC::ReviewReceived => {
+            format!("Вы получили новый отзыв с оценкой {}.", variable("score")?)
+        },
+        NC::TourStarting => format!(
+            "Тур «{}» начнётся {}. Удачи!",
+            variable("tour")?,
+            variable("starts_at")?
+        ),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::{collections::HashMap, time::Duration};
+
+    use rstest::rstest;
+
+    use super::{RetryPolicy, render};
+    use crate::{
+        entity::notification::NotificationCategory, service::ServiceError,
+    };
+
+    #[rstest]
+    #[case::first(1, 1)]
+    #[case::doubled(3, 4)]
+    #[case::capped(5, 10)]
+    #[case::overflow(u32::MAX, 10)]
+    fn delay(#[case] attempt: u32, #[case] expected: u64) {
+        let policy = RetryPolicy {
+            max_attempts: 5,
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(10),
+        };
+
+        assert_eq!(policy.delay(attempt), Duration::from_secs(expected));
+    }
+
+    #[rstest]
+    fn rendered() {
+        let variables = HashMap::from([
+            ("tour".to_string(), "Весна".to_string()),
+            ("starts_at".to_string(), "01.03.2026 10:00 UTC".to_string()),
+        ]);
+
+        let text = render(NotificationCategory::TourStarting, &variables)
+            .expect("Notification should render");
+
+        assert_eq!(text, "Тур «Весна» начнётся 01.03.2026 10:00 UTC. Удачи!");
+    }
+
+    #[rstest]
+    fn missing_variable() {
+        let variables =
+            HashMap::from([("tour".to_string(), "Весна".to_string())]);
+
+        let res = render(NotificationCategory::TourStarting, &variables);
+
+        assert!(matches!(res, Err(ServiceError::BadRequest(..))));
+    }
+}
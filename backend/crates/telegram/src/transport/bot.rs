@@ -0,0 +1,53 @@
+use std::sync::Arc;
+
+use macros::async_trait::async_trait;
+use teloxide::{Bot, RequestError, prelude::Requester as _, types::ChatId};
+
+use super::{TelegramTransport, TransportError};
+
+pub struct BotTransport {
+    bot: Bot,
+}
+impl BotTransport {
+    #[tracing::instrument(skip_all, level = "trace")]
+    pub fn new(bot: Bot) -> Arc<Self> {
+        Arc::new(Self {
+            bot,
+        })
+    }
+}
+
+#[async_trait]
+impl TelegramTransport for BotTransport {
+    #[tracing::instrument(name = "BotTransport::send", skip_all)]
+    async fn send(
+        &self,
+        chat_id: i64,
+        text: String,
+    ) -> Result<(), TransportError> {
+        self.bot.send_message(ChatId(chat_id), text).await?;
+        Ok(())
+    }
+}
+
+impl From<RequestError> for TransportError {
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn from(err: RequestError) -> Self {
+        use RequestError as RE;
+        match err {
+            RE::RetryAfter(seconds) => Self::Retryable {
+                message: err.to_string(),
+                retry_after: Some(seconds.duration()),
+            },
+            RE::Network(..) | RE::Io(..) => Self::Retryable {
+                message: err.to_string(),
+                retry_after: None,
+            },
+            RE::Api(..)
+            | RE::MigrateToChatId(..)
+            | RE::InvalidJson {
+                ..
+            } => Self::Permanent(err.to_string()),
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use std::sync::{Arc, Mutex};
+
+use macros::async_trait::async_trait;
+
+use super::{TelegramTransport, TransportError};
+
+/// An in-process transport that keeps every message it is given instead of
+/// sending it, for tests and local runs without a bot token.
+#[derive(Default)]
+pub struct MemoryTransport {
+    sent: Mutex<Vec<(i64, String)>>,
+    failures: Mutex<u32>,
+    reject: bool,
+}
+impl MemoryTransport {
+    #[tracing::instrument(skip_all, level = "trace")]
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    /// Fails the first `times` messages with a retryable error.
+    #[tracing::instrument(skip_all, level = "trace")]
+    pub fn failing(times: u32) -> Arc<Self> {
+        Arc::new(Self {
+            failures: Mutex::new(times),
+            ..Self::default()
+        })
+    }
+
+    #[tracing::instrument(skip_all, level = "trace")]
+    pub fn rejecting() -> Arc<Self> {
+        Arc::new(Self {
+            reject: true,
+            ..Self::default()
+        })
+    }
+
+    #[tracing::instrument(skip_all, level = "trace")]
+    pub fn sent(&self) -> Vec<(i64, String)> {
+        self.sent
+            .lock()
+            .map(|sent| sent.clone())
+            .unwrap_or_default()
+    }
+}
+
+#[async_trait]
+impl TelegramTransport for MemoryTransport {
+    #[tracing::instrument(name = "MemoryTransport::send", skip_all)]
+    async fn send(
+        &self,
+        chat_id: i64,
+        text: String,
+    ) -> Result<(), TransportError> {
+        if self.reject {
+            return Err(TransportError::Permanent(format!(
+                "Message to chat {chat_id} was rejected"
+            )));
+        }
+        let failed = self
+            .failures
+            .lock()
+            .map(|mut failures| {
+                let failed = *failures > 0;
+                *failures = failures.saturating_sub(1);
+                failed
+            })
+            .unwrap_or_default();
+        if failed {
+            return Err(TransportError::Retryable {
+                message: format!("Message to chat {chat_id} has failed"),
+                retry_after: None,
+            });
+        }
+        if let Ok(mut sent) = self.sent.lock() {
+            sent.push((chat_id, text));
+        }
+        Ok(())
+    }
+}
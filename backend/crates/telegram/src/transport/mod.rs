@@ -0,0 +1,32 @@
+use std::{sync::Arc, time::Duration};
+
+use macros::async_trait::async_trait;
+
+pub mod bot;
+pub mod memory;
+
+#[derive(thiserror::Error, Debug)]
+pub enum TransportError {
+    /// The message may go through if it is sent again, after `retry_after`
+    /// when Telegram asked for a specific delay.
+    #[error("{message}")]
+    Retryable {
+        message: String,
+        retry_after: Option<Duration>,
+    },
+
+    /// Sending the message again won't help, e.g. the user blocked the bot.
+    #[error("{0}")]
+    Permanent(String),
+}
+
+#[async_trait]
+pub trait TelegramTransport {
+    async fn send(
+        &self,
+        chat_id: i64,
+        text: String,
+    ) -> Result<(), TransportError>;
+}
+
+pub type TelegramTransportDependency = Arc<dyn TelegramTransport + Send + Sync>;
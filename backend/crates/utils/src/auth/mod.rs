@@ -1,6 +1,10 @@
 pub extern crate jsonwebtoken;
 
 pub mod jwt;
+pub mod keys;
 pub mod password_hashing;
+pub mod token;
+pub mod totp;
 
+pub use keys::JwtKeys;
 pub use password_hashing::PasswordHasher;
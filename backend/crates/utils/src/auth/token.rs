@@ -0,0 +1,30 @@
+use rand_chacha::{
+    ChaCha20Rng,
+    rand_core::{RngCore, SeedableRng},
+};
+
+const TOKEN_BYTES: usize = 32;
+
+/// Generates a random hex-encoded token for links mailed to users.
+#[tracing::instrument(name = "token::generate", skip_all, level = "debug")]
+pub fn generate() -> String {
+    let mut bytes = [0_u8; TOKEN_BYTES];
+    ChaCha20Rng::from_entropy().fill_bytes(&mut bytes);
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use rstest::rstest;
+
+    use super::{TOKEN_BYTES, generate};
+
+    #[rstest]
+    fn basic() {
+        let token = generate();
+
+        assert_eq!(token.len(), TOKEN_BYTES.saturating_mul(2));
+        assert!(token.chars().all(|char| char.is_ascii_hexdigit()));
+        assert_ne!(token, generate());
+    }
+}
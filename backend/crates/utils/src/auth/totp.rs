@@ -0,0 +1,218 @@
+use data_encoding::BASE32_NOPAD;
+use hmac::{Hmac, Mac};
+use rand_chacha::{
+    ChaCha20Rng,
+    rand_core::{RngCore, SeedableRng},
+};
+use sha1::Sha1;
+use url::Url;
+
+const SECRET_BYTES: usize = 20;
+const STEP_SECONDS: u64 = 30;
+const DIGITS: u32 = 6;
+/// Steps before and after the current one that are still accepted, to
+/// tolerate clock drift between the server and the authenticator.
+const SKEW_STEPS: u64 = 1;
+
+const RECOVERY_CODE_BYTES: usize = 5;
+
+/// Generates a base32-encoded secret for a new authenticator.
+#[tracing::instrument(
+    name = "totp::generate_secret",
+    skip_all,
+    level = "debug"
+)]
+pub fn generate_secret() -> String {
+    let mut bytes = [0_u8; SECRET_BYTES];
+    ChaCha20Rng::from_entropy().fill_bytes(&mut bytes);
+    BASE32_NOPAD.encode(&bytes)
+}
+
+/// Builds the `otpauth://` URI authenticator apps enroll the secret from,
+/// usually rendered as a QR code.
+#[tracing::instrument(
+    name = "totp::provisioning_uri",
+    skip_all,
+    level = "debug"
+)]
+pub fn provisioning_uri(secret: &str, issuer: &str, account: &str) -> String {
+    let mut uri = Url::parse("otpauth://totp/").expect("Got invalid base URI");
+    uri.path_segments_mut()
+        .expect("Got base URI that can't have a path")
+        .pop_if_empty()
+        .push(&format!("{issuer}:{account}"));
+    uri.query_pairs_mut()
+        .append_pair("secret", secret)
+        .append_pair("issuer", issuer)
+        .append_pair("algorithm", "SHA1")
+        .append_pair("digits", &DIGITS.to_string())
+        .append_pair("period", &STEP_SECONDS.to_string());
+    uri.into()
+}
+
+/// Checks `code` against the steps around `unix_time`, returning the step
+/// it was generated for. Steps up to `last_step` are rejected, so a code
+/// can't be used twice.
+#[tracing::instrument(name = "totp::verify", skip_all, level = "debug")]
+pub fn verify(
+    secret: &str,
+    code: &str,
+    unix_time: u64,
+    last_step: Option<u64>,
+) -> Option<u64> {
+    let key = BASE32_NOPAD.decode(secret.as_bytes()).ok()?;
+    let current = unix_time.checked_div(STEP_SECONDS)?;
+    (current.saturating_sub(SKEW_STEPS)..=current.saturating_add(SKEW_STEPS))
+        .filter(|step| last_step.is_none_or(|last_step| *step > last_step))
+        .find(|step| {
+            generate(&key, *step).is_some_and(|expected| {
+                constant_time_eq(expected.as_bytes(), code.as_bytes())
+            })
+        })
+}
+
+/// Generates single-use codes that stand in for the authenticator when it's
+/// lost, formatted as `xxxx-xxxx`.
+#[tracing::instrument(
+    name = "totp::generate_recovery_codes",
+    skip_all,
+    level = "debug"
+)]
+pub fn generate_recovery_codes(count: usize) -> Vec<String> {
+    let mut rng = ChaCha20Rng::from_entropy();
+    (0..count)
+        .map(|_| {
+            let mut bytes = [0_u8; RECOVERY_CODE_BYTES];
+            rng.fill_bytes(&mut bytes);
+            let code = BASE32_NOPAD.encode(&bytes).to_lowercase();
+            let (first, second) = code.split_at(code.len().saturating_div(2));
+            format!("{first}-{second}")
+        })
+        .collect()
+}
+
+/// Strips the separators users may type along with a recovery code.
+#[tracing::instrument(
+    name = "totp::normalize_recovery_code",
+    skip_all,
+    level = "debug"
+)]
+pub fn normalize_recovery_code(code: &str) -> String {
+    code.chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|char| char.to_ascii_lowercase())
+        .collect()
+}
+
+/// HOTP value (RFC 4226) of `step`, which makes it a TOTP one (RFC 6238).
+#[tracing::instrument(skip_all, level = "trace")]
+fn generate(key: &[u8], step: u64) -> Option<String> {
+    let mut mac = Hmac::<Sha1>::new_from_slice(key).ok()?;
+    mac.update(&step.to_be_bytes());
+    let hash = mac.finalize().into_bytes();
+
+    let offset = usize::from(hash.last()? & 0x0f);
+    let truncated = hash.get(offset..offset.checked_add(4)?)?;
+    let value = u32::from_be_bytes(truncated.try_into().ok()?) & 0x7fff_ffff;
+    let code = value.checked_rem(10_u32.checked_pow(DIGITS)?)?;
+    Some(format!(
+        "{code:0width$}",
+        width = usize::try_from(DIGITS).ok()?
+    ))
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+fn constant_time_eq(left: &[u8], right: &[u8]) -> bool {
+    left.len() == right.len()
+        && left
+            .iter()
+            .zip(right)
+            .fold(0, |diff, (left, right)| diff | (left ^ right))
+            == 0
+}
+
+#[cfg(test)]
+mod test {
+    use data_encoding::BASE32_NOPAD;
+    use rstest::rstest;
+
+    use super::{
+        generate, generate_recovery_codes, generate_secret,
+        normalize_recovery_code, provisioning_uri, verify,
+    };
+
+    /// Secret of the RFC 6238 SHA-1 test vectors.
+    const RFC_KEY: &[u8] = b"12345678901234567890";
+
+    #[rstest]
+    #[case::first_step(59, "287082")]
+    #[case::leading_zero(1_111_111_109, "081804")]
+    #[case::next_step(1_111_111_111, "050471")]
+    #[case::recent(1_234_567_890, "005924")]
+    #[case::future(2_000_000_000, "279037")]
+    fn rfc_vectors(#[case] unix_time: u64, #[case] expected: &str) {
+        assert_eq!(
+            generate(RFC_KEY, unix_time.saturating_div(30)).as_deref(),
+            Some(expected)
+        );
+    }
+
+    #[rstest]
+    #[case::current(1_111_111_109, None, Some(37_037_036))]
+    #[case::previous_step(1_111_111_139, None, Some(37_037_036))]
+    #[case::next_step(1_111_111_079, None, Some(37_037_036))]
+    #[case::too_old(1_111_111_169, None, None)]
+    #[case::reused(1_111_111_109, Some(37_037_036), None)]
+    fn verify_window(
+        #[case] unix_time: u64,
+        #[case] last_step: Option<u64>,
+        #[case] expected: Option<u64>,
+    ) {
+        let secret = BASE32_NOPAD.encode(RFC_KEY);
+
+        assert_eq!(verify(&secret, "081804", unix_time, last_step), expected);
+    }
+
+    #[rstest]
+    #[case::wrong_code("081805")]
+    #[case::too_short("81804")]
+    #[case::empty("")]
+    fn invalid_code(#[case] code: &str) {
+        let secret = BASE32_NOPAD.encode(RFC_KEY);
+
+        assert_eq!(verify(&secret, code, 1_111_111_109, None), None);
+    }
+
+    #[rstest]
+    fn secret() {
+        let secret = generate_secret();
+
+        assert_eq!(secret.len(), 32);
+        assert!(BASE32_NOPAD.decode(secret.as_bytes()).is_ok());
+        assert_ne!(secret, generate_secret());
+    }
+
+    #[rstest]
+    fn uri() {
+        assert_eq!(
+            provisioning_uri(
+                "JBSWY3DPEHPK3PXP",
+                "Minizord",
+                "jane@example.com"
+            ),
+            "otpauth://totp/Minizord:jane@example.com?secret=JBSWY3DPEHPK3PXP&issuer=Minizord&algorithm=SHA1&digits=6&period=30"
+        );
+    }
+
+    #[rstest]
+    fn recovery_codes() {
+        let codes = generate_recovery_codes(10);
+
+        assert_eq!(codes.len(), 10);
+        for code in &codes {
+            assert_eq!(code.len(), 9);
+            assert_eq!(normalize_recovery_code(code).len(), 8);
+        }
+        assert_eq!(normalize_recovery_code(" ABCD-efgh "), "abcdefgh");
+    }
+}
@@ -17,6 +17,7 @@ use opentelemetry_sdk::{
     Resource,
     error::OTelSdkResult,
     logs::{BatchLogProcessor, SdkLogger, SdkLoggerProvider},
+    propagation::TraceContextPropagator,
     trace::{BatchSpanProcessor, SdkTracerProvider, Tracer},
 };
 use opentelemetry_semantic_conventions::attribute;
@@ -130,6 +131,8 @@ impl LGTM {
             )
             .build();
         global::set_tracer_provider(tracer_provider.clone());
+        // Lets traces continue across HTTP and gRPC hops between services.
+        global::set_text_map_propagator(TraceContextPropagator::new());
         self.tracer_provider = Some(Arc::new(tracer_provider));
         self
     }
@@ -4,6 +4,7 @@ pub mod adapters;
 pub mod auth;
 mod lgtm;
 pub mod logger;
+pub mod rate_limit;
 pub mod validation;
 
 pub use lgtm::LGTM;
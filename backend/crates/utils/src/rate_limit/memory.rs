@@ -0,0 +1,355 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    time::{Duration, Instant},
+};
+
+use macros::async_trait::async_trait;
+
+use super::{LockoutPolicy, Quota, RateLimitStore};
+
+/// Keys tracked per map, as they are chosen by the clients.
+const MAX_KEYS: usize = 100_000;
+
+/// Entries indexed by the time they stop mattering, so the expired ones get
+/// dropped without scanning the whole map and the ones closest to expiring
+/// make room once it is full.
+struct ExpiringMap<V> {
+    entries: HashMap<String, (V, Instant)>,
+    expirations: BTreeSet<(Instant, String)>,
+    capacity: usize,
+}
+impl<V> ExpiringMap<V> {
+    fn new(capacity: usize) -> Self {
+        Self {
+            entries: HashMap::new(),
+            expirations: BTreeSet::new(),
+            capacity,
+        }
+    }
+
+    fn get(&self, key: &str, now: Instant) -> Option<&V> {
+        self.entries
+            .get(key)
+            .filter(|(_, expires_at)| *expires_at > now)
+            .map(|(value, _)| value)
+    }
+
+    fn insert(
+        &mut self,
+        key: &str,
+        value: V,
+        expires_at: Instant,
+        now: Instant,
+    ) {
+        self.remove(key);
+        self.prune(now);
+        while self.entries.len() >= self.capacity {
+            let Some((_, evicted)) = self.expirations.pop_first() else {
+                break;
+            };
+            self.entries.remove(&evicted);
+        }
+        self.expirations.insert((expires_at, key.to_string()));
+        self.entries.insert(key.to_string(), (value, expires_at));
+    }
+
+    fn remove(&mut self, key: &str) {
+        if let Some((_, expires_at)) = self.entries.remove(key) {
+            self.expirations.remove(&(expires_at, key.to_string()));
+        }
+    }
+
+    fn prune(&mut self, now: Instant) {
+        while self
+            .expirations
+            .first()
+            .is_some_and(|(expires_at, _)| *expires_at <= now)
+        {
+            if let Some((_, key)) = self.expirations.pop_first() {
+                self.entries.remove(&key);
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Failures {
+    count: u32,
+    locked_until: Option<Instant>,
+}
+
+struct State {
+    /// Theoretical arrival time of the next request for each key (GCRA).
+    buckets: ExpiringMap<Instant>,
+    failures: ExpiringMap<Failures>,
+}
+
+/// An in-process store, so limits are tracked per instance and reset on
+/// restart.
+pub struct MemoryRateLimitStore {
+    state: Mutex<State>,
+}
+impl Default for MemoryRateLimitStore {
+    fn default() -> Self {
+        Self::with_capacity(MAX_KEYS)
+    }
+}
+impl MemoryRateLimitStore {
+    #[tracing::instrument(skip_all, level = "trace")]
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            state: Mutex::new(State {
+                buckets: ExpiringMap::new(capacity),
+                failures: ExpiringMap::new(capacity),
+            }),
+        }
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn acquire_at(
+        &self,
+        key: &str,
+        quota: Quota,
+        now: Instant,
+    ) -> Result<(), Duration> {
+        let interval = quota.interval();
+        let tolerance = quota.period.saturating_sub(interval);
+        let mut state = self.state();
+
+        let tat = state.buckets.get(key, now).copied().unwrap_or(now);
+        let allowed_at = tat.checked_sub(tolerance).unwrap_or(now);
+        if allowed_at > now {
+            return Err(allowed_at.saturating_duration_since(now));
+        }
+
+        let tat = tat.checked_add(interval).unwrap_or(tat);
+        state.buckets.insert(key, tat, tat, now);
+        Ok(())
+    }
+
+    fn locked_for_at(&self, key: &str, now: Instant) -> Option<Duration> {
+        self.state()
+            .failures
+            .get(key, now)
+            .and_then(|failures| failures.locked_until)
+            .map(|locked_until| locked_until.saturating_duration_since(now))
+            .filter(|left| !left.is_zero())
+    }
+
+    fn record_failure_at(
+        &self,
+        key: &str,
+        policy: LockoutPolicy,
+        now: Instant,
+    ) -> Option<Duration> {
+        let mut state = self.state();
+
+        let count = state
+            .failures
+            .get(key, now)
+            .map_or(0, |failures| failures.count)
+            .saturating_add(1);
+        let lockout = policy.lockout(count);
+        let locked_until = lockout.and_then(|lockout| now.checked_add(lockout));
+        let expires_at = now
+            .checked_add(policy.reset_after)
+            .unwrap_or(now)
+            .max(locked_until.unwrap_or(now));
+
+        state.failures.insert(
+            key,
+            Failures {
+                count,
+                locked_until,
+            },
+            expires_at,
+            now,
+        );
+        lockout
+    }
+}
+
+#[async_trait]
+impl RateLimitStore for MemoryRateLimitStore {
+    #[tracing::instrument(name = "MemoryRateLimitStore::acquire", skip_all)]
+    async fn acquire(&self, key: &str, quota: Quota) -> Result<(), Duration> {
+        self.acquire_at(key, quota, Instant::now())
+    }
+
+    #[tracing::instrument(name = "MemoryRateLimitStore::locked_for", skip_all)]
+    async fn locked_for(&self, key: &str) -> Option<Duration> {
+        self.locked_for_at(key, Instant::now())
+    }
+
+    #[tracing::instrument(
+        name = "MemoryRateLimitStore::record_failure",
+        skip_all
+    )]
+    async fn record_failure(
+        &self,
+        key: &str,
+        policy: LockoutPolicy,
+    ) -> Option<Duration> {
+        self.record_failure_at(key, policy, Instant::now())
+    }
+
+    #[tracing::instrument(
+        name = "MemoryRateLimitStore::reset_failures",
+        skip_all
+    )]
+    async fn reset_failures(&self, key: &str) {
+        self.state().failures.remove(key);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::{Duration, Instant};
+
+    use rstest::{fixture, rstest};
+
+    use super::MemoryRateLimitStore;
+    use crate::rate_limit::{LockoutPolicy, Quota, RateLimitStore};
+
+    const QUOTA: Quota = Quota {
+        burst: 3,
+        period: Duration::from_secs(30),
+    };
+
+    const POLICY: LockoutPolicy = LockoutPolicy {
+        max_attempts: 2,
+        base: Duration::from_secs(60),
+        max: Duration::from_secs(600),
+        reset_after: Duration::from_secs(900),
+    };
+
+    #[fixture]
+    fn store() -> MemoryRateLimitStore {
+        MemoryRateLimitStore::default()
+    }
+
+    fn after(now: Instant, secs: u64) -> Instant {
+        now.checked_add(Duration::from_secs(secs))
+            .expect("Got unrepresentable instant")
+    }
+
+    #[rstest]
+    fn burst(store: MemoryRateLimitStore) {
+        let now = Instant::now();
+
+        for _ in 0..QUOTA.burst {
+            assert_eq!(store.acquire_at("ip", QUOTA, now), Ok(()));
+        }
+
+        assert_eq!(
+            store.acquire_at("ip", QUOTA, now),
+            Err(Duration::from_secs(10))
+        );
+        assert_eq!(store.acquire_at("other", QUOTA, now), Ok(()));
+    }
+
+    #[rstest]
+    #[case::partially(4, Err(Duration::from_secs(6)))]
+    #[case::single_request(10, Ok(()))]
+    #[case::fully(30, Ok(()))]
+    fn refill(
+        store: MemoryRateLimitStore,
+        #[case] wait: u64,
+        #[case] expected: Result<(), Duration>,
+    ) {
+        let now = Instant::now();
+        for _ in 0..QUOTA.burst {
+            assert_eq!(store.acquire_at("ip", QUOTA, now), Ok(()));
+        }
+
+        assert_eq!(store.acquire_at("ip", QUOTA, after(now, wait)), expected);
+    }
+
+    #[rstest]
+    fn lockout(store: MemoryRateLimitStore) {
+        let now = Instant::now();
+
+        assert_eq!(store.record_failure_at("email", POLICY, now), None);
+        assert_eq!(store.locked_for_at("email", now), None);
+
+        let lockout = store.record_failure_at("email", POLICY, now);
+        assert_eq!(lockout, Some(Duration::from_secs(60)));
+        assert_eq!(
+            store.locked_for_at("email", after(now, 20)),
+            Some(Duration::from_secs(40))
+        );
+        assert_eq!(store.locked_for_at("email", after(now, 60)), None);
+
+        let lockout = store.record_failure_at("email", POLICY, after(now, 60));
+        assert_eq!(lockout, Some(Duration::from_secs(120)));
+    }
+
+    #[rstest]
+    #[case::expired(900, None)]
+    #[case::recent(899, Some(Duration::from_secs(60)))]
+    fn failures_expire(
+        store: MemoryRateLimitStore,
+        #[case] wait: u64,
+        #[case] expected: Option<Duration>,
+    ) {
+        let now = Instant::now();
+        store.record_failure_at("email", POLICY, now);
+
+        assert_eq!(
+            store.record_failure_at("email", POLICY, after(now, wait)),
+            expected
+        );
+    }
+
+    #[rstest]
+    fn expired_keys_are_pruned(store: MemoryRateLimitStore) {
+        let now = Instant::now();
+        store
+            .acquire_at("ip", QUOTA, now)
+            .expect("Request should pass");
+
+        store
+            .acquire_at("other", QUOTA, after(now, 10))
+            .expect("Request should pass");
+
+        assert_eq!(store.state().buckets.entries.len(), 1);
+    }
+
+    #[rstest]
+    fn keys_are_capped() {
+        let store = MemoryRateLimitStore::with_capacity(2);
+        let now = Instant::now();
+
+        store.record_failure_at("first", POLICY, now);
+        store.record_failure_at("second", POLICY, after(now, 1));
+        store.record_failure_at("first", POLICY, after(now, 2));
+        store.record_failure_at("third", POLICY, after(now, 3));
+
+        assert_eq!(store.state().failures.entries.len(), 2);
+        assert_eq!(
+            store.locked_for_at("first", after(now, 3)),
+            Some(Duration::from_secs(59))
+        );
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn reset(store: MemoryRateLimitStore) {
+        store.record_failure("email", POLICY).await;
+        store.record_failure("email", POLICY).await;
+        assert!(store.locked_for("email").await.is_some());
+
+        store.reset_failures("email").await;
+
+        assert_eq!(store.locked_for("email").await, None);
+        assert_eq!(store.record_failure("email", POLICY).await, None);
+    }
+}
@@ -0,0 +1,118 @@
+use std::{sync::Arc, time::Duration};
+
+use macros::async_trait::async_trait;
+
+pub mod memory;
+
+pub use memory::MemoryRateLimitStore;
+
+/// Token bucket holding up to `burst` requests that refills completely over
+/// `period`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Quota {
+    pub burst: u32,
+    pub period: Duration,
+}
+impl Quota {
+    /// Time it takes the bucket to refill a single request.
+    #[tracing::instrument(skip_all, level = "trace")]
+    pub fn interval(&self) -> Duration {
+        self.period.checked_div(self.burst).unwrap_or(self.period)
+    }
+}
+
+/// Progressive lockout applied after repeated failed attempts.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct LockoutPolicy {
+    /// Failed attempts allowed before the first lockout.
+    pub max_attempts: u32,
+    /// Length of the first lockout, doubled by every failure after it.
+    pub base: Duration,
+    /// Upper bound for a single lockout.
+    pub max: Duration,
+    /// Failures are forgotten after this long without a new one.
+    pub reset_after: Duration,
+}
+impl LockoutPolicy {
+    /// Lockout triggered by `failures` consecutive failed attempts, if any.
+    #[tracing::instrument(skip_all, level = "trace")]
+    pub fn lockout(&self, failures: u32) -> Option<Duration> {
+        let excess = failures.checked_sub(self.max_attempts)?;
+        let factor = 2_u32.checked_pow(excess).unwrap_or(u32::MAX);
+        Some(
+            self.base
+                .checked_mul(factor)
+                .unwrap_or(self.max)
+                .min(self.max),
+        )
+    }
+}
+
+/// Counters backing rate limits and lockouts, so they can be shared between
+/// instances by swapping the store.
+#[async_trait]
+pub trait RateLimitStore {
+    /// Takes a request from the bucket under `key`, failing with the time
+    /// until the next one is allowed.
+    async fn acquire(&self, key: &str, quota: Quota) -> Result<(), Duration>;
+
+    /// Time left until `key` is no longer locked out, if it is.
+    async fn locked_for(&self, key: &str) -> Option<Duration>;
+
+    /// Counts a failed attempt for `key`, returning the lockout it
+    /// triggered, if any.
+    async fn record_failure(
+        &self,
+        key: &str,
+        policy: LockoutPolicy,
+    ) -> Option<Duration>;
+
+    async fn reset_failures(&self, key: &str);
+}
+
+pub type RateLimitStoreDependency = Arc<dyn RateLimitStore + Send + Sync>;
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use rstest::rstest;
+
+    use super::{LockoutPolicy, Quota};
+
+    const POLICY: LockoutPolicy = LockoutPolicy {
+        max_attempts: 3,
+        base: Duration::from_secs(30),
+        max: Duration::from_secs(300),
+        reset_after: Duration::from_secs(900),
+    };
+
+    #[rstest]
+    #[case::steady(10, 60, 6)]
+    #[case::single(1, 60, 60)]
+    #[case::empty(0, 60, 60)]
+    fn interval(
+        #[case] burst: u32,
+        #[case] period: u64,
+        #[case] expected: u64,
+    ) {
+        let quota = Quota {
+            burst,
+            period: Duration::from_secs(period),
+        };
+
+        assert_eq!(quota.interval(), Duration::from_secs(expected));
+    }
+
+    #[rstest]
+    #[case::no_failures(0, None)]
+    #[case::below_threshold(2, None)]
+    #[case::threshold(3, Some(30))]
+    #[case::doubled(4, Some(60))]
+    #[case::doubled_twice(5, Some(120))]
+    #[case::capped(7, Some(300))]
+    #[case::overflowing(u32::MAX, Some(300))]
+    fn lockout(#[case] failures: u32, #[case] expected: Option<u64>) {
+        assert_eq!(POLICY.lockout(failures), expected.map(Duration::from_secs));
+    }
+}